@@ -63,6 +63,9 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
+pub mod location;
+pub mod runtime_api;
+pub mod voting;
 pub mod weights;
 
 #[cfg_attr(feature = "runtime-benchmarks", recursion_limit = "256")]
@@ -74,16 +77,34 @@ pub mod pallet {
 	use core::marker::PhantomData;
 	use cumulus_primitives_core::ParaId;
 	use frame_support::{
-		dispatch::{DispatchResultWithPostInfo, PostDispatchInfo},
+		dispatch::{DispatchResultWithPostInfo, GetDispatchInfo},
 		pallet_prelude::*,
-		traits::Incrementable,
+		storage::{with_transaction_opaque_err, TransactionOutcome},
+		traits::{
+			tokens::nonfungibles_v2::{
+				Create as NonFungiblesCreate, Inspect as NonFungiblesInspect,
+			},
+			EnsureOriginWithArg, Incrementable, ReservableCurrency, UnfilteredDispatchable,
+		},
+		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
-	use pallet_nfts::{CollectionConfigFor, CollectionSettings, DestroyWitness, MintSettings};
+	use pallet_nfts::{
+		BalanceOf, CollectionConfigFor, CollectionSettings, DestroyWitness, MintSettings,
+	};
 	use scale_info::prelude::vec;
-	use sp_runtime::{traits::StaticLookup, DispatchError, DispatchErrorWithPostInfo};
+	use sp_runtime::{
+		traits::{
+			AccountIdConversion, BlakeTwo256, Convert, Hash, Saturating, StaticLookup,
+			UniqueSaturatedInto, Zero,
+		},
+		DispatchError, Perbill,
+	};
 	use sp_std::prelude::*;
-	use xcm::latest::prelude::*;
+	use xcm::latest::{prelude::*, ExecuteXcm};
+	use xcm_executor::traits::{QueryHandler, QueryResponseStatus};
+
+	use crate::voting::{Tally, VotingRule, VotingStrategy};
 
 	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
@@ -104,11 +125,104 @@ pub mod pallet {
 		/// A type representing the weights required by the dispatchables of this pallet.
 		type WeightInfo: crate::weights::WeightInfo;
 
+		/// Specifies the maximum weight of a call executed on destination chain, transfers with
+		/// heavier payload are rejected before they are sent
+		#[pallet::constant]
+		type MaxTransactWeight: Get<Weight>;
+
+		/// Specifies the weight of a single XCM instruction on destination chain, used to bound
+		/// weight of sent messages
+		#[pallet::constant]
+		type XcmInstructionWeight: Get<Weight>;
+
 		/// Specifies how long should cross-chain proposals last
 		type ProposalTimeInBlocks: Get<u32>;
 
-		/// Specifies how manys different owners can be in a collection - used in voting process
-		type MaxOwners: Get<u32>;
+		/// Handler of queries, through which destination chains report status of sent messages
+		type QueryHandler: QueryHandler<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Specifies how long should status report of sent message be awaited before it times out
+		type MessageTimeoutInBlocks: Get<u32>;
+
+		/// Specifies how many sent messages with final delivery status are kept, older ones are
+		/// removed
+		#[pallet::constant]
+		type MaxFinalizedMessages: Get<u32>;
+
+		/// Specifies how many messages that failed to send for transport reason can be queued to be
+		/// retried
+		#[pallet::constant]
+		type MaxQueuedMessages: Get<u32>;
+
+		/// Specifies how many times is queued message attempted to be sent before it is dropped
+		#[pallet::constant]
+		type MaxSendAttempts: Get<u32>;
+
+		/// Specifies how long is queued message retried after its first failed attempt, delay is
+		/// doubled with every next failed attempt
+		type RetryBackoffInBlocks: Get<u32>;
+
+		/// Specifies how many NFTs are snapshotted in one page when cross-chain proposal is created
+		/// or its snapshot is continued, ended proposals are cleaned up in pages of the same size
+		type SnapshotPageSize: Get<u32>;
+
+		/// Specifies the rule that decides whether cross-chain proposal passed
+		type VotingStrategy: VotingStrategy;
+
+		/// Specifies what happens to NFTs of accounts that voted against cross-chain proposal
+		type ProposalMigrationMode: Get<MigrationMode>;
+
+		/// Specifies what happens to received metadata that exceeds `StringLimit` of this chain
+		#[pallet::constant]
+		type MetadataPolicy: Get<MetadataPolicy>;
+
+		/// Specifies maximum length of received metadata kept whole under
+		/// `MetadataPolicy::Overflow`
+		#[pallet::constant]
+		type MaxOverflowMetadata: Get<u32>;
+
+		/// Specifies the deposit reserved from proposer when cross-chain proposal is created
+		#[pallet::constant]
+		type ProposalDeposit: Get<BalanceOf<Self, I>>;
+
+		/// Specifies the portion of proposal deposit that is slashed when proposal does not pass
+		#[pallet::constant]
+		type ProposalDepositSlash: Get<Perbill>;
+
+		/// Specifies how many cross-chain proposals can one account have open at the same time
+		#[pallet::constant]
+		type MaxActiveProposals: Get<u32>;
+
+		/// Specifies the pallet id, its account owns collections created for received NFTs
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Specifies how index is converted into item id, used when item id of received NFT is
+		/// allocated
+		type ItemIdConverter: Convert<u32, Self::ItemId>;
+
+		/// Specifies how local account is converted into location, used as origin of cross-chain
+		/// messages and as beneficiary
+		type AccountToLocation: Convert<Self::AccountId, Option<Location>>;
+
+		/// Specifies how beneficiary location is converted into local account
+		type LocationToAccount: Convert<Location, Option<Self::AccountId>>;
+
+		/// Specifies the origin that can set origin mode and fee of destination chains
+		type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// Specifies the executor used to charge senders for execution fees on destination chains
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Specifies the location of this chain, used to reanchor fee assets to destination chains
+		type UniversalLocation: Get<InteriorLocation>;
+
+		/// Specifies the origin of calls descended from xcNFT pallets of other chains, converted
+		/// into location of the pallet and account that sent the call
+		type XcmOrigin: EnsureOrigin<
+			<Self as frame_system::Config>::RuntimeOrigin,
+			Success = Location,
+		>;
 	}
 
 	#[pallet::pallet]
@@ -133,28 +247,208 @@ pub mod pallet {
 		Nay,
 	}
 
-	/// Struct for votes, contains two vectors, one for Aye voters and one for Nay voters.
-	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Default, Debug)]
+	/// Enum for migration mode of cross-chain proposal, either all NFTs are migrated or NFTs of
+	/// accounts that voted Nay stay on the origin chain.
+	#[derive(
+		Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Default, Debug,
+	)]
+	pub enum MigrationMode {
+		#[default]
+		BurnAll,
+		OptOut,
+	}
+
+	/// Enum for origin mode of destination chain, either sender account is aliased or origin is
+	/// descended into sender account within xcNFT pallet, which destination verifies as sender.
+	#[derive(
+		Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Default, Debug,
+	)]
+	pub enum OriginMode {
+		#[default]
+		Alias,
+		Descend,
+	}
+
+	/// Enum for policy applied to received metadata that exceeds `StringLimit` of this chain,
+	/// either the call is rejected and its failure is reported back, metadata is truncated,
+	/// metadata is truncated and kept whole in `OverflowMetadata`, or metadata is replaced with
+	/// its blake2-256 hash pointing to the content.
+	#[derive(
+		Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Default, Debug,
+	)]
+	pub enum MetadataPolicy {
+		#[default]
+		Reject,
+		Truncate,
+		Overflow,
+		Hash,
+	}
+
+	/// Enum for operation carried by sent message.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+	pub enum Operation {
+		CollectionTransfer,
+		NFTTransfer,
+		CollectionMetadata,
+		NFTMetadata,
+		CollectionBurn,
+		NFTBurn,
+		CollectionOwnership,
+		NFTOwnership,
+		TransferConfirmation,
+	}
+
+	impl Operation {
+		/// Returns whether message carrying the operation can be sent again after failed attempt.
+		/// Collection transfers settle proposals and destroy the collection once sent, so they
+		/// are not retried.
+		pub fn is_retry_safe(&self) -> bool {
+			!matches!(self, Operation::CollectionTransfer)
+		}
+	}
+
+	/// Number of nonces skipped by a sender that are still accepted when they are received later.
+	pub const MAX_SKIPPED_NONCES: u32 = 64;
+
+	/// Structure of nonces received from a sender, contains nonce following the highest received
+	/// nonce and nonces below it that were not received yet. Skipped nonces belong to messages
+	/// that failed to send, which are accepted when their retry arrives, regardless of how many
+	/// messages the sender sent in the meantime. Only the latest `MAX_SKIPPED_NONCES` skipped
+	/// nonces are kept.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Default, Debug)]
+	pub struct NonceWindow {
+		pub next: u64,
+		pub skipped: BoundedVec<u64, ConstU32<MAX_SKIPPED_NONCES>>,
+	}
+
+	impl NonceWindow {
+		/// Records `nonce` as received. Returns false if it was already received or it was skipped
+		/// too long ago to tell whether it was received.
+		pub fn receive(&mut self, nonce: u64) -> bool {
+			if nonce >= self.next {
+				let first_skipped = self.next.max(nonce.saturating_sub(MAX_SKIPPED_NONCES as u64));
+
+				for skipped in first_skipped..nonce {
+					// Oldest skipped nonce is forgotten to make room for the new one
+					if self.skipped.is_full() {
+						self.skipped.remove(0);
+					}
+
+					let _ = self.skipped.try_push(skipped);
+				}
+
+				self.next = nonce.saturating_add(1);
+
+				return true;
+			}
+
+			match self.skipped.iter().position(|skipped| *skipped == nonce) {
+				Some(position) => {
+					self.skipped.remove(position);
+
+					true
+				},
+				None => false,
+			}
+		}
+	}
+
+	/// Enum for delivery status of sent message, either awaiting status report, executed on
+	/// destination chain, failed with dispatch error, or no status report came in time. Status
+	/// report of transfer that timed out is still awaited, as only the report proves whether
+	/// the transfer was executed.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug)]
+	pub enum MessageStatus {
+		Sent,
+		Executed,
+		Failed(MaybeErrorCode),
+		TimedOut,
+	}
+
+	/// Structure of sent message, contains operation, sender location, destination parachain id,
+	/// id of query awaiting status report, and delivery status.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug)]
+	pub struct OutboundMessage {
+		pub operation: Operation,
+		pub sender: Location,
+		pub destination_para_id: ParaId,
+		pub query_id: QueryId,
+		pub status: MessageStatus,
+	}
+
+	/// Structure of message queued after failed attempt to send it, contains account that sent
+	/// it, destination parachain id, sender location, origin kind and call to be sent, funded
+	/// deposit, number of attempts, block of next attempt, and NFT locked until it is sent.
+	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Debug)]
+	#[scale_info(skip_type_params(T, I))]
+	pub struct QueuedMessage<T: Config<I>, I: 'static = ()> {
+		pub owner: T::AccountId,
+		pub destination_para_id: ParaId,
+		pub sender: Location,
+		pub origin_kind: OriginKind,
+		pub call: Call<T, I>,
+		pub deposit: Option<(Asset, Location)>,
+		pub attempts: u32,
+		pub next_attempt: BlockNumberFor<T>,
+		pub locked_nft: Option<(T::CollectionId, T::ItemId)>,
+	}
+
+	/// Enum for assets sent by transfer message, kept until destination chain reports status of
+	/// the message, so they can be restored if the message fails. Either a single NFT with its
+	/// owner and metadata, or a whole collection with its owner, config, metadata, and NFTs with
+	/// their owners and metadata.
+	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Debug)]
 	#[scale_info(skip_type_params(T, I))]
-	pub struct Votes<T: Config<I>, I: 'static = ()> {
-		pub aye: BoundedVec<T::AccountId, T::MaxOwners>,
-		pub nay: BoundedVec<T::AccountId, T::MaxOwners>,
+	pub enum PendingTransfer<T: Config<I>, I: 'static = ()> {
+		NFT {
+			owner: T::AccountId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			data: Vec<u8>,
+		},
+		Collection {
+			owner: T::AccountId,
+			collection: T::CollectionId,
+			config: Option<CollectionConfigFor<T, I>>,
+			metadata: Vec<u8>,
+			nfts: Vec<(T::ItemId, T::AccountId, Vec<u8>)>,
+		},
+	}
+
+	impl<T: Config<I>, I: 'static> PendingTransfer<T, I> {
+		/// Returns account that sent the assets.
+		pub fn owner(&self) -> &T::AccountId {
+			match self {
+				PendingTransfer::NFT { owner, .. } | PendingTransfer::Collection { owner, .. } =>
+					owner,
+			}
+		}
 	}
 
 	/// Structure of proposal, contains proposal id, collection id, proposed collection owner,
-	/// proposed destination parachain, proposed destination config, owners, number of votes, and
-	/// end time.
+	/// beneficiary of the collection on destination, asset funding storage deposits on
+	/// destination, proposed destination parachain, proposed destination config, total voting
+	/// weight of owners, running weight of Aye and Nay votes, last snapshotted NFT, whether
+	/// snapshot is complete, voting rule, migration mode, reserved deposit, and end time.
 	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Default, Debug)]
 	#[scale_info(skip_type_params(T, I))]
 	pub struct Proposal<T: Config<I>, I: 'static = ()> {
 		pub proposal_id: u64,
 		pub collection_id: T::CollectionId,
 		pub proposed_collection_owner: T::AccountId,
+		pub proposed_beneficiary: Location,
+		pub proposed_deposit_asset: Option<AssetId>,
 		pub proposed_destination_para: ParaId,
 		pub proposed_dest_collection_id: Option<T::CollectionId>,
 		pub proposed_destination_config: Option<CollectionConfigFor<T, I>>,
-		pub owners: BoundedVec<T::AccountId, T::MaxOwners>,
-		pub number_of_votes: Votes<T, I>,
+		pub total_weight: u32,
+		pub aye_weight: u32,
+		pub nay_weight: u32,
+		pub snapshot_cursor: Option<T::ItemId>,
+		pub snapshot_complete: bool,
+		pub voting_rule: VotingRule,
+		pub migration_mode: MigrationMode,
+		pub deposit: BalanceOf<T, I>,
 		pub end_time: BlockNumberFor<T>,
 	}
 
@@ -192,6 +486,51 @@ pub mod pallet {
 		pub received_collection_id: T::CollectionId,
 	}
 
+	/// Hash of metadata carried with transferred NFTs.
+	pub type MetadataHash = <BlakeTwo256 as Hash>::Output;
+
+	/// Structure of metadata hashes carried with transferred NFT, contains hash of its collection
+	/// metadata if transferred along, hash of its metadata, and hash of its attributes on origin
+	/// chain.
+	#[derive(
+		Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Default, Debug,
+	)]
+	pub struct MetadataHashes {
+		pub collection: Option<MetadataHash>,
+		pub item: MetadataHash,
+		pub attributes: MetadataHash,
+	}
+
+	/// Structure of metadata hashes of received NFT, contains hashes the NFT was transferred with
+	/// and hashes of its metadata as stored on arrival, which differ from transferred ones if the
+	/// metadata was shortened by `MetadataPolicy`.
+	#[derive(
+		Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Default, Debug,
+	)]
+	pub struct ReceivedHashes {
+		pub received: MetadataHashes,
+		pub arrived: MetadataHashes,
+	}
+
+	/// Structure of metadata integrity of received NFT, contains hashes the NFT was transferred
+	/// with, hashes of its metadata on arrival and hashes of its current metadata.
+	#[derive(Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+	pub struct MetadataIntegrity {
+		pub received: MetadataHashes,
+		pub arrived: MetadataHashes,
+		pub current: MetadataHashes,
+	}
+
+	/// Structure of residual collections, contains id of proposal that migrated the collection,
+	/// destination parachain id, and destination collection id once confirmed by destination chain.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Default, Debug)]
+	#[scale_info(skip_type_params(T, I))]
+	pub struct ResidualCols<T: Config<I>, I: 'static = ()> {
+		pub proposal_id: u64,
+		pub destination_para_id: ParaId,
+		pub destination_collection_id: Option<T::CollectionId>,
+	}
+
 	/// Storage for sent assets, contains origin collection id and origin asset id as tuple key and
 	/// SentStruct as value.
 	#[pallet::storage]
@@ -199,6 +538,13 @@ pub mod pallet {
 	pub type SentAssets<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, (T::CollectionId, T::ItemId), SentStruct<T, I>>;
 
+	/// Storage for index of next item id allocated for received NFTs, contains collection id as
+	/// key.
+	#[pallet::storage]
+	#[pallet::getter(fn next_received_item)]
+	pub type NextReceivedItem<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, u32>;
+
 	/// Storage for received assets, contains received collection id as tuple key and ReceivedStruct
 	/// as value.
 	#[pallet::storage]
@@ -206,12 +552,67 @@ pub mod pallet {
 	pub type ReceivedAssets<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, (T::CollectionId, T::ItemId), ReceivedStruct<T, I>>;
 
+	/// Storage for metadata hashes of received assets, contains received collection id and
+	/// received asset id as tuple key and ReceivedHashes as value.
+	#[pallet::storage]
+	#[pallet::getter(fn received_metadata_hashes)]
+	pub type ReceivedMetadataHashes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, (T::CollectionId, T::ItemId), ReceivedHashes>;
+
 	/// Storage for sent collections, contains origin collection id as key and SentCols as value.
 	#[pallet::storage]
 	#[pallet::getter(fn received_collections)]
 	pub type ReceivedCollections<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::CollectionId, ReceivedCols<T, I>>;
 
+	/// Storage for collections owned by the pallet account, that receive NFTs whose sender does not
+	/// own destination collection, contains origin parachain id and origin collection id as keys
+	/// and derivative collection id as value.
+	#[pallet::storage]
+	#[pallet::getter(fn derivative_collections)]
+	pub type DerivativeCollections<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ParaId,
+		Blake2_128Concat,
+		T::CollectionId,
+		T::CollectionId,
+	>;
+
+	/// Storage for origins allowed to send NFTs into collection, contains collection id and origin
+	/// parachain id with origin collection id as keys. Origin collection id `None` allows every
+	/// collection of origin parachain.
+	#[pallet::storage]
+	#[pallet::getter(fn incoming_allowlist)]
+	pub type IncomingAllowlist<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		(ParaId, Option<T::CollectionId>),
+		(),
+	>;
+
+	/// Storage for received collections indexed by their origin, contains origin parachain id and
+	/// origin collection id as keys and received collection id as value.
+	#[pallet::storage]
+	#[pallet::getter(fn received_collections_by_origin)]
+	pub type ReceivedCollectionsByOrigin<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ParaId,
+		Blake2_128Concat,
+		T::CollectionId,
+		T::CollectionId,
+	>;
+
+	/// Storage for collections that stayed on origin chain with NFTs of accounts that opted out
+	/// of migration, contains origin collection id as key and ResidualCols as value.
+	#[pallet::storage]
+	#[pallet::getter(fn residual_collections)]
+	pub type ResidualCollections<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, ResidualCols<T, I>>;
+
 	/// Storage holding proposal ID, it is incremented each time a new proposal is created.
 	#[pallet::storage]
 	#[pallet::getter(fn next_proposal_id)]
@@ -224,14 +625,227 @@ pub mod pallet {
 	pub type CrossChainProposals<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, u64, Proposal<T, I>>;
 
+	/// Storage for active proposal of a collection, contains collection id as key and proposal id
+	/// as value.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_of_collection)]
+	pub type ProposalOfCollection<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, u64>;
+
+	/// Storage for NFTs locked against transfer by an open proposal, contains proposal id and item
+	/// id as keys.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_locked_items)]
+	pub type ProposalLockedItems<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, u64, Blake2_128Concat, T::ItemId, ()>;
+
+	/// Storage for NFTs covered by snapshot of proposal, contains proposal id and item id as keys
+	/// and account that owned the NFT when snapshot was taken as value.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_items)]
+	pub type ProposalItems<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, u64, Blake2_128Concat, T::ItemId, T::AccountId>;
+
+	/// Storage for beneficiaries chosen by voters, contains proposal id and account id as keys and
+	/// location of the account that receives the NFTs at destination as value.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_beneficiaries)]
+	pub type ProposalBeneficiaries<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, u64, Blake2_128Concat, T::AccountId, Location>;
+
+	/// Storage for owners of proposal, contains proposal id and account id as keys and number of
+	/// NFTs the account held when snapshot was taken as value.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_owners)]
+	pub type ProposalOwners<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, u64, Blake2_128Concat, T::AccountId, u32>;
+
+	/// Storage for votes on proposal, contains proposal id and account id as keys and vote along
+	/// with its weight as value.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_votes)]
+	pub type ProposalVotes<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, u64, Blake2_128Concat, T::AccountId, (Vote, u32)>;
+
+	/// Storage for delegated votes, contains proposal id and delegating account id as keys and
+	/// account id of the delegate as value.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_delegations)]
+	pub type ProposalDelegations<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, u64, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	/// Storage for accounts that delegated their vote, contains proposal id and account id of the
+	/// delegate as keys and number of delegating accounts along with their total voting weight as
+	/// value.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_delegators)]
+	pub type ProposalDelegators<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, u64, Blake2_128Concat, T::AccountId, (u32, u32)>;
+
+	/// Storage for proposals that account can vote on, contains account id and proposal id as
+	/// keys.
+	#[pallet::storage]
+	#[pallet::getter(fn votable_proposals)]
+	pub type VotableProposals<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, u64, ()>;
+
+	/// Storage for number of open proposals of proposer, contains account id as key.
+	#[pallet::storage]
+	#[pallet::getter(fn active_proposals)]
+	pub type ActiveProposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	/// Storage for ended proposals whose NFTs are still being unlocked and whose votes are still
+	/// being removed in pages, contains collection id as key and proposal id along with account
+	/// that locked the NFTs as value.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_cleanups)]
+	pub type ProposalCleanups<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, (u64, T::AccountId)>;
+
+	/// Storage for origin modes of destination chains, contains destination parachain id as key.
+	#[pallet::storage]
+	#[pallet::getter(fn destination_origin_modes)]
+	pub type DestinationOriginModes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ParaId, OriginMode, ValueQuery>;
+
+	/// Storage for assets that pay execution on destination chains, contains destination
+	/// parachain id as key. Messages to destinations without fee asset use unpaid execution.
+	#[pallet::storage]
+	#[pallet::getter(fn destination_fees)]
+	pub type DestinationFees<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ParaId, Asset>;
+
+	/// Storage for index of xcNFT pallet on sibling chains, contains sibling parachain id as key.
+	/// Only calls descended from this pallet of a sibling chain are verified to be sent by its
+	/// xcNFT pallet.
+	#[pallet::storage]
+	#[pallet::getter(fn sibling_pallets)]
+	pub type SiblingPallets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ParaId, u8>;
+
+	/// Storage for sent messages, contains message id set through `SetTopic` as key. Only last
+	/// `MaxFinalizedMessages` messages with final delivery status are kept.
+	#[pallet::storage]
+	#[pallet::getter(fn outbound_messages)]
+	pub type OutboundMessages<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, XcmHash, OutboundMessage>;
+
+	/// Storage for queries awaiting status report of sent message, contains query id as key and
+	/// message id as value.
+	#[pallet::storage]
+	pub type PendingQueries<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, QueryId, XcmHash>;
+
+	/// Storage for last query whose status report was checked, checking continues after it in
+	/// next block.
+	#[pallet::storage]
+	pub type PendingQueryCursor<T: Config<I>, I: 'static = ()> = StorageValue<_, QueryId>;
+
+	/// Storage for sent messages with final delivery status, contains slot index as key and
+	/// message id as value. Message in the slot is removed from outbound messages once the slot
+	/// is reused.
+	#[pallet::storage]
+	pub type FinalizedMessages<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, u32, XcmHash>;
+
+	/// Storage for number of sent messages that reached final delivery status.
+	#[pallet::storage]
+	pub type FinalizedMessageCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u64, ValueQuery>;
+
+	/// Storage for number of sent messages, used to derive unique message ids.
+	#[pallet::storage]
+	pub type OutboundMessageCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u64, ValueQuery>;
+
+	/// Storage for messages that failed to send and are retried from `on_idle`, contains message
+	/// id as key.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::getter(fn outbound_queue)]
+	pub type OutboundQueue<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, XcmHash, QueuedMessage<T, I>>;
+
+	/// Storage for number of queued messages, bounded by `MaxQueuedMessages`.
+	#[pallet::storage]
+	pub type OutboundQueueLen<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// Storage for next nonce of senders, contains sender location as key.
+	#[pallet::storage]
+	#[pallet::getter(fn outbound_nonces)]
+	pub type OutboundNonces<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, Location, u64, ValueQuery>;
+
+	/// Storage for nonces received from senders, contains origin parachain id and account id of
+	/// the sender as keys.
+	#[pallet::storage]
+	pub type InboundNonces<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ParaId,
+		Blake2_128Concat,
+		T::AccountId,
+		NonceWindow,
+		ValueQuery,
+	>;
+
+	/// Storage for last prompts applied to collections, contains collection id as key and origin
+	/// parachain id with index of the prompt message on it as value.
+	#[pallet::storage]
+	pub type PromptNonces<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, (ParaId, u64)>;
+
+	/// Storage for flag set while call received in `parse_sequenced` is dispatched. Calls from
+	/// sibling chains are only accepted within it, so none of them skips replay protection.
+	#[pallet::storage]
+	pub type InSequencedCall<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
+	/// Storage for assets sent by transfer messages awaiting status report, contains message id as
+	/// key.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::getter(fn pending_transfers)]
+	pub type PendingTransfers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, XcmHash, PendingTransfer<T, I>>;
+
+	/// Storage for assets of transfers reported to fail on destination chain, which failed to be
+	/// restored along with the report, contains message id as key. Their owner restores them.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::getter(fn failed_transfers)]
+	pub type FailedTransfers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, XcmHash, PendingTransfer<T, I>>;
+
+	/// Storage for sent NFTs awaiting confirmation of asset they were received into, contains
+	/// collection id and asset id as tuple key and destination parachain id as value.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_confirmations)]
+	pub type PendingConfirmations<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, (T::CollectionId, T::ItemId), ParaId>;
+
+	/// Storage for received metadata that exceeded `StringLimit` and was truncated under
+	/// `MetadataPolicy::Overflow`, contains collection id and item id as keys and whole metadata
+	/// bounded by `MaxOverflowMetadata` as value. Item id `None` holds collection metadata.
+	#[pallet::storage]
+	#[pallet::getter(fn overflow_metadata)]
+	pub type OverflowMetadata<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		Option<T::ItemId>,
+		BoundedVec<u8, T::MaxOverflowMetadata>,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// Event emited when an empty collection is transferred cross-chain.
 		CollectionTransferred {
 			origin_collection_id: T::CollectionId,
-			origin_collection_metadata: BoundedVec<u8, T::StringLimit>,
+			origin_collection_metadata: Vec<u8>,
 			destination_para_id: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited when a collection and its NFTs are transferred cross-chain.
@@ -239,15 +853,27 @@ pub mod pallet {
 			origin_collection_id: T::CollectionId,
 			nft_ids: Vec<T::ItemId>,
 			destination_para_id: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited when a collection and its NFTs with different owners are transferred
 		/// cross-chain.
 		CollectionAndNFTsDiffTransferred {
 			origin_collection_id: T::CollectionId,
-			nfts: Vec<(T::ItemId, AccountIdLookupOf<T>, BoundedVec<u8, T::StringLimit>)>,
+			nfts: Vec<(T::ItemId, Location, Vec<u8>)>,
 			destination_para_id: ParaId,
 			to_address: AccountIdLookupOf<T>,
+			message_hash: XcmHash,
+		},
+
+		/// Event emited when NFTs of accounts that opted out of migration stay in the origin
+		/// collection.
+		ResidualCollectionRetained {
+			origin_collection_id: T::CollectionId,
+			proposal_id: u64,
+			nfts: Vec<T::ItemId>,
+			destination_para_id: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited when collection cross-chain transfer fails.
@@ -256,6 +882,7 @@ pub mod pallet {
 			collection_id: T::CollectionId,
 			owner: T::AccountId,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited when collection metadata update prompt is transferred cross-chain.
@@ -264,6 +891,7 @@ pub mod pallet {
 			proposed_data: BoundedVec<u8, T::StringLimit>,
 			owner: T::AccountId,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited when cross-chain collection metadata update prompt transfer fails.
@@ -273,6 +901,7 @@ pub mod pallet {
 			proposed_data: BoundedVec<u8, T::StringLimit>,
 			owner: T::AccountId,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited when cross-chain collection burn prompt transfer fails.
@@ -282,6 +911,7 @@ pub mod pallet {
 			burn_data: GeneralizedDestroyWitness,
 			owner: T::AccountId,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited when collection burn prompt is transferred cross-chain.
@@ -290,6 +920,7 @@ pub mod pallet {
 			burn_data: GeneralizedDestroyWitness,
 			owner: T::AccountId,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited when cross-chain collection ownership change prompt transfer fails.
@@ -298,6 +929,7 @@ pub mod pallet {
 			collection_id: T::CollectionId,
 			proposed_owner: AccountIdLookupOf<T>,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited when collection ownership change prompt is transferred cross-chain.
@@ -305,6 +937,7 @@ pub mod pallet {
 			collection_id: T::CollectionId,
 			proposed_owner: AccountIdLookupOf<T>,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emited on destination chain, when empty collection is received.
@@ -321,44 +954,18 @@ pub mod pallet {
 			to_address: AccountIdLookupOf<T>,
 		},
 
-		/// Event emited on destination chain, when empty collection fails to be created.
-		CollectionCreationFailed { error: DispatchError, owner: AccountIdLookupOf<T> },
-
-		/// Event emited on destination chain, when collection burn prompt fails to execute.
-		CollectionBurnFailed {
-			error: DispatchErrorWithPostInfo<PostDispatchInfo>,
-			collection_id: T::CollectionId,
-			owner: AccountIdLookupOf<T>,
-		},
-
-		/// Event emited on destination chain, when collection metadata update prompt fails to
-		/// execute.
-		CollectionMetadataSetFailed {
-			error: DispatchError,
-			collection_id: T::CollectionId,
-			owner: AccountIdLookupOf<T>,
-		},
-
-		/// Event emited on destination chain, when collection ownership change prompt fails to
-		/// execute.
-		CollectionOwnershipTransferFailed {
-			error: DispatchError,
-			collection_id: T::CollectionId,
-			owner: AccountIdLookupOf<T>,
-		},
-
 		/// Event emited on destination chain, when collection and its NFT are successfuly
 		/// received.
 		CollectionWithNftsReceived {
 			collection_id: T::CollectionId,
-			items: Vec<(T::ItemId, BoundedVec<u8, T::StringLimit>)>,
+			items: Vec<(T::ItemId, Vec<u8>)>,
 		},
 
 		/// Event emited on destination chain, when collection and its NFTs with different owners
 		/// are successfuly received.
 		CollectionWithNftsDiffOwnersReceived {
 			collection_id: T::CollectionId,
-			items: Vec<(T::ItemId, AccountIdLookupOf<T>, BoundedVec<u8, T::StringLimit>)>,
+			items: Vec<(T::ItemId, Location, Vec<u8>)>,
 		},
 
 		/// Event emitted when collection cross-chain transfer proposal is created (Collection
@@ -379,13 +986,99 @@ pub mod pallet {
 		/// Event emitted when proposal did not pass
 		ProposalDidNotPass { proposal_id: u64 },
 
+		/// Event emitted when proposal is cancelled by the proposer
+		ProposalCancelled { proposal_id: u64 },
+
+		/// Event emitted when part of proposal deposit is slashed because proposal did not pass
+		ProposalDepositSlashed { proposal_id: u64, proposer: T::AccountId, amount: BalanceOf<T, I> },
+
+		/// Event emitted when next page of proposal snapshot is taken
+		ProposalSnapshotUpdated { proposal_id: u64, complete: bool },
+
+		/// Event emitted when next page of ended proposal is cleaned up
+		ProposalCleanupUpdated { proposal_id: u64, complete: bool },
+
+		/// Event emitted when collection owner allows or disallows receiving NFTs from origin
+		IncomingAllowlistUpdated {
+			collection_id: T::CollectionId,
+			origin_para_id: ParaId,
+			origin_collection_id: Option<T::CollectionId>,
+			allowed: bool,
+		},
+
+		/// Event emitted when pallet account creates collection for received NFTs
+		DerivativeCollectionCreated {
+			collection_id: T::CollectionId,
+			origin_para_id: ParaId,
+			origin_collection_id: T::CollectionId,
+		},
+
+		/// Event emitted when status of sent message is updated from destination chain report
+		OutboundMessageStatusUpdated { message_hash: XcmHash, status: MessageStatus },
+
+		/// Event emitted when message that failed to send is queued to be retried
+		MessageQueued {
+			message_hash: XcmHash,
+			owner: T::AccountId,
+			e: SendError,
+			next_attempt: BlockNumberFor<T>,
+		},
+
+		/// Event emitted when queued message is sent
+		QueuedMessageSent { message_hash: XcmHash, attempts: u32 },
+
+		/// Event emitted when queued message is dropped after its last failed attempt
+		QueuedMessageDropped { message_hash: XcmHash, e: SendError },
+
+		/// Event emitted when owner cancels queued message
+		QueuedMessageCancelled { message_hash: XcmHash, owner: T::AccountId },
+
+		/// Event emitted when assets of transfer that failed on destination chain are restored
+		TransferRestored {
+			message_hash: XcmHash,
+			collection_id: T::CollectionId,
+			error: MaybeErrorCode,
+		},
+
+		/// Event emitted when assets of transfer that failed on destination chain fail to be
+		/// restored, they are kept until their owner restores them
+		TransferRestoreFailed { message_hash: XcmHash, error: DispatchError },
+
+		/// Event emitted when owner restores assets of failed transfer
+		FailedTransferRestored {
+			message_hash: XcmHash,
+			collection_id: T::CollectionId,
+			owner: T::AccountId,
+		},
+
+		/// Event emitted when received metadata exceeds `StringLimit` and metadata policy is
+		/// applied to it
+		MetadataPolicyApplied {
+			collection_id: T::CollectionId,
+			item_id: Option<T::ItemId>,
+			policy: MetadataPolicy,
+			length: u32,
+		},
+
+		/// Event emitted when a proposal vote is withdrawn
+		CrossChainProposalVoteWithdrawn { proposal_id: u64, voter: T::AccountId },
+
+		/// Event emitted when a proposal vote is delegated, or delegation is removed if delegate
+		/// is None
+		CrossChainProposalVoteDelegated {
+			proposal_id: u64,
+			delegator: T::AccountId,
+			delegate: Option<T::AccountId>,
+		},
+
 		/// Event emitted when non-fungible asset is transferred cross-chain
 		NFTTransferred {
 			origin_collection_id: T::CollectionId,
 			origin_asset_id: T::ItemId,
 			destination_para_id: ParaId,
 			destination_collection_id: T::CollectionId,
-			destination_asset_id: T::ItemId,
+			destination_asset_id: Option<T::ItemId>,
+			message_hash: XcmHash,
 		},
 
 		/// Event emitted when non-fungible asset is claimed (Its origin collection was sent
@@ -405,6 +1098,7 @@ pub mod pallet {
 			proposed_data: BoundedVec<u8, T::StringLimit>,
 			owner: T::AccountId,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emitted when NFT metadata update prompt is transferred cross-chain.
@@ -414,6 +1108,7 @@ pub mod pallet {
 			proposed_data: BoundedVec<u8, T::StringLimit>,
 			owner: T::AccountId,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emitted when cross-chain NFT burn prompt transfer fails.
@@ -423,6 +1118,7 @@ pub mod pallet {
 			asset_id: T::ItemId,
 			owner: T::AccountId,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emitted when NFT burn prompt is transferred cross-chain.
@@ -431,6 +1127,7 @@ pub mod pallet {
 			asset_id: T::ItemId,
 			owner: T::AccountId,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emitted when cross-chain NFT ownership change prompt transfer fails.
@@ -440,6 +1137,7 @@ pub mod pallet {
 			asset_id: T::ItemId,
 			proposed_owner: AccountIdLookupOf<T>,
 			destination: ParaId,
+			message_hash: XcmHash,
 		},
 
 		/// Event emitted when NFT ownership change prompt is transferred cross-chain.
@@ -448,38 +1146,7 @@ pub mod pallet {
 			asset_id: T::ItemId,
 			proposed_owner: AccountIdLookupOf<T>,
 			destination: ParaId,
-		},
-
-		/// Event emitted on destination chain, when NFT burn prompt fails to execute.
-		NFTBurnFailed {
-			error: DispatchError,
-			collection_id: T::CollectionId,
-			asset_id: T::ItemId,
-			owner: AccountIdLookupOf<T>,
-		},
-
-		/// Event emitted on destination chain, when NFT metadata update prompt fails to execute.
-		NFTMetadataSetFailed {
-			error: DispatchError,
-			collection_id: T::CollectionId,
-			asset_id: T::ItemId,
-			owner: AccountIdLookupOf<T>,
-		},
-
-		/// Event emitted on destination chain, when NFT ownership change prompt fails to execute.
-		NFTOwnershipTransferFailed {
-			error: DispatchError,
-			collection_id: T::CollectionId,
-			asset_id: T::ItemId,
-			owner: AccountIdLookupOf<T>,
-		},
-
-		/// Event emitted on destination chain, when NFT fails to be minted.
-		NFTMintFailed {
-			error: DispatchError,
-			collection_id: T::CollectionId,
-			asset_id: T::ItemId,
-			owner: AccountIdLookupOf<T>,
+			message_hash: XcmHash,
 		},
 
 		/// Event emitted on destination chain, when NFT is successfully received along with
@@ -500,8 +1167,31 @@ pub mod pallet {
 			to_address: T::AccountId,
 		},
 
-		/// Event emitted when collection fails to mint on destination chain
-		CollectionMintFailed { error: DispatchError },
+		/// Event emitted on origin chain, when destination chain reports collection and asset
+		/// that sent NFT was received into.
+		NFTTransferConfirmed {
+			collection_id: T::CollectionId,
+			asset_id: T::ItemId,
+			destination_collection_id: T::CollectionId,
+			destination_asset_id: T::ItemId,
+		},
+
+		/// Event emitted on origin chain, when destination chain reports collection that NFTs of
+		/// residual collection were migrated into.
+		CollectionTransferConfirmed {
+			collection_id: T::CollectionId,
+			destination_para_id: ParaId,
+			destination_collection_id: T::CollectionId,
+		},
+
+		/// Event emitted when origin mode of destination chain is set.
+		DestinationOriginModeSet { destination_para_id: ParaId, mode: OriginMode },
+
+		/// Event emitted when fee asset of destination chain is set or removed.
+		DestinationFeeSet { destination_para_id: ParaId, fee: Option<Asset> },
+
+		/// Event emitted when index of xcNFT pallet of sibling chain is set or removed.
+		SiblingPalletSet { sibling_para_id: ParaId, pallet_index: Option<u8> },
 	}
 
 	#[pallet::error]
@@ -540,12 +1230,18 @@ pub mod pallet {
 		/// Error returned when user has already voted the same vote.
 		AlreadyVotedThis,
 
-		/// Error returned when maximum number of owners is reached.
-		MaxOwnersReached,
-
 		/// Error returned when user is not NFT owner.
 		NotNFTOwner,
 
+		/// Error returned when NFT is locked because its collection is part of an open proposal.
+		CollectionUnderProposal,
+
+		/// Error returned when beneficiary is not a 32 or 20 byte account location.
+		InvalidBeneficiary,
+
+		/// Error returned when account cannot be converted into location.
+		AccountNotConvertible,
+
 		/// Error returned when NFT is not received, but user wants to claim it into different
 		/// collection.
 		NFTNotReceived,
@@ -553,21 +1249,115 @@ pub mod pallet {
 		/// Error, that shouldn't happen.
 		NoNextCollectionId,
 
+		/// Error returned when no free item id is left in collection.
+		NoNextItemId,
+
+		/// Error returned when NFT was not sent cross-chain.
+		NFTNotSent,
+
+		/// Error returned when collection did not stay on origin chain as residual collection.
+		CollectionNotResidual,
+
 		/// Error returned when user enters wrong origin collection id.
 		WrongOriginCollectionAtOrigin,
-	}
 
-	#[pallet::call]
-	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		/// Transfer a Collection along with its associated metadata / assets to another parachain.
-		///
-		/// Origin must be Signed and the signing account must be :
-		/// - the Owner of the `Collection`;
-		///
-		/// Arguments:
-		/// - `origin_collection`: The collection_id of the collection to be transferred.
-		/// - `destination_para`: The destination chain ID to which collection is transferred.
+		/// Error returned when user holds no NFTs of the collection and thus cannot vote.
+		NoVotingPower,
+
+		/// Error returned when user has not voted on the proposal.
+		NotVoted,
+
+		/// Error returned when user delegated their vote and thus cannot vote directly.
+		VoteDelegated,
+
+		/// Error returned when user tries to delegate their vote to themselves.
+		CannotDelegateToSelf,
+
+		/// Error returned when proposal snapshot is not complete yet, so it cannot be voted on.
+		SnapshotIncomplete,
+
+		/// Error returned when proposal snapshot is already complete.
+		SnapshotComplete,
+
+		/// Error returned when ended proposal of the collection is still being cleaned up.
+		ProposalCleanupPending,
+
+		/// Error returned when collection has no ended proposal left to clean up.
+		NoProposalCleanup,
+
+		/// Error returned when proposer already has maximum number of open proposals.
+		TooManyActiveProposals,
+
+		/// Error returned when call is not sent by the chain it is received from.
+		InvalidSender,
+
+		/// Error returned when fee asset is not fungible.
+		InvalidFeeAsset,
+
+		/// Error returned when transferred payload does not fit into destination block.
+		PayloadTooHeavy,
+
+		/// Error returned when queued message does not exist.
+		QueuedMessageNotFound,
+
+		/// Error returned when caller is not the owner of queued message.
+		NotQueuedMessageOwner,
+
+		/// Error returned when message with the same nonce was already received from sender.
+		DuplicateMessage,
+
+		/// Error returned when prompt is older than last prompt applied to the collection.
+		StalePrompt,
+
+		/// Error returned when call from sibling chain is not wrapped in a single
+		/// `parse_sequenced` call.
+		UnsequencedMessage,
+
+		/// Error returned when received metadata exceeds `StringLimit` and metadata policy rejects
+		/// it.
+		MetadataTooLong,
+
+		/// Error returned when received metadata hashes do not cover the transferred metadata.
+		MetadataHashMismatch,
+
+		/// Error returned when call on behalf of another account was not verified to come from
+		/// xcNFT pallet of another chain.
+		UnverifiedSender,
+
+		/// Error returned when no assets of failed transfer sent by the message await
+		/// restoration.
+		TransferNotPending,
+
+		/// Error returned when caller did not send assets of the transfer.
+		NotTransferOwner,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let consumed = Self::update_outbound_messages(now, remaining_weight);
+
+			consumed.saturating_add(Self::retry_queued_messages(
+				now,
+				remaining_weight.saturating_sub(consumed),
+			))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Transfer a Collection along with its associated metadata / assets to another parachain.
+		///
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the `Collection`;
+		///
+		/// Arguments:
+		/// - `origin_collection`: The collection_id of the collection to be transferred.
+		/// - `destination_para`: The destination chain ID to which collection is transferred.
 		/// - `config`: The config of transferred collection.
+		/// - `beneficiary`: The account location that owns the collection on destination chain.
+		/// - `deposit_asset`: Optional native or reserve asset in which the sender funds storage
+		///   deposits of the beneficiary on destination chain.
 		///
 		/// On success emits `CollectionTransferred` or `CollectionAndNFTsTransferred`.
 		///
@@ -580,9 +1370,13 @@ pub mod pallet {
 			destination_collection: Option<T::CollectionId>,
 			destination_para: ParaId,
 			config: Option<CollectionConfigFor<T, I>>,
+			beneficiary: Location,
+			deposit_asset: Option<AssetId>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin.clone())?;
 
+			ensure!(Self::is_account_location(&beneficiary), Error::<T, I>::InvalidBeneficiary);
+
 			// See if collection exists
 			ensure!(
 				pallet_nfts::Collection::<T, I>::contains_key(&origin_collection),
@@ -606,56 +1400,55 @@ pub mod pallet {
 				items.push(item_id);
 			}
 
-			// First check if collection contains any metadata if it does, then save it
-			let mut collection_metadata = None;
-			if pallet_nfts::CollectionMetadataOf::<T, I>::contains_key(&origin_collection) {
-				collection_metadata = Some(
-					pallet_nfts::CollectionMetadataOf::<T, I>::get(origin_collection).unwrap().data,
-				);
-			}
-
-			// If collection metadata is not present, then create empty metadata
-			if collection_metadata.is_none() {
-				collection_metadata = Some(BoundedVec::new());
-			}
+			// Collection metadata is sent whole, empty if collection has none
+			let collection_metadata = Self::sent_metadata(&origin_collection, None);
 
-			// Convert accountId into accountid32
-			let account_vec = who.encode();
-			ensure!(account_vec.len() == 32, "AccountId must be 32 bytes.");
-			let mut bytes = [0u8; 32];
-			bytes.copy_from_slice(&account_vec);
+			// Convert account into location used as origin on destination chain
+			let who_location =
+				Self::account_to_location(&who).ok_or(Error::<T, I>::AccountNotConvertible)?;
 
 			// Check if the collection is empty (items array is empty)
 			if items.is_empty() {
+				// Storage deposits of the beneficiary on destination chain are funded by the sender
+				let deposit = Self::deposit_funding(
+					deposit_asset,
+					Self::collection_deposit(&collection_metadata, []),
+					&beneficiary,
+				);
+
+				let call = Call::<T, I>::parse_collection_empty {
+					origin_collection: origin_collection.clone(),
+					destination_collection,
+					collection_metadata: collection_metadata.clone(),
+					config,
+					beneficiary: beneficiary.clone(),
+				};
+
+				// Payload has to fit into a block of destination chain
+				ensure!(
+					Self::transact_weight(&call).all_lte(T::MaxTransactWeight::get()),
+					Error::<T, I>::PayloadTooHeavy
+				);
+
 				// Transfer the empty collection to the destination parachain
-				match send_xcm::<T::XcmSender>(
-					(Parent, Junction::Parachain(destination_para.into())).into(),
-					Xcm(vec![
-						UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-						AliasOrigin(
-							xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-								.into(),
-						),
-						Transact {
-							origin_kind: OriginKind::SovereignAccount,
-							call: <T as Config<I>>::RuntimeCall::from(
-								Call::<T, I>::parse_collection_empty {
-									origin_collection: origin_collection.clone(),
-									destination_collection,
-									collection_metadata: collection_metadata.clone().unwrap(),
-									config,
-								},
-							)
-							.encode()
-							.into(),
-						},
-					]),
+				match Self::send_transact(
+					destination_para,
+					who_location.clone(),
+					OriginKind::SovereignAccount,
+					call,
+					deposit,
 				) {
-					Ok((_hash, _cost)) => {
+					Ok((message_hash, _cost)) => {
+						// Collection is restored if it fails to be received on destination chain
+						Self::record_pending_collection(
+							message_hash,
+							&who,
+							&origin_collection,
+							&items,
+						);
+
 						// If collection was received, remove from received collections
-						if ReceivedCollections::<T, I>::contains_key(&origin_collection) {
-							ReceivedCollections::<T, I>::remove(&origin_collection);
-						}
+						Self::remove_received_collection(&origin_collection);
 
 						// Get collection from the storage
 						let collection =
@@ -677,17 +1470,20 @@ pub mod pallet {
 
 						// Emit an success event
 						Self::deposit_event(Event::CollectionTransferred {
+							message_hash,
 							origin_collection_id: origin_collection,
-							origin_collection_metadata: collection_metadata.unwrap(),
+							origin_collection_metadata: collection_metadata,
 							destination_para_id: destination_para,
 						});
 					},
-					Err(e) => Self::deposit_event(Event::CollectionFailedToXCM {
-						e,
-						collection_id: origin_collection,
-						owner: who.clone(),
-						destination: destination_para,
-					}),
+					Err((message_hash, e, _)) =>
+						Self::deposit_event(Event::CollectionFailedToXCM {
+							e,
+							message_hash,
+							collection_id: origin_collection,
+							owner: who.clone(),
+							destination: destination_para,
+						}),
 				}
 			} else {
 				// Check if all the NFTs are owned by the same owner
@@ -698,30 +1494,28 @@ pub mod pallet {
 						pallet_nfts::Pallet::<T, I>::owner(origin_collection, item_id)
 					{
 						if nft_owner != collection_owner {
-							for (_data, proposal) in CrossChainProposals::<T, I>::iter() {
-								if proposal.collection_id == origin_collection {
-									return Err(Error::<T, I>::ProposalAlreadyExists.into());
-								}
-							}
+							ensure!(
+								!ProposalOfCollection::<T, I>::contains_key(&origin_collection),
+								Error::<T, I>::ProposalAlreadyExists
+							);
 
-							// Find out how many different NFT owners does the collection have
-							let mut different_owners = BoundedVec::new();
-
-							for item_id in items.clone() {
-								if let Some(nft_owner) =
-									pallet_nfts::Pallet::<T, I>::owner(origin_collection, item_id)
-								{
-									if nft_owner != collection_owner {
-										// Check if owner is not present in different owners
-										if !different_owners.contains(&nft_owner) {
-											different_owners.try_push(nft_owner).ok();
-										}
-									}
-								}
-							}
+							// NFTs locked by ended proposal have to be unlocked first
+							ensure!(
+								!ProposalCleanups::<T, I>::contains_key(&origin_collection),
+								Error::<T, I>::ProposalCleanupPending
+							);
+
+							// Check if proposer did not reach the limit of open proposals
+							let active_proposals = ActiveProposals::<T, I>::get(&who).unwrap_or(0);
+							ensure!(
+								active_proposals < T::MaxActiveProposals::get(),
+								Error::<T, I>::TooManyActiveProposals
+							);
 
-							// Also add collection owner
-							different_owners.try_push(collection_owner.clone()).ok();
+							// Reserve proposal deposit, it is returned once the proposal ends
+							let deposit = T::ProposalDeposit::get();
+							T::Currency::reserve(&who, deposit)?;
+							ActiveProposals::<T, I>::insert(&who, active_proposals + 1);
 
 							let proposal_id = NextProposalId::<T, I>::get();
 
@@ -736,21 +1530,36 @@ pub mod pallet {
 							let block_n: BlockNumberFor<T> =
 								frame_system::Pallet::<T>::block_number();
 
-							let proposal = Proposal::<T, I> {
+							let mut proposal = Proposal::<T, I> {
 								proposal_id,
 								collection_id: origin_collection,
 								proposed_collection_owner: who.clone(),
+								proposed_beneficiary: beneficiary.clone(),
+								proposed_deposit_asset: deposit_asset.clone(),
 								proposed_destination_config: config,
 								proposed_dest_collection_id: destination_collection,
 								proposed_destination_para: destination_para,
-								owners: different_owners,
-								number_of_votes: Votes {
-									aye: BoundedVec::new(),
-									nay: BoundedVec::new(),
-								},
+								total_weight: 0,
+								aye_weight: 0,
+								nay_weight: 0,
+								snapshot_cursor: None,
+								snapshot_complete: false,
+								voting_rule: T::VotingStrategy::rule(),
+								migration_mode: T::ProposalMigrationMode::get(),
+								deposit,
 								end_time: block_n + T::ProposalTimeInBlocks::get().into(),
 							};
 
+							// Collection owner can vote even if they do not hold any NFTs
+							ProposalOwners::<T, I>::insert(proposal_id, &collection_owner, 0);
+							VotableProposals::<T, I>::insert(&collection_owner, proposal_id, ());
+
+							// Snapshot first page of NFT owners, remaining pages are snapshotted
+							// through `collection_x_transfer_snapshot`
+							Self::snapshot_page(&mut proposal)?;
+
+							ProposalOfCollection::<T, I>::insert(origin_collection, proposal_id);
+
 							<CrossChainProposals<T, I>>::insert(proposal_id, proposal);
 
 							Self::deposit_event(Event::CollectionTransferProposalCreated {
@@ -765,71 +1574,74 @@ pub mod pallet {
 					}
 				}
 
-				// We get there, because collection owner is the same as NFT owners
-				let mut collection_metadata = None;
-
-				if pallet_nfts::CollectionMetadataOf::<T, I>::contains_key(&origin_collection) {
-					collection_metadata = Some(
-						pallet_nfts::CollectionMetadataOf::<T, I>::get(origin_collection)
-							.unwrap()
-							.data,
-					);
-				}
-
-				if collection_metadata.is_none() {
-					collection_metadata = Some(BoundedVec::new());
-				}
-
-				// Get NFT configs
+				// We get there, because collection owner is the same as NFT owners, get NFT
+				// metadata, empty if NFT has none
 				let mut nft_metadata = Vec::new();
 				for item_id in items.clone() {
-					if pallet_nfts::ItemMetadataOf::<T, I>::contains_key(
-						&origin_collection,
-						item_id,
-					) {
-						let item_details =
-							pallet_nfts::ItemMetadataOf::<T, I>::get(origin_collection, item_id)
-								.unwrap()
-								.data;
-						nft_metadata.push((item_id, item_details));
-					} else {
-						// Add empty metadata
-						nft_metadata.push((item_id, BoundedVec::new()));
-					}
+					nft_metadata
+						.push((item_id, Self::sent_metadata(&origin_collection, Some(&item_id))));
 				}
 
+				// Storage deposits of the beneficiary on destination chain are funded by the sender
+				let deposit = Self::deposit_funding(
+					deposit_asset,
+					Self::collection_deposit(
+						&collection_metadata,
+						nft_metadata.iter().map(|(_, data)| data.as_slice()),
+					),
+					&beneficiary,
+				);
+
+				// Destination chain verifies received metadata against hashes of sent metadata
+				let metadata_hashes = nft_metadata
+					.iter()
+					.map(|(item_id, data)| {
+						Self::metadata_hashes(
+							&origin_collection,
+							item_id,
+							Some(collection_metadata.as_slice()),
+							data,
+						)
+					})
+					.collect();
+
+				let call = Call::<T, I>::parse_collection_same_owner {
+					origin_collection_id: origin_collection.clone(),
+					origin_para: parachain_info::Pallet::<T>::parachain_id(),
+					collection_metadata,
+					nfts: nft_metadata,
+					metadata_hashes,
+					dest_collection_id: destination_collection.clone(),
+					config,
+					beneficiary: beneficiary.clone(),
+				};
+
+				// Payload has to fit into a block of destination chain
+				ensure!(
+					Self::transact_weight(&call).all_lte(T::MaxTransactWeight::get()),
+					Error::<T, I>::PayloadTooHeavy
+				);
+
 				// Send the collection and nfts along with associated metadata to the destination
 				// parachain
-				match send_xcm::<T::XcmSender>(
-					(Parent, Junction::Parachain(destination_para.into())).into(),
-					Xcm(vec![
-						UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-						AliasOrigin(
-							xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-								.into(),
-						),
-						Transact {
-							origin_kind: OriginKind::SovereignAccount,
-							call: <T as Config<I>>::RuntimeCall::from(
-								Call::<T, I>::parse_collection_same_owner {
-									origin_collection_id: origin_collection.clone(),
-									origin_para: parachain_info::Pallet::<T>::parachain_id(),
-									collection_metadata: collection_metadata.unwrap(),
-									nfts: nft_metadata,
-									dest_collection_id: destination_collection.clone(),
-									config,
-								},
-							)
-							.encode()
-							.into(),
-						},
-					]),
+				match Self::send_transact(
+					destination_para,
+					who_location.clone(),
+					OriginKind::SovereignAccount,
+					call,
+					deposit,
 				) {
-					Ok((_hash, _cost)) => {
+					Ok((message_hash, _cost)) => {
+						// Collection is restored if it fails to be received on destination chain
+						Self::record_pending_collection(
+							message_hash,
+							&who,
+							&origin_collection,
+							&items,
+						);
+
 						// If collection was received, remove from received collections
-						if ReceivedCollections::<T, I>::contains_key(&origin_collection) {
-							ReceivedCollections::<T, I>::remove(&origin_collection);
-						}
+						Self::remove_received_collection(&origin_collection);
 
 						// Burning the NFTs
 						for item_id in items.clone() {
@@ -860,17 +1672,20 @@ pub mod pallet {
 
 						// Emit a success event
 						Self::deposit_event(Event::CollectionAndNFTsTransferred {
+							message_hash,
 							origin_collection_id: origin_collection,
 							nft_ids: items,
 							destination_para_id: destination_para,
 						});
 					},
-					Err(e) => Self::deposit_event(Event::CollectionFailedToXCM {
-						e,
-						collection_id: origin_collection,
-						owner: who.clone(),
-						destination: destination_para,
-					}),
+					Err((message_hash, e, _)) =>
+						Self::deposit_event(Event::CollectionFailedToXCM {
+							e,
+							message_hash,
+							collection_id: origin_collection,
+							owner: who.clone(),
+							destination: destination_para,
+						}),
 				}
 			}
 			Ok(().into())
@@ -884,6 +1699,8 @@ pub mod pallet {
 		/// Arguments:
 		/// - `proposal_id`: The cross-chain proposal ID.
 		/// - `actual_vote`: Enum type - either Aye or Nay.
+		/// - `beneficiary`: Optional account on destination chain that receives voter's NFTs,
+		///   either 32 or 20 byte account. If not provided, NFTs are received by voter's account.
 		///
 		/// On success emits `CrossChainPropoposalVoteRegistered`.
 		#[pallet::call_index(1)]
@@ -892,6 +1709,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			proposal_id: u64,
 			actual_vote: Vote,
+			beneficiary: Option<Location>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
@@ -901,84 +1719,79 @@ pub mod pallet {
 				Error::<T, I>::ProposalDoesNotExist
 			);
 
+			// Check if beneficiary is an account the destination chain can mint to
+			if let Some(location) = &beneficiary {
+				ensure!(Self::is_account_location(location), Error::<T, I>::InvalidBeneficiary);
+			}
+
 			// Get the proposal
 			let mut unwrapped_proposal = CrossChainProposals::<T, I>::get(proposal_id).unwrap();
 
-			// See if the user can vote, check if they are in the owners list
-			ensure!(unwrapped_proposal.owners.contains(&who), Error::<T, I>::NotNFTOwner);
-
-			// Check if the proposal is still active
 			let block_n: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
 
-			if block_n > unwrapped_proposal.end_time {
-				let number_of_votes = &unwrapped_proposal.number_of_votes.aye.len() +
-					&unwrapped_proposal.number_of_votes.nay.len();
+			// Voting starts once the snapshot of owners is complete
+			ensure!(
+				unwrapped_proposal.snapshot_complete || block_n > unwrapped_proposal.end_time,
+				Error::<T, I>::SnapshotIncomplete
+			);
 
-				// If proposal did not pass (Less than 50% of votes are aye) remove proposal from
+			// See if the user can vote, check if they are in the owners list or if other owners
+			// delegated their vote to them
+			let snapshot_weight = ProposalOwners::<T, I>::get(proposal_id, &who)
+				.or_else(|| Self::has_delegators(proposal_id, &who).then_some(0))
+				.ok_or(Error::<T, I>::NotNFTOwner)?;
+
+			// Check if the proposal is still active
+			if block_n > unwrapped_proposal.end_time {
+				// If proposal did not pass according to its voting rule remove proposal from
 				// storage and emit event.
-				if unwrapped_proposal.number_of_votes.aye.len() < number_of_votes / 2 ||
-					unwrapped_proposal.number_of_votes.aye.len() == 0 &&
-						unwrapped_proposal.number_of_votes.nay.len() == 0 ||
-					unwrapped_proposal.number_of_votes.aye.len() == 0 &&
-						unwrapped_proposal.number_of_votes.nay.len() == 1
-				{
-					CrossChainProposals::<T, I>::remove(proposal_id);
+				if !Self::proposal_passed(&unwrapped_proposal) {
+					Self::remove_proposal(&unwrapped_proposal, true);
 
 					Self::deposit_event(Event::ProposalDidNotPass { proposal_id });
 
 					return Ok(().into());
 				}
 
-				CrossChainProposals::<T, I>::remove(proposal_id);
+				Self::remove_proposal(&unwrapped_proposal, false);
 
 				Self::deposit_event(Event::ProposalExpired { proposal_id });
 
 				return Ok(().into());
 			}
 
+			// Check if the user did not delegate their vote
+			ensure!(
+				!ProposalDelegations::<T, I>::contains_key(proposal_id, &who),
+				Error::<T, I>::VoteDelegated
+			);
+
+			// Weight of the vote is number of NFTs held when snapshot was taken, which stay locked
+			// while the proposal is open, along with weight delegated to the user
+			let weight = snapshot_weight.saturating_add(Self::delegated_weight(proposal_id, &who));
+			ensure!(
+				weight > 0 || Self::has_delegators(proposal_id, &who),
+				Error::<T, I>::NoVotingPower
+			);
+
 			// Check if the user has already voted, if they did, see if they voted the same or
 			// different. If same, return error and if different, update the vote
-			if unwrapped_proposal.number_of_votes.aye.contains(&who) {
-				if actual_vote == Vote::Nay {
-					unwrapped_proposal.number_of_votes.aye.retain(|x| x != &who);
-					unwrapped_proposal
-						.number_of_votes
-						.nay
-						.try_push(who.clone())
-						.map_err(|_| Error::<T, I>::MaxOwnersReached)?;
-				} else {
-					return Err(Error::<T, I>::AlreadyVotedThis.into());
-				}
-			} else if unwrapped_proposal.number_of_votes.nay.contains(&who) {
-				if actual_vote == Vote::Aye {
-					unwrapped_proposal.number_of_votes.nay.retain(|x| x != &who);
-					unwrapped_proposal
-						.number_of_votes
-						.aye
-						.try_push(who.clone())
-						.map_err(|_| Error::<T, I>::MaxOwnersReached)?;
-				} else {
-					return Err(Error::<T, I>::AlreadyVotedThis.into());
-				}
-			} else {
-				if actual_vote == Vote::Aye {
-					unwrapped_proposal
-						.number_of_votes
-						.aye
-						.try_push(who.clone())
-						.map_err(|_| Error::<T, I>::MaxOwnersReached)?;
-				} else {
-					unwrapped_proposal
-						.number_of_votes
-						.nay
-						.try_push(who.clone())
-						.map_err(|_| Error::<T, I>::MaxOwnersReached)?;
-				}
+			if let Some((previous_vote, previous_weight)) =
+				ProposalVotes::<T, I>::get(proposal_id, &who)
+			{
+				ensure!(previous_vote != actual_vote, Error::<T, I>::AlreadyVotedThis);
+				Self::tally_vote(&mut unwrapped_proposal, &previous_vote, 0, previous_weight);
 			}
 
-			// Update the proposal
+			ProposalVotes::<T, I>::insert(proposal_id, &who, (actual_vote.clone(), weight));
+			Self::tally_vote(&mut unwrapped_proposal, &actual_vote, weight, 0);
 			CrossChainProposals::<T, I>::insert(proposal_id, unwrapped_proposal);
 
+			// Remember where the voter wants to receive their NFTs
+			if let Some(location) = beneficiary {
+				ProposalBeneficiaries::<T, I>::insert(proposal_id, who.clone(), location);
+			}
+
 			//Emit a success event
 			Self::deposit_event(Event::CrossChainPropoposalVoteRegistered {
 				proposal_id,
@@ -997,11 +1810,18 @@ pub mod pallet {
 		///
 		/// Prereqiuisites:
 		/// - Collection must be associated with proposal that has passed.
+		/// - Proposal must have ended, or every owner holding NFTs must have voted Aye.
+		///
+		/// If proposal was created in `OptOut` migration mode, NFTs of accounts that voted Nay are
+		/// not transferred and the collection stays on origin chain as residual collection. NFTs
+		/// minted after the snapshot of owners was taken stay in the residual collection as well,
+		/// and votes are counted only for snapshotted NFTs that were not burned since.
 		///
 		/// Arguments:
 		/// - `proposal_id`: The cross-chain proposal ID.
 		///
-		/// On success emits `CollectionAndNFTsDiffTransferred`.
+		/// On success emits `CollectionAndNFTsDiffTransferred` and `ResidualCollectionRetained` if
+		/// some NFTs stayed on origin chain.
 		#[pallet::call_index(2)]
 		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
 		pub fn collection_x_transfer_initiate(
@@ -1017,7 +1837,7 @@ pub mod pallet {
 			);
 
 			//Check if owner of the collection is the one who initiated the transfer
-			let proposal = CrossChainProposals::<T, I>::get(proposal_id).unwrap();
+			let mut proposal = CrossChainProposals::<T, I>::get(proposal_id).unwrap();
 
 			ensure!(
 				pallet_nfts::Pallet::<T, I>::collection_owner(proposal.collection_id.clone())
@@ -1029,41 +1849,28 @@ pub mod pallet {
 			// Check if the proposal is active or not
 			let block_n: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
 
-			if block_n < proposal.end_time {
+			ensure!(proposal.snapshot_complete, Error::<T, I>::SnapshotIncomplete);
+
+			// NFTs burned since the snapshot no longer count for votes of their owners
+			Self::recount_votes(&mut proposal);
+
+			// Proposal can be initiated early once every owner voted Aye
+			if block_n < proposal.end_time && !Self::all_owners_voted_aye(&proposal) {
 				return Err(Error::<T, I>::ProposalStillActive.into());
 			}
 
-			// Check if the proposal passed
-			let number_of_votes =
-				proposal.number_of_votes.aye.len() + proposal.number_of_votes.nay.len();
-
-			if proposal.number_of_votes.aye.len() < number_of_votes / 2 ||
-				proposal.number_of_votes.aye.len() == 0 &&
-					proposal.number_of_votes.nay.len() == 0 ||
-				proposal.number_of_votes.aye.len() == 0 &&
-					proposal.number_of_votes.nay.len() == 1
-			{
+			// Check if the proposal passed, proposal whose snapshotted NFTs were all burned has no
+			// owners left to decide it
+			if proposal.total_weight > 0 && !Self::proposal_passed(&proposal) {
 				Self::deposit_event(Event::ProposalDidNotPass { proposal_id });
 
 				// Remove the proposal
-				CrossChainProposals::<T, I>::remove(proposal_id);
+				Self::remove_proposal(&proposal, true);
 
 				return Ok(().into());
-			} else if proposal.number_of_votes.aye.len() >= number_of_votes / 2 {
+			} else {
 				// Get the collection metadata
-				let mut collection_metadata = Some(BoundedVec::new());
-
-				if pallet_nfts::CollectionMetadataOf::<T, I>::contains_key(
-					proposal.collection_id.clone(),
-				) {
-					collection_metadata = Some(
-						pallet_nfts::CollectionMetadataOf::<T, I>::get(
-							proposal.collection_id.clone(),
-						)
-						.unwrap()
-						.data,
-					);
-				}
+				let collection_metadata = Self::sent_metadata(&proposal.collection_id, None);
 
 				// Get NFT metadata
 				let mut nft_metadata = Vec::new();
@@ -1077,7 +1884,7 @@ pub mod pallet {
 
 				if items.is_empty() {
 					// Remove the proposal
-					CrossChainProposals::<T, I>::remove(proposal_id);
+					Self::remove_proposal(&proposal, false);
 
 					// Transfer through regular transfer function again, because there are no NFTs
 					// in the collection
@@ -1087,75 +1894,139 @@ pub mod pallet {
 						proposal.proposed_dest_collection_id,
 						proposal.proposed_destination_para,
 						proposal.proposed_destination_config.clone(),
+						proposal.proposed_beneficiary.clone(),
+						proposal.proposed_deposit_asset.clone(),
 					)?;
+
+					return Ok(().into());
+				}
+
+				// NFTs minted after the snapshot were not voted on, they stay in the origin
+				// collection along with NFTs of accounts that voted Nay in opt-out mode
+				let mut retained_items = Vec::new();
+
+				items.retain(|item_id| {
+					let snapshotted = ProposalItems::<T, I>::contains_key(proposal_id, item_id);
+
+					if !snapshotted {
+						retained_items.push(*item_id);
+					}
+
+					snapshotted
+				});
+
+				if proposal.migration_mode == MigrationMode::OptOut {
+					items.retain(|item_id| {
+						let opted_out = pallet_nfts::Pallet::<T, I>::owner(
+							proposal.collection_id.clone(),
+							*item_id,
+						)
+						.map_or(false, |nft_owner| {
+							Self::effective_vote(&proposal, &nft_owner) == Some(Vote::Nay)
+						});
+
+						if opted_out {
+							retained_items.push(*item_id);
+						}
+
+						!opted_out
+					});
 				}
 
 				for item_id in items.clone() {
 					let nft_owner =
 						pallet_nfts::Pallet::<T, I>::owner(proposal.collection_id.clone(), item_id)
 							.unwrap();
-					let unlooked_recipient = T::Lookup::unlookup(nft_owner.clone());
+					// NFT is minted to beneficiary chosen by the owner when voting, or to the
+					// owner's account
+					let recipient =
+						match ProposalBeneficiaries::<T, I>::get(proposal_id, &nft_owner) {
+							Some(beneficiary) => beneficiary,
+							None => Self::account_to_location(&nft_owner)
+								.ok_or(Error::<T, I>::InvalidBeneficiary)?,
+						};
 
-					if pallet_nfts::ItemMetadataOf::<T, I>::contains_key(
-						proposal.collection_id.clone(),
+					// Add NFT metadata, empty if NFT has none
+					nft_metadata.push((
 						item_id,
-					) {
-						let item_details = pallet_nfts::ItemMetadataOf::<T, I>::get(
-							proposal.collection_id.clone(),
-							item_id,
-						)
-						.unwrap()
-						.data;
-						nft_metadata.push((item_id, unlooked_recipient.clone(), item_details));
-					} else {
-						// Add empty metadata
-						nft_metadata.push((item_id, unlooked_recipient.clone(), BoundedVec::new()));
-					}
+						recipient.clone(),
+						Self::sent_metadata(&proposal.collection_id, Some(&item_id)),
+					));
 				}
 
 				let destination = proposal.proposed_destination_para.clone();
-				let unlooked_col_recipient = T::Lookup::unlookup(who.clone());
+				let unlooked_col_recipient = T::Lookup::unlookup(
+					Self::location_to_account(&proposal.proposed_beneficiary)
+						.ok_or(Error::<T, I>::InvalidBeneficiary)?,
+				);
 				let config = proposal.proposed_destination_config.clone();
 
-				// Convert accountId into accountid32
-				let account_vec = who.encode();
-				ensure!(account_vec.len() == 32, "AccountId must be 32 bytes.");
-				let mut bytes = [0u8; 32];
-				bytes.copy_from_slice(&account_vec);
+				// Convert account into location used as origin on destination chain
+				let who_location =
+					Self::account_to_location(&who).ok_or(Error::<T, I>::AccountNotConvertible)?;
+
+				// Storage deposits of the beneficiary on destination chain are funded by the
+				// initiator
+				let deposit = Self::deposit_funding(
+					proposal.proposed_deposit_asset.clone(),
+					Self::collection_deposit(
+						&collection_metadata,
+						nft_metadata.iter().map(|(_, _, data)| data.as_slice()),
+					),
+					&proposal.proposed_beneficiary,
+				);
+
+				// Destination chain verifies received metadata against hashes of sent metadata
+				let metadata_hashes = nft_metadata
+					.iter()
+					.map(|(item_id, _, data)| {
+						Self::metadata_hashes(
+							&proposal.collection_id,
+							item_id,
+							Some(collection_metadata.as_slice()),
+							data,
+						)
+					})
+					.collect();
+
+				let call = Call::<T, I>::parse_collection_diff_owners {
+					config,
+					origin_collection_id: proposal.collection_id.clone(),
+					origin_para: parachain_info::Pallet::<T>::parachain_id(),
+					collection_metadata,
+					nfts: nft_metadata.clone(),
+					metadata_hashes,
+					dest_collection_id: proposal.proposed_dest_collection_id.clone(),
+					beneficiary: proposal.proposed_beneficiary.clone(),
+				};
+
+				// Payload has to fit into a block of destination chain
+				ensure!(
+					Self::transact_weight(&call).all_lte(T::MaxTransactWeight::get()),
+					Error::<T, I>::PayloadTooHeavy
+				);
 
 				// Send collection and NFTs along with their metadata to destination parachain
-				match send_xcm::<T::XcmSender>(
-					(Parent, Junction::Parachain(destination.into())).into(),
-					Xcm(vec![
-						UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-						AliasOrigin(
-							xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-								.into(),
-						),
-						Transact {
-							origin_kind: OriginKind::SovereignAccount,
-							call: <T as Config<I>>::RuntimeCall::from(
-								Call::<T, I>::parse_collection_diff_owners {
-									config,
-									origin_collection_id: proposal.collection_id.clone(),
-									origin_para: parachain_info::Pallet::<T>::parachain_id(),
-									collection_metadata: collection_metadata.unwrap(),
-									nfts: nft_metadata.clone(),
-									dest_collection_id: proposal
-										.proposed_dest_collection_id
-										.clone(),
-								},
-							)
-							.encode()
-							.into(),
-						},
-					]),
+				match Self::send_transact(
+					destination,
+					who_location.clone(),
+					OriginKind::SovereignAccount,
+					call,
+					deposit,
 				) {
-					Ok((_hash, _cost)) => {
-						// If collection was received, remove from received collections
-						if ReceivedCollections::<T, I>::contains_key(proposal.collection_id.clone())
-						{
-							ReceivedCollections::<T, I>::remove(proposal.collection_id.clone());
+					Ok((message_hash, _cost)) => {
+						// Collection is restored if it fails to be received on destination chain
+						Self::record_pending_collection(
+							message_hash,
+							&who,
+							&proposal.collection_id,
+							&items,
+						);
+
+						// If collection was received and no NFTs stay in it, remove from received
+						// collections
+						if retained_items.is_empty() {
+							Self::remove_received_collection(&proposal.collection_id);
 						}
 
 						// Burning the NFTs
@@ -1177,1613 +2048,3890 @@ pub mod pallet {
 							);
 						}
 
-						//Burning the collection
-						let collection =
-							pallet_nfts::Collection::<T, I>::get(proposal.collection_id.clone())
-								.unwrap();
+						if retained_items.is_empty() {
+							//Burning the collection
+							let collection = pallet_nfts::Collection::<T, I>::get(
+								proposal.collection_id.clone(),
+							)
+							.unwrap();
 
-						let destroy_witness = DestroyWitness {
-							item_metadatas: collection.clone().item_metadatas,
-							item_configs: collection.clone().item_configs,
-							attributes: collection.attributes,
-						};
+							let destroy_witness = DestroyWitness {
+								item_metadatas: collection.clone().item_metadatas,
+								item_configs: collection.clone().item_configs,
+								attributes: collection.attributes,
+							};
 
-						let _ = pallet_nfts::Pallet::<T, I>::destroy(
-							origin.clone(),
-							proposal.collection_id.clone(),
-							destroy_witness,
-						);
+							let _ = pallet_nfts::Pallet::<T, I>::destroy(
+								origin.clone(),
+								proposal.collection_id.clone(),
+								destroy_witness,
+							);
+						} else {
+							// Keep the collection as residual and link it with destination
+							ResidualCollections::<T, I>::insert(
+								proposal.collection_id.clone(),
+								ResidualCols {
+									proposal_id,
+									destination_para_id: proposal.proposed_destination_para.clone(),
+									// Updated once destination chain confirms received collection
+									destination_collection_id: None,
+								},
+							);
+
+							Self::deposit_event(Event::ResidualCollectionRetained {
+								message_hash,
+								origin_collection_id: proposal.collection_id.clone(),
+								proposal_id,
+								nfts: retained_items.clone(),
+								destination_para_id: proposal.proposed_destination_para.clone(),
+							});
+						}
 
 						// Remove proposal from proposals
-						CrossChainProposals::<T, I>::remove(proposal_id);
+						Self::remove_proposal(&proposal, false);
 
 						// Emit a success event.
 						Self::deposit_event(Event::CollectionAndNFTsDiffTransferred {
+							message_hash,
 							origin_collection_id: proposal.collection_id.clone(),
 							nfts: nft_metadata.clone(),
 							destination_para_id: proposal.proposed_destination_para.clone(),
 							to_address: unlooked_col_recipient.clone(),
 						});
 					},
-					Err(e) => Self::deposit_event(Event::CollectionFailedToXCM {
-						e,
-						collection_id: proposal.collection_id.clone(),
-						owner: who.clone(),
-						destination: proposal.proposed_destination_para.clone(),
-					}),
+					Err((message_hash, e, _)) =>
+						Self::deposit_event(Event::CollectionFailedToXCM {
+							e,
+							message_hash,
+							collection_id: proposal.collection_id.clone(),
+							owner: who.clone(),
+							destination: proposal.proposed_destination_para.clone(),
+						}),
 				}
 			}
 
 			Ok(().into())
 		}
 
-		/// Transfer an asset along with associated metadata to another parachain.
+		/// Cancel collection cross-chain transfer proposal.
 		///
 		/// Origin must be Signed and the signing account must be :
-		/// - the Owner of the `Asset`;
+		/// - the proposer of the proposal (Owner of the `Collection`);
 		///
 		/// Arguments:
-		/// - `origin_collection`: The collection_id of the collection to be transferred.
-		/// - `origin_asset`: The asset_id of the asset to be transferred.
-		/// - `destination_para`: The destination chain ID to which collection is transferred.
-		/// - `destination_collection`: The collection_id of the collection that the asset have to
-		///   be received into.
-		/// - `destination_asset`: The asset_id of the asset to be received.
+		/// - `proposal_id`: The cross-chain proposal ID.
 		///
-		/// On success emits `NFTTransferred`.
-		#[pallet::call_index(3)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn nft_x_transfer(
+		/// On success emits `ProposalCancelled`.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::collection_x_transfer_cancel())]
+		pub fn collection_x_transfer_cancel(
 			origin: OriginFor<T>,
-			origin_collection: T::CollectionId,
-			origin_asset: T::ItemId,
-			destination_para: ParaId,
-			destination_collection: T::CollectionId,
-			destination_asset: T::ItemId,
+			proposal_id: u64,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin.clone())?;
+			let who = ensure_signed(origin)?;
 
-			// See if collection exists
+			// Check if proposal exists
 			ensure!(
-				pallet_nfts::Collection::<T, I>::contains_key(&origin_collection),
-				Error::<T, I>::CollectionDoesNotExist
+				CrossChainProposals::<T, I>::contains_key(proposal_id),
+				Error::<T, I>::ProposalDoesNotExist
 			);
 
-			// See if item exists
-			ensure!(
-				pallet_nfts::Item::<T, I>::contains_key(&origin_collection, &origin_asset),
-				Error::<T, I>::NFTDoesNotExist
-			);
+			let proposal = CrossChainProposals::<T, I>::get(proposal_id).unwrap();
 
-			// See if user owns the item
-			ensure!(
-				pallet_nfts::Pallet::<T, I>::owner(origin_collection.clone(), origin_asset.clone())
-					.ok_or(Error::<T, I>::NFTDoesNotExist)? ==
-					who.clone(),
-				Error::<T, I>::NotNFTOwner
-			);
+			// Check if the user is the proposer
+			ensure!(proposal.proposed_collection_owner == who, Error::<T, I>::NotCollectionOwner);
 
-			// Get Item data
-			let mut metadata = Some(BoundedVec::new());
+			// Remove the proposal, this also unlocks the NFTs
+			Self::remove_proposal(&proposal, false);
 
-			if pallet_nfts::ItemMetadataOf::<T, I>::contains_key(&origin_collection, &origin_asset)
-			{
-				metadata = pallet_nfts::ItemMetadataOf::<T, I>::get(
-					origin_collection.clone(),
-					origin_asset.clone(),
-				)
-				.map(|i| i.data);
-			}
+			Self::deposit_event(Event::ProposalCancelled { proposal_id });
 
-			// Convert accountId into accountid32
-			let account_vec = who.encode();
-			ensure!(account_vec.len() == 32, "AccountId must be 32 bytes.");
-			let mut bytes = [0u8; 32];
-			bytes.copy_from_slice(&account_vec);
+			Ok(().into())
+		}
 
-			// Send the asset along with associated metadata cross-chain
-			match send_xcm::<T::XcmSender>(
-				(Parent, Junction::Parachain(destination_para.into())).into(),
-				Xcm(vec![
-					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-					AliasOrigin(
-						xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-							.into(),
-					),
-					Transact {
-						origin_kind: OriginKind::SovereignAccount,
-						call: <T as Config<I>>::RuntimeCall::from(
-							Call::<T, I>::parse_nft_transfer {
-								origin_collection: origin_collection.clone(),
-								origin_item: origin_asset.clone(),
-								collection: destination_collection.clone(),
-								item: destination_asset.clone(),
-								data: metadata.unwrap(),
-								origin_chain: parachain_info::Pallet::<T>::parachain_id(),
-							},
-						)
-						.encode()
-						.into(),
-					},
-				]),
-			) {
-				Ok((_hash, _cost)) => {
-					// If in received list, burn asset and remove from received list
-					if ReceivedAssets::<T, I>::contains_key(&(
-						origin_collection.clone(),
-						origin_asset.clone(),
-					)) {
-						let received = ReceivedAssets::<T, I>::get(&(
-							origin_collection.clone(),
-							origin_asset.clone(),
-						))
-						.unwrap();
+		/// Withdraw a vote on collection cross-chain transfer.
+		///
+		/// Origin must be Signed and the signing account must have voted on the proposal.
+		///
+		/// Arguments:
+		/// - `proposal_id`: The cross-chain proposal ID.
+		///
+		/// On success emits `CrossChainProposalVoteWithdrawn`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::collection_x_transfer_withdraw_vote())]
+		pub fn collection_x_transfer_withdraw_vote(
+			origin: OriginFor<T>,
+			proposal_id: u64,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
-						SentAssets::<T, I>::insert(
-							(origin_collection.clone(), origin_asset.clone()),
-							SentStruct {
-								origin_para_id: received.origin_para_id,
-								origin_collection_id: received.origin_collection_id,
-								origin_asset_id: received.origin_asset_id,
-								destination_collection_id: destination_collection.clone(),
-								destination_asset_id: destination_asset.clone(),
-							},
-						);
+			// Check if proposal exists
+			ensure!(
+				CrossChainProposals::<T, I>::contains_key(proposal_id),
+				Error::<T, I>::ProposalDoesNotExist
+			);
 
-						// Remove from received assets
-						ReceivedAssets::<T, I>::remove(&(
-							origin_collection.clone(),
-							origin_asset.clone(),
-						));
+			let mut proposal = CrossChainProposals::<T, I>::get(proposal_id).unwrap();
 
-						// Burn the asset
-						let _ = pallet_nfts::Pallet::<T, I>::burn(
-							origin.clone(),
-							origin_collection.clone(),
-							origin_asset.clone(),
-						);
-					}
-					//Only remove asset metadata, because we are sending from origin chain
-					else {
-						let col_owner = pallet_nfts::Pallet::<T, I>::collection_owner(
-							origin_collection.clone(),
-						)
-						.unwrap();
-						let signed_col: OriginFor<T> =
-							frame_system::RawOrigin::Signed(col_owner.clone()).into();
+			// Check if the proposal is still active
+			let block_n: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+			ensure!(block_n <= proposal.end_time, Error::<T, I>::ProposalExpired);
 
-						let _ = pallet_nfts::Pallet::<T, I>::clear_metadata(
-							signed_col.clone(),
-							origin_collection.clone(),
-							origin_asset.clone(),
-						);
+			// Check if the user voted
+			let (vote, weight) =
+				ProposalVotes::<T, I>::take(proposal_id, &who).ok_or(Error::<T, I>::NotVoted)?;
+
+			Self::tally_vote(&mut proposal, &vote, 0, weight);
+			CrossChainProposals::<T, I>::insert(proposal_id, proposal);
+
+			Self::deposit_event(Event::CrossChainProposalVoteWithdrawn { proposal_id, voter: who });
 
-						SentAssets::<T, I>::insert(
-							(origin_collection.clone(), origin_asset.clone()),
-							SentStruct {
-								origin_para_id: parachain_info::Pallet::<T>::parachain_id(),
-								origin_collection_id: origin_collection.clone(),
-								origin_asset_id: origin_asset.clone(),
-								destination_collection_id: destination_collection.clone(),
-								destination_asset_id: destination_asset.clone(),
-							},
-						);
-					}
-					//Emit a success event
-					Self::deposit_event(Event::NFTTransferred {
-						origin_collection_id: origin_collection.clone(),
-						origin_asset_id: origin_asset.clone(),
-						destination_para_id: destination_para,
-						destination_collection_id: destination_collection.clone(),
-						destination_asset_id: destination_asset.clone(),
-					});
-				},
-				Err(e) => Self::deposit_event(Event::CollectionFailedToXCM {
-					e,
-					collection_id: origin_collection.clone(),
-					owner: who.clone(),
-					destination: destination_para.clone(),
-				}),
-			}
 			Ok(().into())
 		}
 
-		/// Claim cross-chain sent asset if its origin collection was also sent to same destination
-		/// chain.
+		/// Delegate a vote on collection cross-chain transfer to another account.
 		///
 		/// Origin must be Signed and the signing account must be :
-		/// - the Owner of the asset in the `Current collection` and owner of the asset in the
-		///   `Origin collection`;
+		/// - the Owner of the `Asset` or `Collection` at proposal creation;
+		///
+		/// Delegating replaces the vote the account cast directly, the delegate's vote then counts
+		/// with the weight of the delegating account as well. Delegated votes are not passed on
+		/// further if the delegate delegates their own vote.
 		///
 		/// Arguments:
-		/// - `origin_collection_at_destination`: The collection_id at the destination of the
-		///   collection that was transferred (Id it has at destination chain).
-		/// - `origin_collection_at_origin`: The origin collection_id of the collection that was
-		///   transferred (Id it had at origin chain).
-		/// - `origin_asset_at_destination`: The origin asset id at the origin collection that is
-		///   delivered.
-		/// - `current_collection`: The collection_id of the collection that the asset have been
-		///   delivered into.
-		/// - `current_asset`: The current asset_id of the asset in current collection.
+		/// - `proposal_id`: The cross-chain proposal ID.
+		/// - `delegate`: Account to delegate the vote to, or None to remove the delegation.
 		///
-		/// On success emits `NFTClaimed`.
-		#[pallet::call_index(4)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn nft_x_claim(
+		/// On success emits `CrossChainProposalVoteDelegated`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::collection_x_transfer_delegate())]
+		pub fn collection_x_transfer_delegate(
 			origin: OriginFor<T>,
-			origin_collection_at_destination: T::CollectionId,
-			origin_collection_at_origin: T::CollectionId,
-			origin_asset_at_destination: T::ItemId,
-			current_collection: T::CollectionId,
-			current_asset: T::ItemId,
+			proposal_id: u64,
+			delegate: Option<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin.clone())?;
-
-			// See if collection exists
-			ensure!(
-				pallet_nfts::Collection::<T, I>::contains_key(&current_collection),
-				Error::<T, I>::CollectionDoesNotExist
-			);
+			let who = ensure_signed(origin)?;
 
-			// See if origin collection exists
+			// Check if proposal exists
 			ensure!(
-				pallet_nfts::Collection::<T, I>::contains_key(&origin_collection_at_destination),
-				Error::<T, I>::CollectionDoesNotExist
+				CrossChainProposals::<T, I>::contains_key(proposal_id),
+				Error::<T, I>::ProposalDoesNotExist
 			);
 
-			// See if origin collection at origin is the same as in received collection
-			ensure!(
-				ReceivedCollections::<T, I>::get(&origin_collection_at_destination)
-					.unwrap()
-					.origin_collection_id ==
-					origin_collection_at_origin,
-				Error::<T, I>::WrongOriginCollectionAtOrigin
-			);
+			let mut proposal = CrossChainProposals::<T, I>::get(proposal_id).unwrap();
 
-			// See if current asset is in received assets
-			ensure!(
-				ReceivedAssets::<T, I>::contains_key(&(
-					current_collection.clone(),
-					current_asset.clone()
-				)),
-				Error::<T, I>::NFTNotReceived
-			);
+			// See if the user can delegate, check if they are in the owners list
+			let weight =
+				ProposalOwners::<T, I>::get(proposal_id, &who).ok_or(Error::<T, I>::NotNFTOwner)?;
 
-			// See if item in origin collection exists
-			ensure!(
-				pallet_nfts::Item::<T, I>::contains_key(
-					&origin_collection_at_destination,
-					&origin_asset_at_destination
-				),
-				Error::<T, I>::NFTDoesNotExist
-			);
+			// Delegated weight is known once the snapshot is complete
+			ensure!(proposal.snapshot_complete, Error::<T, I>::SnapshotIncomplete);
 
-			// See if user owns the item
-			ensure!(
-				pallet_nfts::Pallet::<T, I>::owner(
-					origin_collection_at_destination.clone(),
-					origin_asset_at_destination.clone()
-				)
-				.ok_or(Error::<T, I>::NFTDoesNotExist)? ==
-					who.clone(),
-				Error::<T, I>::NotNFTOwner
-			);
+			// Check if the proposal is still active
+			let block_n: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+			ensure!(block_n <= proposal.end_time, Error::<T, I>::ProposalExpired);
 
-			// See if user owns the current asset
-			ensure!(
-				pallet_nfts::Pallet::<T, I>::owner(
-					current_collection.clone(),
-					current_asset.clone()
-				)
-				.ok_or(Error::<T, I>::NFTDoesNotExist)? ==
-					who.clone(),
-				Error::<T, I>::NotNFTOwner
-			);
+			if let Some(delegate) = &delegate {
+				ensure!(delegate != &who, Error::<T, I>::CannotDelegateToSelf);
+			}
 
-			// Claim the asset
-			let mut metadata = Some(BoundedVec::new());
+			// Remove previous delegation
+			if let Some(previous) = ProposalDelegations::<T, I>::take(proposal_id, &who) {
+				Self::update_delegated_weight(&mut proposal, &previous, weight, false);
 
-			if pallet_nfts::ItemMetadataOf::<T, I>::contains_key(
-				current_collection.clone(),
-				current_asset.clone(),
-			) {
-				metadata = pallet_nfts::ItemMetadataOf::<T, I>::get(
-					current_collection.clone(),
-					current_asset.clone(),
-				)
-				.map(|i| i.data);
+				// Delegate that is not an owner cannot vote anymore without delegators
+				if !Self::has_delegators(proposal_id, &previous) &&
+					!ProposalOwners::<T, I>::contains_key(proposal_id, &previous)
+				{
+					VotableProposals::<T, I>::remove(&previous, proposal_id);
+				}
 			}
 
-			// Burn the current asset
-			let _ = pallet_nfts::Pallet::<T, I>::burn(
-				origin.clone(),
-				current_collection.clone(),
-				current_asset.clone(),
-			);
+			if let Some(delegate) = &delegate {
+				// Delegated vote replaces the vote cast directly
+				if let Some((vote, vote_weight)) = ProposalVotes::<T, I>::take(proposal_id, &who) {
+					Self::tally_vote(&mut proposal, &vote, 0, vote_weight);
+				}
 
-			// Add the metadata to the old asset location
-			if metadata.is_some() {
-				let _ = pallet_nfts::Pallet::<T, I>::set_metadata(
-					origin.clone(),
-					origin_collection_at_destination.clone(),
-					origin_asset_at_destination.clone(),
-					metadata.unwrap(),
-				);
+				ProposalDelegations::<T, I>::insert(proposal_id, &who, delegate);
+				Self::update_delegated_weight(&mut proposal, delegate, weight, true);
+				VotableProposals::<T, I>::insert(delegate, proposal_id, ());
 			}
 
-			// Remove asset from received
-			ReceivedAssets::<T, I>::remove(&(current_collection.clone(), current_asset.clone()));
+			CrossChainProposals::<T, I>::insert(proposal_id, proposal);
 
-			// Emit a success event
-			Self::deposit_event(Event::NFTClaimed {
-				collection_claimed_from: current_collection.clone(),
-				asset_removed: current_asset.clone(),
-				collection_claimed_to: origin_collection_at_destination.clone(),
-				asset_claimed: origin_asset_at_destination.clone(),
+			Self::deposit_event(Event::CrossChainProposalVoteDelegated {
+				proposal_id,
+				delegator: who,
+				delegate,
 			});
 
 			Ok(().into())
 		}
 
-		/// Update collection metadata cross-chain.
+		/// Snapshot next page of NFT owners of collection cross-chain transfer proposal.
 		///
-		/// Origin must be Signed and the signing account must be :
-		/// - the Owner of the `destination collection`;
+		/// Origin must be Signed, any account can continue the snapshot.
+		///
+		/// Voting on the proposal starts once the snapshot is complete.
 		///
 		/// Arguments:
-		/// - `destination_collection_id`: The collection_id at the destination.
-		/// - `destination_para`: The recipient parachain ID.
-		/// - `data`: The metadata to be added to destination collection.
+		/// - `proposal_id`: The cross-chain proposal ID.
 		///
-		/// On success emits `CollectionMetadataSent`.
-		#[pallet::call_index(5)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn collection_x_update(
+		/// On success emits `ProposalSnapshotUpdated`.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::collection_x_transfer_snapshot())]
+		pub fn collection_x_transfer_snapshot(
 			origin: OriginFor<T>,
-			destination_collection_id: T::CollectionId,
-			destination_para: ParaId,
-			data: BoundedVec<u8, T::StringLimit>,
+			proposal_id: u64,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+			ensure_signed(origin)?;
+
+			// Check if proposal exists
+			ensure!(
+				CrossChainProposals::<T, I>::contains_key(proposal_id),
+				Error::<T, I>::ProposalDoesNotExist
+			);
 
-			// Convert accountId into accountid32
-			let account_vec = who.encode();
-			ensure!(account_vec.len() == 32, "AccountId must be 32 bytes.");
-			let mut bytes = [0u8; 32];
-			bytes.copy_from_slice(&account_vec);
+			let mut proposal = CrossChainProposals::<T, I>::get(proposal_id).unwrap();
+
+			ensure!(!proposal.snapshot_complete, Error::<T, I>::SnapshotComplete);
+
+			Self::snapshot_page(&mut proposal)?;
+
+			let complete = proposal.snapshot_complete;
+
+			CrossChainProposals::<T, I>::insert(proposal_id, proposal);
+
+			Self::deposit_event(Event::ProposalSnapshotUpdated { proposal_id, complete });
 
-			// Send the prompt to update collection metadata
-			match send_xcm::<T::XcmSender>(
-				(Parent, Junction::Parachain(destination_para.into())).into(),
-				Xcm(vec![
-					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-					AliasOrigin(
-						xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-							.into(),
-					),
-					Transact {
-						origin_kind: OriginKind::Native,
-						call: <T as Config<I>>::RuntimeCall::from(
-							Call::<T, I>::parse_collection_metadata {
-								collection: destination_collection_id.clone(),
-								data: data.clone(),
-							},
-						)
-						.encode()
-						.into(),
-					},
-				]),
-			) {
-				Ok((_hash, _cost)) => {
-					// Emit event about sucessful metadata send
-					Self::deposit_event(Event::CollectionMetadataSent {
-						collection_id: destination_collection_id.clone(),
-						proposed_data: data.clone(),
-						owner: who.clone(),
-						destination: destination_para.clone(),
-					});
-				},
-				Err(e) => Self::deposit_event(Event::CollectionMetadataFailedToXCM {
-					e,
-					collection_id: destination_collection_id.clone(),
-					proposed_data: data.clone(),
-					owner: who.clone(),
-					destination: destination_para.clone(),
-				}),
-			}
 			Ok(().into())
 		}
 
-		/// Update NFT metadata cross-chain.
+		/// Clean up next page of ended collection cross-chain transfer proposal.
 		///
-		/// Origin must be Signed and the signing account must be :
-		/// - the Owner of the `destination collection`;
+		/// Origin must be Signed, any account can continue the cleanup.
+		///
+		/// NFTs locked by the proposal are unlocked and its owners, votes, delegations and
+		/// beneficiaries are removed. New proposal for the collection can be created once the
+		/// cleanup is complete.
 		///
 		/// Arguments:
-		/// - `destination_collection_id`: The collection_id at the destination.
-		/// - `destination_asset_id`: The asset_id at the destination.
-		/// - `destination_para`: The recipient parachain ID.
-		/// - `data`: The metadata to be added to destination collection.
+		/// - `collection_id`: The collection of the ended proposal.
 		///
-		/// On success emits `NFTMetadataSent`.
-		#[pallet::call_index(6)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn nft_x_update(
+		/// On success emits `ProposalCleanupUpdated`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::collection_x_transfer_cleanup())]
+		pub fn collection_x_transfer_cleanup(
 			origin: OriginFor<T>,
-			destination_collection_id: T::CollectionId,
-			destination_asset_id: T::ItemId,
-			destination_para: ParaId,
-			data: BoundedVec<u8, T::StringLimit>,
+			collection_id: T::CollectionId,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+			ensure_signed(origin)?;
 
-			// Convert accountId into accountid32
-			let account_vec = who.encode();
-			ensure!(account_vec.len() == 32, "AccountId must be 32 bytes.");
-			let mut bytes = [0u8; 32];
-			bytes.copy_from_slice(&account_vec);
+			let (proposal_id, _) = ProposalCleanups::<T, I>::get(&collection_id)
+				.ok_or(Error::<T, I>::NoProposalCleanup)?;
+
+			let complete = Self::cleanup_page(&collection_id);
+
+			Self::deposit_event(Event::ProposalCleanupUpdated { proposal_id, complete });
 
-			// Send the prompt to update NFT metadata
-			match send_xcm::<T::XcmSender>(
-				(Parent, Junction::Parachain(destination_para.into())).into(),
-				Xcm(vec![
-					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-					AliasOrigin(
-						xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-							.into(),
-					),
-					Transact {
-						origin_kind: OriginKind::SovereignAccount,
-						call: <T as Config<I>>::RuntimeCall::from(
-							Call::<T, I>::parse_nft_metadata {
-								collection: destination_collection_id.clone(),
-								item: destination_asset_id.clone(),
-								data: data.clone(),
-							},
-						)
-						.encode()
-						.into(),
-					},
-				]),
-			) {
-				Ok((_hash, _cost)) => {
-					// Emit event about sucessful metadata send
-					Self::deposit_event(Event::NFTMetadataSent {
-						collection_id: destination_collection_id.clone(),
-						asset_id: destination_asset_id.clone(),
-						proposed_data: data.clone(),
-						owner: who.clone(),
-						destination: destination_para.clone(),
-					});
-				},
-				Err(e) => Self::deposit_event(Event::NFTMetadataFailedToXCM {
-					e,
-					collection_id: destination_collection_id.clone(),
-					asset_id: destination_asset_id.clone(),
-					proposed_data: data.clone(),
-					owner: who.clone(),
-					destination: destination_para.clone(),
-				}),
-			}
 			Ok(().into())
 		}
 
-		/// Prompt to burn empty collection cross-chain.
+		/// Allow or disallow receiving NFTs into collection from senders that do not own it.
 		///
 		/// Origin must be Signed and the signing account must be :
-		/// - the Owner of the `destination collection`;
+		/// - the Owner of the `Collection`;
 		///
 		/// Arguments:
-		/// - `destination_collection_id`: The collection_id at the destination.
-		/// - `destination_para`: The recipient parachain ID.
-		/// - `witness_data`: The amount of NFTs, metadatas and configs in the collection (Needs to
-		///   be all zeros for successful burn).
+		/// - `collection`: The collection_id of the collection that receives NFTs.
+		/// - `origin_para`: The parachain ID that NFTs are sent from.
+		/// - `origin_collection`: The collection_id of the collection at origin parachain, `None`
+		///   applies to every collection of origin parachain.
+		/// - `allowed`: Whether NFTs are allowed to be received.
 		///
-		/// On success emits `CollectionBurnSent`.
-		#[pallet::call_index(7)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn collection_x_burn(
+		/// On success emits `IncomingAllowlistUpdated`.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::collection_x_allow_incoming())]
+		pub fn collection_x_allow_incoming(
 			origin: OriginFor<T>,
-			destination_collection_id: T::CollectionId,
-			destination_para: ParaId,
-			witnes_data: GeneralizedDestroyWitness,
+			collection: T::CollectionId,
+			origin_para: ParaId,
+			origin_collection: Option<T::CollectionId>,
+			allowed: bool,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			// Convert accountId into accountid32
-			let account_vec = who.encode();
-			ensure!(account_vec.len() == 32, "AccountId must be 32 bytes.");
-			let mut bytes = [0u8; 32];
-			bytes.copy_from_slice(&account_vec);
+			// See if user owns the collection
+			ensure!(
+				pallet_nfts::Pallet::<T, I>::collection_owner(collection.clone())
+					.ok_or(Error::<T, I>::CollectionDoesNotExist)? ==
+					who,
+				Error::<T, I>::NotCollectionOwner
+			);
 
-			// Send the prompt to burn collection
-			match send_xcm::<T::XcmSender>(
-				(Parent, Junction::Parachain(destination_para.into())).into(),
-				Xcm(vec![
-					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-					AliasOrigin(
-						xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-							.into(),
-					),
-					Transact {
-						origin_kind: OriginKind::SovereignAccount,
-						call: <T as Config<I>>::RuntimeCall::from(Call::parse_collection_burn {
-							collection_to_burn: destination_collection_id.clone(),
-							witness_data: witnes_data.clone(),
-						})
-						.encode()
-						.into(),
-					},
-				]),
-			) {
-				Ok((_hash, _cost)) => {
-					// Emit event about sucessful burn prompt transfer
-					Self::deposit_event(Event::CollectionBurnSent {
-						collection_id: destination_collection_id.clone(),
-						burn_data: witnes_data.clone(),
-						owner: who.clone(),
-						destination: destination_para.clone(),
-					});
-				},
-				Err(e) => Self::deposit_event(Event::CollectionBurnFailedToXCM {
-					e,
-					collection_id: destination_collection_id.clone(),
-					burn_data: witnes_data.clone(),
-					owner: who.clone(),
-					destination: destination_para.clone(),
-				}),
+			if allowed {
+				IncomingAllowlist::<T, I>::insert(
+					&collection,
+					(origin_para.clone(), origin_collection.clone()),
+					(),
+				);
+			} else {
+				IncomingAllowlist::<T, I>::remove(
+					&collection,
+					(origin_para.clone(), origin_collection.clone()),
+				);
 			}
 
+			Self::deposit_event(Event::IncomingAllowlistUpdated {
+				collection_id: collection,
+				origin_para_id: origin_para,
+				origin_collection_id: origin_collection,
+				allowed,
+			});
+
 			Ok(().into())
 		}
 
-		/// Prompt to burn NFT cross-chain.
+		/// Set origin mode used for messages sent to destination chain.
 		///
-		/// Origin must be Signed and the signing account must be :
-		/// - the Owner of the `destination NFT`;
+		/// Origin must be `AdminOrigin`.
 		///
 		/// Arguments:
-		/// - `destination_collection_id`: The collection_id at the destination.
-		/// - `destination_asset_id`: The asset_id at the destination.
-		/// - `destination_para`: The recipient parachain ID.
+		/// - `destination_para`: The destination chain ID.
+		/// - `mode`: Whether sender account is aliased or origin is descended into it.
 		///
-		/// On success emits `NFTBurnSent`.
-		#[pallet::call_index(8)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn nft_x_burn(
+		/// On success emits `DestinationOriginModeSet`.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_destination_origin_mode())]
+		pub fn set_destination_origin_mode(
 			origin: OriginFor<T>,
-			destination_collection_id: T::CollectionId,
-			destination_asset_id: T::ItemId,
 			destination_para: ParaId,
+			mode: OriginMode,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			// Convert accountId into accountid32
-			let account_vec = who.encode();
-			ensure!(account_vec.len() == 32, "AccountId must be 32 bytes.");
-			let mut bytes = [0u8; 32];
-			bytes.copy_from_slice(&account_vec);
+			DestinationOriginModes::<T, I>::insert(destination_para, mode);
 
-			// Send the prompt to burn NFT
-			match send_xcm::<T::XcmSender>(
-				(Parent, Junction::Parachain(destination_para.into())).into(),
-				Xcm(vec![
-					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-					AliasOrigin(
-						xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-							.into(),
-					),
-					Transact {
-						origin_kind: OriginKind::SovereignAccount,
-						call: <T as Config<I>>::RuntimeCall::from(Call::<T, I>::parse_nft_burn {
-							collection: destination_collection_id.clone(),
-							item: destination_asset_id.clone(),
-						})
-						.encode()
-						.into(),
-					},
-				]),
-			) {
-				Ok((_hash, _cost)) => {
-					// Emit event about sucessful metadata send
-					Self::deposit_event(Event::NFTBurnSent {
-						collection_id: destination_collection_id.clone(),
-						asset_id: destination_asset_id.clone(),
-						owner: who.clone(),
-						destination: destination_para.clone(),
-					});
-				},
-				Err(e) => Self::deposit_event(Event::NFTBurnFailedToXCM {
-					e,
-					collection_id: destination_collection_id.clone(),
-					asset_id: destination_asset_id.clone(),
-					owner: who.clone(),
-					destination: destination_para.clone(),
-				}),
-			}
+			Self::deposit_event(Event::DestinationOriginModeSet {
+				destination_para_id: destination_para,
+				mode,
+			});
 
 			Ok(().into())
 		}
 
-		/// Prompt to change collection owner cross-chain.
+		/// Set asset that pays execution of messages sent to destination chain.
 		///
-		/// Origin must be Signed and the signing account must be :
-		/// - the Owner of the `destination collection`;
-		/// - the New owner must agree to the ownership change by executing function
-		///   setAcceptOwnership(maybeCollection)
+		/// Origin must be `AdminOrigin`.
 		///
 		/// Arguments:
-		/// - `destination_collection_id`: The collection_id at the destination.
-		/// - `destination_para`: The recipient parachain ID.
-		/// - `destination_account`: The destination account that will receive collection.
+		/// - `destination_para`: The destination chain ID.
+		/// - `fee`: The fungible asset as seen from this chain, withdrawn on destination chain to
+		///   buy execution and charged from sender on this chain. `None` switches destination to
+		///   unpaid execution.
 		///
-		/// On success emits `CollectionOwnershipSent`.
-		#[pallet::call_index(9)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn collection_x_change_owner(
+		/// On success emits `DestinationFeeSet`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_destination_fee())]
+		pub fn set_destination_fee(
 			origin: OriginFor<T>,
-			destination_collection_id: T::CollectionId,
 			destination_para: ParaId,
-			destination_account: AccountIdLookupOf<T>,
+			fee: Option<Asset>,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
-
-			// Convert accountId into accountid32
-			let account_vec = who.encode();
-			ensure!(account_vec.len() == 32, "AccountId must be 32 bytes.");
-			let mut bytes = [0u8; 32];
-			bytes.copy_from_slice(&account_vec);
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			// Send the prompt to change collection owner
-			match send_xcm::<T::XcmSender>(
-				(Parent, Junction::Parachain(destination_para.into())).into(),
-				Xcm(vec![
-					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-					AliasOrigin(
-						xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-							.into(),
-					),
-					Transact {
-						origin_kind: OriginKind::SovereignAccount,
-						call: <T as Config<I>>::RuntimeCall::from(
-							Call::<T, I>::parse_collection_owner {
-								new_owner: destination_account.clone(),
-								collection: destination_collection_id.clone(),
-							},
-						)
-						.encode()
-						.into(),
-					},
-				]),
-			) {
-				Ok((_hash, _cost)) => {
-					// Emit event about sucessful metadata send
-					Self::deposit_event(Event::CollectionOwnershipSent {
-						collection_id: destination_collection_id.clone(),
-						proposed_owner: destination_account.clone(),
-						destination: destination_para.clone(),
-					});
+			match &fee {
+				Some(asset) => {
+					ensure!(
+						matches!(asset.fun, Fungibility::Fungible(amount) if amount > 0),
+						Error::<T, I>::InvalidFeeAsset
+					);
+					DestinationFees::<T, I>::insert(destination_para, asset);
 				},
-				Err(e) => Self::deposit_event(Event::CollectionOwnershipFailedToXCM {
-					e,
-					collection_id: destination_collection_id.clone(),
-					proposed_owner: destination_account.clone(),
-					destination: destination_para.clone(),
-				}),
+				None => DestinationFees::<T, I>::remove(destination_para),
 			}
 
+			Self::deposit_event(Event::DestinationFeeSet {
+				destination_para_id: destination_para,
+				fee,
+			});
+
 			Ok(().into())
 		}
 
-		/// Prompt to change NFT owner cross-chain.
+		/// Set index of xcNFT pallet of sibling chain, whose calls are verified to be sent by it.
 		///
-		/// Origin must be Signed and the signing account must be :
-		/// - the Owner of the `destination asset`;
+		/// Origin must be `AdminOrigin`.
 		///
 		/// Arguments:
-		/// - `destination_collection_id`: The collection_id at the destination.
-		/// - `destination_asset_id`: The asset_id at the destination.
-		/// - `destination_para`: The recipient parachain ID.
-		/// - `destination_account`: The destination account that will receive collection.
+		/// - `sibling_para`: The sibling chain ID.
+		/// - `pallet_index`: The index of xcNFT pallet in runtime of sibling chain. `None` stops
+		///   verifying calls of sibling chain.
 		///
-		/// On success emits `NFTOwnershipSent`.
-		#[pallet::call_index(10)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn nft_x_change_owner(
+		/// On success emits `SiblingPalletSet`.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::set_sibling_pallet())]
+		pub fn set_sibling_pallet(
 			origin: OriginFor<T>,
-			destination_collection_id: T::CollectionId,
-			destination_asset_id: T::ItemId,
-			destination_para: ParaId,
-			destination_account: AccountIdLookupOf<T>,
+			sibling_para: ParaId,
+			pallet_index: Option<u8>,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin)?;
-
-			// Convert accountId into accountid32
-			let account_vec = who.encode();
-			ensure!(account_vec.len() == 32, "AccountId must be 32 bytes.");
-			let mut bytes = [0u8; 32];
-			bytes.copy_from_slice(&account_vec);
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			// Send the prompt to change NFT owner
-			match send_xcm::<T::XcmSender>(
-				(Parent, Junction::Parachain(destination_para.into())).into(),
-				Xcm(vec![
-					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
-					AliasOrigin(
-						xcm::latest::prelude::AccountId32 { id: bytes.into(), network: None }
-							.into(),
-					),
-					Transact {
-						origin_kind: OriginKind::SovereignAccount,
-						call: <T as Config<I>>::RuntimeCall::from(Call::<T, I>::parse_nft_owner {
-							new_owner: destination_account.clone(),
-							collection: destination_collection_id.clone(),
-							item: destination_asset_id.clone(),
-						})
-						.encode()
-						.into(),
-					},
-				]),
-			) {
-				Ok((_hash, _cost)) => {
-					// Emit event about sucessful metadata send
-					Self::deposit_event(Event::NFTOwnershipSent {
-						collection_id: destination_collection_id.clone(),
-						asset_id: destination_asset_id.clone(),
-						proposed_owner: destination_account.clone(),
-						destination: destination_para.clone(),
-					});
-				},
-				Err(e) => Self::deposit_event(Event::NFTOwnershipFailedToXCM {
-					e,
-					collection_id: destination_collection_id.clone(),
-					asset_id: destination_asset_id.clone(),
-					proposed_owner: destination_account.clone(),
-					destination: destination_para.clone(),
-				}),
+			match pallet_index {
+				Some(index) => SiblingPallets::<T, I>::insert(sibling_para, index),
+				None => SiblingPallets::<T, I>::remove(sibling_para),
 			}
 
+			Self::deposit_event(Event::SiblingPalletSet {
+				sibling_para_id: sibling_para,
+				pallet_index,
+			});
+
 			Ok(().into())
 		}
 
-		/// Receive function for collection_x_transfer function.
+		/// Cancel message queued after failed attempt to send it, NFT carried by the message is
+		/// unlocked.
 		///
-		/// Shouldn't be used as a regular call.
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the queued message;
 		///
-		/// On success emits `CollectionReceived`.
-		#[pallet::call_index(11)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_collection_empty(
+		/// Arguments:
+		/// - `message_hash`: The id of queued message.
+		///
+		/// On success emits `QueuedMessageCancelled`.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::cancel_queued_message())]
+		pub fn cancel_queued_message(
 			origin: OriginFor<T>,
-			origin_collection: T::CollectionId,
-			_destination_collection: Option<T::CollectionId>,
-			collection_metadata: BoundedVec<u8, T::StringLimit>,
-			config: Option<CollectionConfigFor<T, I>>,
+			message_hash: XcmHash,
 		) -> DispatchResultWithPostInfo {
-			let signed_origin = ensure_signed(origin.clone())?;
-			let signed_origin_lookup = T::Lookup::unlookup(signed_origin.clone());
-
-			//Get next collection id
-			let mut next_collection_id = pallet_nfts::NextCollectionId::<T, I>::get()
-				.or(T::CollectionId::initial_value())
-				.unwrap();
-
-			// Check if config is present
-			if config.is_none() {
-				// Create default configfor collection
-				let def_config = CollectionConfigFor::<T, I> {
-					settings: CollectionSettings::all_enabled(), // Default settings (all enabled)
-					max_supply: None,                            /* No maximum supply defined
-					                                              * initially */
-					mint_settings: MintSettings::default(), // Use default mint settings
-				};
-
-				match pallet_nfts::Pallet::<T, I>::create(
-					origin.clone(),
-					signed_origin_lookup.clone(),
-					def_config.clone(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						//  Deposit event indicating failure to create collection
-						Self::deposit_event(Event::CollectionMintFailed { error: e });
-					},
-				}
-			} else {
-				match pallet_nfts::Pallet::<T, I>::create(
-					origin.clone(),
-					signed_origin_lookup.clone(),
-					config.clone().unwrap(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						//  Deposit event indicating failure to create collection
-						Self::deposit_event(Event::CollectionMintFailed { error: e });
-					},
-				}
-			}
+			let who = ensure_signed(origin)?;
 
-			let mut user_collection = next_collection_id.clone();
+			let queued = OutboundQueue::<T, I>::get(message_hash)
+				.ok_or(Error::<T, I>::QueuedMessageNotFound)?;
 
-			// just to be sure, check if user is collection owner
-			if pallet_nfts::Pallet::<T, I>::collection_owner(next_collection_id.clone()).unwrap() !=
-				signed_origin.clone()
-			{
-				//Get current next_collection_id
-				let current_next_collection_id = pallet_nfts::NextCollectionId::<T, I>::get()
-					.ok_or(Error::<T, I>::NoNextCollectionId)?;
-
-				// Go from next_collection_id to current_next_collection_id and try to find the
-				// collection that is owned by the user
-				while next_collection_id != current_next_collection_id {
-					if pallet_nfts::Pallet::<T, I>::collection_owner(next_collection_id).unwrap() ==
-						signed_origin.clone()
-					{
-						// We have users collection
-						user_collection = next_collection_id.clone();
-						break;
-					}
-					next_collection_id =
-						next_collection_id.increment().ok_or(Error::<T, I>::NoNextCollectionId)?;
-				}
-			}
+			ensure!(queued.owner == who, Error::<T, I>::NotQueuedMessageOwner);
 
-			// Set the collection metadata if not empty
-			if !collection_metadata.is_empty() {
-				match pallet_nfts::Pallet::<T, I>::set_collection_metadata(
-					origin.clone(),
-					user_collection.clone(),
-					collection_metadata.clone(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						// Deposit event indicating failure to set metadata
-						Self::deposit_event(Event::CollectionMetadataSetFailed {
-							collection_id: origin_collection.clone(),
-							owner: signed_origin_lookup.clone(),
-							error: e,
-						});
-					},
-				}
-			}
+			Self::remove_queued_message(message_hash, &queued);
 
-			// Emit a success event
-			Self::deposit_event(Event::CollectionReceived {
-				origin_collection_id: origin_collection.clone(),
-				received_collection_id: origin_collection.clone(),
-				to_address: signed_origin_lookup,
-			});
+			Self::deposit_event(Event::QueuedMessageCancelled { message_hash, owner: who });
 
 			Ok(().into())
 		}
 
-		/// Receive function for collection_x_burn function.
+		/// Restore assets of transfer reported to fail on destination chain, which failed to be
+		/// restored along with the report.
 		///
-		/// Doesn't differ from nfts pallet destroy function.
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the transferred `Asset` or `Collection`;
 		///
-		/// On success emits regular destroy function events.
-		#[pallet::call_index(12)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_collection_burn(
+		/// Assets of transfer are restored only after destination chain reports its failure,
+		/// message that timed out may still have been executed on destination chain.
+		///
+		/// Arguments:
+		/// - `message_hash`: The id of failed message.
+		///
+		/// On success emits `FailedTransferRestored`.
+		#[pallet::call_index(33)]
+		#[pallet::weight(Pallet::<T, I>::failed_transfer_weight(message_hash))]
+		pub fn restore_failed_transfer(
 			origin: OriginFor<T>,
-			collection_to_burn: T::CollectionId,
-			witness_data: GeneralizedDestroyWitness,
+			message_hash: XcmHash,
 		) -> DispatchResultWithPostInfo {
-			let signed_origin = ensure_signed(origin.clone())?;
-			let signed_origin_lookup = T::Lookup::unlookup(signed_origin.clone());
+			let who = ensure_signed(origin)?;
 
-			let destroy_witness = DestroyWitness {
-				item_metadatas: witness_data.item_meta.clone(),
-				item_configs: witness_data.item_configs.clone(),
-				attributes: witness_data.attributes.clone(),
-			};
+			let transfer = FailedTransfers::<T, I>::get(message_hash)
+				.ok_or(Error::<T, I>::TransferNotPending)?;
 
-			match pallet_nfts::Pallet::<T, I>::destroy(
-				origin.clone(),
-				collection_to_burn.clone(),
-				destroy_witness.clone(),
-			) {
-				Ok(_) => {},
-				Err(e) => {
-					// Deposit event indicating failure to burn collection
-					Self::deposit_event(Event::CollectionBurnFailed {
-						owner: signed_origin_lookup.clone(),
-						collection_id: collection_to_burn.clone(),
-						error: e,
-					});
-				},
-			}
+			ensure!(transfer.owner() == &who, Error::<T, I>::NotTransferOwner);
 
-			Ok(().into())
-		}
+			FailedTransfers::<T, I>::remove(message_hash);
 
-		/// Receive function for collection_x_update function.
-		///
-		/// Doesn't differ from nfts pallet setCollectionMetadara function.
-		///
-		/// On success emits regular setCollectionMetadata function events.
-		#[pallet::call_index(13)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_collection_metadata(
-			origin: OriginFor<T>,
-			collection: T::CollectionId,
-			data: BoundedVec<u8, T::StringLimit>,
-		) -> DispatchResultWithPostInfo {
-			let signed_origin = ensure_signed(origin.clone())?;
-			let signed_origin_lookup = T::Lookup::unlookup(signed_origin.clone());
+			let collection_id = Self::restore_transfer(transfer)?;
 
-			match pallet_nfts::Pallet::<T, I>::set_collection_metadata(
-				origin.clone(),
-				collection.clone(),
-				data.clone(),
-			) {
-				Ok(_) => {},
-				Err(e) => {
-					// Deposit event indicating failure to set metadata
-					Self::deposit_event(Event::CollectionMetadataSetFailed {
-						collection_id: collection.clone(),
-						owner: signed_origin_lookup.clone(),
-						error: e,
-					});
-				},
-			}
+			Self::deposit_event(Event::FailedTransferRestored {
+				message_hash,
+				collection_id,
+				owner: who,
+			});
 
 			Ok(().into())
 		}
 
-		/// Receive function for collection_x_change_owner function.
+		/// Transfer an asset along with associated metadata to another parachain.
 		///
-		/// Doesn't differ from nfts pallet transferOwnership function.
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the `Asset`;
 		///
-		/// On success emits regular transferOwnership function events.
-		#[pallet::call_index(14)]
+		/// Arguments:
+		/// - `origin_collection`: The collection_id of the collection to be transferred.
+		/// - `origin_asset`: The asset_id of the asset to be transferred.
+		/// - `destination_para`: The destination chain ID to which collection is transferred.
+		/// - `destination_collection`: The collection_id of the collection that the asset have to
+		///   be received into.
+		/// - `destination_asset`: The asset_id of the asset to be received, if `None`, the
+		///   destination keeps origin asset_id when it is free or allocates a free one.
+		/// - `beneficiary`: The account location that receives the asset on destination chain.
+		/// - `deposit_asset`: Optional native or reserve asset in which the sender funds storage
+		///   deposits of the beneficiary on destination chain.
+		///
+		/// On success emits `NFTTransferred`.
+		#[pallet::call_index(3)]
 		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_collection_owner(
+		pub fn nft_x_transfer(
 			origin: OriginFor<T>,
-			new_owner: AccountIdLookupOf<T>,
-			collection: T::CollectionId,
+			origin_collection: T::CollectionId,
+			origin_asset: T::ItemId,
+			destination_para: ParaId,
+			destination_collection: T::CollectionId,
+			destination_asset: Option<T::ItemId>,
+			beneficiary: Location,
+			deposit_asset: Option<AssetId>,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin.clone())?;
+			let who = ensure_signed(origin)?;
 
-			match pallet_nfts::Pallet::<T, I>::transfer_ownership(
-				origin.clone(),
-				collection.clone(),
-				new_owner.clone(),
-			) {
-				Ok(_) => {},
-				Err(e) => {
-					// Deposit event indicating failure to transfer ownership
-					Self::deposit_event(Event::CollectionOwnershipTransferFailed {
-						collection_id: collection.clone(),
-						owner: new_owner.clone(),
-						error: e,
-					});
-				},
+			ensure!(Self::is_account_location(&beneficiary), Error::<T, I>::InvalidBeneficiary);
+
+			// See if collection exists
+			ensure!(
+				pallet_nfts::Collection::<T, I>::contains_key(&origin_collection),
+				Error::<T, I>::CollectionDoesNotExist
+			);
+
+			// See if item exists
+			ensure!(
+				pallet_nfts::Item::<T, I>::contains_key(&origin_collection, &origin_asset),
+				Error::<T, I>::NFTDoesNotExist
+			);
+
+			// See if user owns the item
+			ensure!(
+				pallet_nfts::Pallet::<T, I>::owner(origin_collection.clone(), origin_asset.clone())
+					.ok_or(Error::<T, I>::NFTDoesNotExist)? ==
+					who.clone(),
+				Error::<T, I>::NotNFTOwner
+			);
+
+			// See if the item is not locked by an open proposal
+			ensure!(
+				!ProposalOfCollection::<T, I>::contains_key(&origin_collection),
+				Error::<T, I>::CollectionUnderProposal
+			);
+
+			// Get Item data
+			let metadata = Self::sent_metadata(&origin_collection, Some(&origin_asset));
+
+			// NFT returning to its origin collection keeps its origin asset_id
+			let destination_asset = destination_asset.or_else(|| {
+				ReceivedAssets::<T, I>::get(&(origin_collection.clone(), origin_asset.clone()))
+					.filter(|received| {
+						received.origin_para_id == destination_para &&
+							received.origin_collection_id == destination_collection
+					})
+					.map(|received| received.origin_asset_id)
+			});
+
+			// Convert account into location used as origin on destination chain
+			let who_location =
+				Self::account_to_location(&who).ok_or(Error::<T, I>::AccountNotConvertible)?;
+
+			// Storage deposits of the beneficiary on destination chain are funded by the sender
+			let deposit =
+				Self::deposit_funding(deposit_asset, Self::item_deposit(&metadata), &beneficiary);
+
+			// Destination chain verifies received metadata against hashes of sent metadata,
+			// collection metadata is not sent along with the NFT
+			let metadata_hashes =
+				Self::metadata_hashes(&origin_collection, &origin_asset, None, &metadata);
+
+			let call = Call::<T, I>::parse_nft_transfer {
+				origin_collection: origin_collection.clone(),
+				origin_item: origin_asset.clone(),
+				collection: destination_collection.clone(),
+				item: destination_asset.clone(),
+				data: metadata,
+				metadata_hashes,
+				origin_chain: parachain_info::Pallet::<T>::parachain_id(),
+				beneficiary,
+			};
+
+			// Payload has to fit into a block of destination chain
+			ensure!(
+				Self::transact_weight(&call).all_lte(T::MaxTransactWeight::get()),
+				Error::<T, I>::PayloadTooHeavy
+			);
+
+			// Send the asset along with associated metadata cross-chain
+			match Self::send_transact(
+				destination_para,
+				who_location.clone(),
+				OriginKind::SovereignAccount,
+				call,
+				deposit.clone(),
+			) {
+				Ok((message_hash, _cost)) => Self::complete_nft_transfer(
+					message_hash,
+					&who,
+					origin_collection.clone(),
+					origin_asset.clone(),
+					destination_para,
+					destination_collection.clone(),
+					destination_asset.clone(),
+				),
+				Err((message_hash, e, call)) => {
+					// Transient failures are retried from `on_idle`, while the NFT is locked
+					if !Self::queue_message(
+						message_hash,
+						&e,
+						destination_para,
+						who_location,
+						OriginKind::SovereignAccount,
+						call,
+						deposit,
+					) {
+						Self::deposit_event(Event::CollectionFailedToXCM {
+							e,
+							message_hash,
+							collection_id: origin_collection.clone(),
+							owner: who.clone(),
+							destination: destination_para.clone(),
+						});
+					}
+				},
+			}
+			Ok(().into())
+		}
+
+		/// Claim cross-chain sent asset if its origin collection was also sent to same destination
+		/// chain.
+		///
+		/// Assets received after their origin collection are received directly into it, so claim
+		/// is only needed for assets received before their origin collection.
+		///
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the asset in the `Current collection` and owner of the asset in the
+		///   `Origin collection`;
+		///
+		/// Arguments:
+		/// - `origin_collection_at_destination`: The collection_id at the destination of the
+		///   collection that was transferred (Id it has at destination chain).
+		/// - `origin_collection_at_origin`: The origin collection_id of the collection that was
+		///   transferred (Id it had at origin chain).
+		/// - `origin_asset_at_destination`: The origin asset id at the origin collection that is
+		///   delivered.
+		/// - `current_collection`: The collection_id of the collection that the asset have been
+		///   delivered into.
+		/// - `current_asset`: The current asset_id of the asset in current collection.
+		///
+		/// On success emits `NFTClaimed`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn nft_x_claim(
+			origin: OriginFor<T>,
+			origin_collection_at_destination: T::CollectionId,
+			origin_collection_at_origin: T::CollectionId,
+			origin_asset_at_destination: T::ItemId,
+			current_collection: T::CollectionId,
+			current_asset: T::ItemId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+
+			// See if collection exists
+			ensure!(
+				pallet_nfts::Collection::<T, I>::contains_key(&current_collection),
+				Error::<T, I>::CollectionDoesNotExist
+			);
+
+			// See if origin collection exists
+			ensure!(
+				pallet_nfts::Collection::<T, I>::contains_key(&origin_collection_at_destination),
+				Error::<T, I>::CollectionDoesNotExist
+			);
+
+			// See if origin collection at origin is the same as in received collection
+			ensure!(
+				ReceivedCollections::<T, I>::get(&origin_collection_at_destination)
+					.unwrap()
+					.origin_collection_id ==
+					origin_collection_at_origin,
+				Error::<T, I>::WrongOriginCollectionAtOrigin
+			);
+
+			// See if current asset is in received assets
+			ensure!(
+				ReceivedAssets::<T, I>::contains_key(&(
+					current_collection.clone(),
+					current_asset.clone()
+				)),
+				Error::<T, I>::NFTNotReceived
+			);
+
+			// See if item in origin collection exists
+			ensure!(
+				pallet_nfts::Item::<T, I>::contains_key(
+					&origin_collection_at_destination,
+					&origin_asset_at_destination
+				),
+				Error::<T, I>::NFTDoesNotExist
+			);
+
+			// See if user owns the item
+			ensure!(
+				pallet_nfts::Pallet::<T, I>::owner(
+					origin_collection_at_destination.clone(),
+					origin_asset_at_destination.clone()
+				)
+				.ok_or(Error::<T, I>::NFTDoesNotExist)? ==
+					who.clone(),
+				Error::<T, I>::NotNFTOwner
+			);
+
+			// See if user owns the current asset
+			ensure!(
+				pallet_nfts::Pallet::<T, I>::owner(
+					current_collection.clone(),
+					current_asset.clone()
+				)
+				.ok_or(Error::<T, I>::NFTDoesNotExist)? ==
+					who.clone(),
+				Error::<T, I>::NotNFTOwner
+			);
+
+			// Claim the asset
+			let mut metadata = Some(BoundedVec::new());
+
+			if pallet_nfts::ItemMetadataOf::<T, I>::contains_key(
+				current_collection.clone(),
+				current_asset.clone(),
+			) {
+				metadata = pallet_nfts::ItemMetadataOf::<T, I>::get(
+					current_collection.clone(),
+					current_asset.clone(),
+				)
+				.map(|i| i.data);
+			}
+
+			// Burn the current asset
+			let _ = pallet_nfts::Pallet::<T, I>::burn(
+				origin.clone(),
+				current_collection.clone(),
+				current_asset.clone(),
+			);
+
+			// Add the metadata to the old asset location
+			if metadata.is_some() {
+				let _ = pallet_nfts::Pallet::<T, I>::set_metadata(
+					origin.clone(),
+					origin_collection_at_destination.clone(),
+					origin_asset_at_destination.clone(),
+					metadata.unwrap(),
+				);
+			}
+
+			// Remove asset from received
+			ReceivedAssets::<T, I>::remove(&(current_collection.clone(), current_asset.clone()));
+
+			// Metadata hashes follow the claimed asset
+			if let Some(hashes) = ReceivedMetadataHashes::<T, I>::take(&(
+				current_collection.clone(),
+				current_asset.clone(),
+			)) {
+				ReceivedMetadataHashes::<T, I>::insert(
+					(origin_collection_at_destination.clone(), origin_asset_at_destination.clone()),
+					hashes,
+				);
+			}
+
+			// Emit a success event
+			Self::deposit_event(Event::NFTClaimed {
+				collection_claimed_from: current_collection.clone(),
+				asset_removed: current_asset.clone(),
+				collection_claimed_to: origin_collection_at_destination.clone(),
+				asset_claimed: origin_asset_at_destination.clone(),
+			});
+
+			Ok(().into())
+		}
+
+		/// Update collection metadata cross-chain.
+		///
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the `destination collection`;
+		///
+		/// Arguments:
+		/// - `destination_collection_id`: The collection_id at the destination.
+		/// - `destination_para`: The recipient parachain ID.
+		/// - `data`: The metadata to be added to destination collection.
+		///
+		/// On success emits `CollectionMetadataSent`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn collection_x_update(
+			origin: OriginFor<T>,
+			destination_collection_id: T::CollectionId,
+			destination_para: ParaId,
+			data: BoundedVec<u8, T::StringLimit>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// Convert account into location used as origin on destination chain
+			let who_location =
+				Self::account_to_location(&who).ok_or(Error::<T, I>::AccountNotConvertible)?;
+
+			// Send the prompt to update collection metadata
+			let call = Call::<T, I>::parse_collection_metadata {
+				collection: destination_collection_id.clone(),
+				data: data.to_vec(),
+			};
+
+			match Self::send_transact(
+				destination_para,
+				who_location.clone(),
+				OriginKind::Native,
+				call,
+				None,
+			) {
+				Ok((message_hash, _cost)) => {
+					// Emit event about sucessful metadata send
+					Self::deposit_event(Event::CollectionMetadataSent {
+						message_hash,
+						collection_id: destination_collection_id.clone(),
+						proposed_data: data.clone(),
+						owner: who.clone(),
+						destination: destination_para.clone(),
+					});
+				},
+				Err((message_hash, e, call)) => {
+					// Transient failures are retried from `on_idle`
+					if !Self::queue_message(
+						message_hash,
+						&e,
+						destination_para,
+						who_location,
+						OriginKind::Native,
+						call,
+						None,
+					) {
+						Self::deposit_event(Event::CollectionMetadataFailedToXCM {
+							e,
+							message_hash,
+							collection_id: destination_collection_id.clone(),
+							proposed_data: data.clone(),
+							owner: who.clone(),
+							destination: destination_para.clone(),
+						});
+					}
+				},
+			}
+			Ok(().into())
+		}
+
+		/// Update NFT metadata cross-chain.
+		///
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the `destination collection`;
+		///
+		/// Arguments:
+		/// - `destination_collection_id`: The collection_id at the destination.
+		/// - `destination_asset_id`: The asset_id at the destination.
+		/// - `destination_para`: The recipient parachain ID.
+		/// - `data`: The metadata to be added to destination collection.
+		///
+		/// On success emits `NFTMetadataSent`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn nft_x_update(
+			origin: OriginFor<T>,
+			destination_collection_id: T::CollectionId,
+			destination_asset_id: T::ItemId,
+			destination_para: ParaId,
+			data: BoundedVec<u8, T::StringLimit>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// Convert account into location used as origin on destination chain
+			let who_location =
+				Self::account_to_location(&who).ok_or(Error::<T, I>::AccountNotConvertible)?;
+
+			// Send the prompt to update NFT metadata
+			let call = Call::<T, I>::parse_nft_metadata {
+				collection: destination_collection_id.clone(),
+				item: destination_asset_id.clone(),
+				data: data.to_vec(),
+			};
+
+			match Self::send_transact(
+				destination_para,
+				who_location.clone(),
+				OriginKind::SovereignAccount,
+				call,
+				None,
+			) {
+				Ok((message_hash, _cost)) => {
+					// Emit event about sucessful metadata send
+					Self::deposit_event(Event::NFTMetadataSent {
+						message_hash,
+						collection_id: destination_collection_id.clone(),
+						asset_id: destination_asset_id.clone(),
+						proposed_data: data.clone(),
+						owner: who.clone(),
+						destination: destination_para.clone(),
+					});
+				},
+				Err((message_hash, e, call)) => {
+					// Transient failures are retried from `on_idle`
+					if !Self::queue_message(
+						message_hash,
+						&e,
+						destination_para,
+						who_location,
+						OriginKind::SovereignAccount,
+						call,
+						None,
+					) {
+						Self::deposit_event(Event::NFTMetadataFailedToXCM {
+							e,
+							message_hash,
+							collection_id: destination_collection_id.clone(),
+							asset_id: destination_asset_id.clone(),
+							proposed_data: data.clone(),
+							owner: who.clone(),
+							destination: destination_para.clone(),
+						});
+					}
+				},
+			}
+			Ok(().into())
+		}
+
+		/// Prompt to burn empty collection cross-chain.
+		///
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the `destination collection`;
+		///
+		/// Arguments:
+		/// - `destination_collection_id`: The collection_id at the destination.
+		/// - `destination_para`: The recipient parachain ID.
+		/// - `witness_data`: The amount of NFTs, metadatas and configs in the collection (Needs to
+		///   be all zeros for successful burn).
+		///
+		/// On success emits `CollectionBurnSent`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn collection_x_burn(
+			origin: OriginFor<T>,
+			destination_collection_id: T::CollectionId,
+			destination_para: ParaId,
+			witnes_data: GeneralizedDestroyWitness,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// Convert account into location used as origin on destination chain
+			let who_location =
+				Self::account_to_location(&who).ok_or(Error::<T, I>::AccountNotConvertible)?;
+
+			// Send the prompt to burn collection
+			let call = Call::parse_collection_burn {
+				collection_to_burn: destination_collection_id.clone(),
+				witness_data: witnes_data.clone(),
+			};
+
+			match Self::send_transact(
+				destination_para,
+				who_location.clone(),
+				OriginKind::SovereignAccount,
+				call,
+				None,
+			) {
+				Ok((message_hash, _cost)) => {
+					// Emit event about sucessful burn prompt transfer
+					Self::deposit_event(Event::CollectionBurnSent {
+						message_hash,
+						collection_id: destination_collection_id.clone(),
+						burn_data: witnes_data.clone(),
+						owner: who.clone(),
+						destination: destination_para.clone(),
+					});
+				},
+				Err((message_hash, e, call)) => {
+					// Transient failures are retried from `on_idle`
+					if !Self::queue_message(
+						message_hash,
+						&e,
+						destination_para,
+						who_location,
+						OriginKind::SovereignAccount,
+						call,
+						None,
+					) {
+						Self::deposit_event(Event::CollectionBurnFailedToXCM {
+							e,
+							message_hash,
+							collection_id: destination_collection_id.clone(),
+							burn_data: witnes_data.clone(),
+							owner: who.clone(),
+							destination: destination_para.clone(),
+						});
+					}
+				},
+			}
+
+			Ok(().into())
+		}
+
+		/// Prompt to burn NFT cross-chain.
+		///
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the `destination NFT`;
+		///
+		/// Arguments:
+		/// - `destination_collection_id`: The collection_id at the destination.
+		/// - `destination_asset_id`: The asset_id at the destination.
+		/// - `destination_para`: The recipient parachain ID.
+		///
+		/// On success emits `NFTBurnSent`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn nft_x_burn(
+			origin: OriginFor<T>,
+			destination_collection_id: T::CollectionId,
+			destination_asset_id: T::ItemId,
+			destination_para: ParaId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// Convert account into location used as origin on destination chain
+			let who_location =
+				Self::account_to_location(&who).ok_or(Error::<T, I>::AccountNotConvertible)?;
+
+			// Send the prompt to burn NFT
+			let call = Call::<T, I>::parse_nft_burn {
+				collection: destination_collection_id.clone(),
+				item: destination_asset_id.clone(),
+			};
+
+			match Self::send_transact(
+				destination_para,
+				who_location.clone(),
+				OriginKind::SovereignAccount,
+				call,
+				None,
+			) {
+				Ok((message_hash, _cost)) => {
+					// Emit event about sucessful metadata send
+					Self::deposit_event(Event::NFTBurnSent {
+						message_hash,
+						collection_id: destination_collection_id.clone(),
+						asset_id: destination_asset_id.clone(),
+						owner: who.clone(),
+						destination: destination_para.clone(),
+					});
+				},
+				Err((message_hash, e, call)) => {
+					// Transient failures are retried from `on_idle`
+					if !Self::queue_message(
+						message_hash,
+						&e,
+						destination_para,
+						who_location,
+						OriginKind::SovereignAccount,
+						call,
+						None,
+					) {
+						Self::deposit_event(Event::NFTBurnFailedToXCM {
+							e,
+							message_hash,
+							collection_id: destination_collection_id.clone(),
+							asset_id: destination_asset_id.clone(),
+							owner: who.clone(),
+							destination: destination_para.clone(),
+						});
+					}
+				},
+			}
+
+			Ok(().into())
+		}
+
+		/// Prompt to change collection owner cross-chain.
+		///
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the `destination collection`;
+		/// - the New owner must agree to the ownership change by executing function
+		///   setAcceptOwnership(maybeCollection)
+		///
+		/// Arguments:
+		/// - `destination_collection_id`: The collection_id at the destination.
+		/// - `destination_para`: The recipient parachain ID.
+		/// - `destination_account`: The destination account that will receive collection.
+		///
+		/// On success emits `CollectionOwnershipSent`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn collection_x_change_owner(
+			origin: OriginFor<T>,
+			destination_collection_id: T::CollectionId,
+			destination_para: ParaId,
+			destination_account: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// Convert account into location used as origin on destination chain
+			let who_location =
+				Self::account_to_location(&who).ok_or(Error::<T, I>::AccountNotConvertible)?;
+
+			// Send the prompt to change collection owner
+			let call = Call::<T, I>::parse_collection_owner {
+				new_owner: destination_account.clone(),
+				collection: destination_collection_id.clone(),
+			};
+
+			match Self::send_transact(
+				destination_para,
+				who_location.clone(),
+				OriginKind::SovereignAccount,
+				call,
+				None,
+			) {
+				Ok((message_hash, _cost)) => {
+					// Emit event about sucessful metadata send
+					Self::deposit_event(Event::CollectionOwnershipSent {
+						message_hash,
+						collection_id: destination_collection_id.clone(),
+						proposed_owner: destination_account.clone(),
+						destination: destination_para.clone(),
+					});
+				},
+				Err((message_hash, e, call)) => {
+					// Transient failures are retried from `on_idle`
+					if !Self::queue_message(
+						message_hash,
+						&e,
+						destination_para,
+						who_location,
+						OriginKind::SovereignAccount,
+						call,
+						None,
+					) {
+						Self::deposit_event(Event::CollectionOwnershipFailedToXCM {
+							e,
+							message_hash,
+							collection_id: destination_collection_id.clone(),
+							proposed_owner: destination_account.clone(),
+							destination: destination_para.clone(),
+						});
+					}
+				},
+			}
+
+			Ok(().into())
+		}
+
+		/// Prompt to change NFT owner cross-chain.
+		///
+		/// Origin must be Signed and the signing account must be :
+		/// - the Owner of the `destination asset`;
+		///
+		/// Arguments:
+		/// - `destination_collection_id`: The collection_id at the destination.
+		/// - `destination_asset_id`: The asset_id at the destination.
+		/// - `destination_para`: The recipient parachain ID.
+		/// - `destination_account`: The destination account that will receive collection.
+		///
+		/// On success emits `NFTOwnershipSent`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn nft_x_change_owner(
+			origin: OriginFor<T>,
+			destination_collection_id: T::CollectionId,
+			destination_asset_id: T::ItemId,
+			destination_para: ParaId,
+			destination_account: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// Convert account into location used as origin on destination chain
+			let who_location =
+				Self::account_to_location(&who).ok_or(Error::<T, I>::AccountNotConvertible)?;
+
+			// Send the prompt to change NFT owner
+			let call = Call::<T, I>::parse_nft_owner {
+				new_owner: destination_account.clone(),
+				collection: destination_collection_id.clone(),
+				item: destination_asset_id.clone(),
+			};
+
+			match Self::send_transact(
+				destination_para,
+				who_location.clone(),
+				OriginKind::SovereignAccount,
+				call,
+				None,
+			) {
+				Ok((message_hash, _cost)) => {
+					// Emit event about sucessful metadata send
+					Self::deposit_event(Event::NFTOwnershipSent {
+						message_hash,
+						collection_id: destination_collection_id.clone(),
+						asset_id: destination_asset_id.clone(),
+						proposed_owner: destination_account.clone(),
+						destination: destination_para.clone(),
+					});
+				},
+				Err((message_hash, e, call)) => {
+					// Transient failures are retried from `on_idle`
+					if !Self::queue_message(
+						message_hash,
+						&e,
+						destination_para,
+						who_location,
+						OriginKind::SovereignAccount,
+						call,
+						None,
+					) {
+						Self::deposit_event(Event::NFTOwnershipFailedToXCM {
+							e,
+							message_hash,
+							collection_id: destination_collection_id.clone(),
+							asset_id: destination_asset_id.clone(),
+							proposed_owner: destination_account.clone(),
+							destination: destination_para.clone(),
+						});
+					}
+				},
+			}
+
+			Ok(().into())
+		}
+
+		/// Receive function for collection_x_transfer function.
+		///
+		/// Shouldn't be used as a regular call.
+		///
+		/// Collection is owned by `beneficiary`.
+		///
+		/// On success emits `CollectionReceived`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_collection_empty(
+			origin: OriginFor<T>,
+			origin_collection: T::CollectionId,
+			_destination_collection: Option<T::CollectionId>,
+			collection_metadata: Vec<u8>,
+			config: Option<CollectionConfigFor<T, I>>,
+			beneficiary: Location,
+		) -> DispatchResultWithPostInfo {
+			let (sender, sender_chain) = Self::ensure_sender(origin)?;
+
+			// Collection is created by the beneficiary chosen on origin chain, other than the
+			// sender only if xcNFT pallet of origin chain is verified to send the call
+			let signed_origin =
+				Self::location_to_account(&beneficiary).ok_or(Error::<T, I>::InvalidBeneficiary)?;
+			ensure!(
+				signed_origin == sender || sender_chain.is_some(),
+				Error::<T, I>::UnverifiedSender
+			);
+			let signed_origin_lookup = T::Lookup::unlookup(signed_origin.clone());
+			let origin: OriginFor<T> =
+				frame_system::RawOrigin::Signed(signed_origin.clone()).into();
+
+			// Collection is created under id returned by the nfts pallet
+			let user_collection = Self::do_create_collection(&signed_origin, config)?;
+
+			// Set the collection metadata if not empty
+			if !collection_metadata.is_empty() {
+				let data = Self::receive_metadata(&user_collection, None, collection_metadata)?;
+				pallet_nfts::Pallet::<T, I>::set_collection_metadata(
+					origin.clone(),
+					user_collection.clone(),
+					data,
+				)?;
+			}
+
+			// Index received collection by its origin
+			Self::index_received_collection(sender_chain, &origin_collection, &user_collection);
+
+			// Emit a success event
+			Self::deposit_event(Event::CollectionReceived {
+				origin_collection_id: origin_collection.clone(),
+				received_collection_id: user_collection.clone(),
+				to_address: signed_origin_lookup,
+			});
+
+			Ok(().into())
+		}
+
+		/// Receive function for collection_x_burn function.
+		///
+		/// Doesn't differ from nfts pallet destroy function.
+		///
+		/// On success emits regular destroy function events.
+		#[pallet::call_index(12)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_collection_burn(
+			origin: OriginFor<T>,
+			collection_to_burn: T::CollectionId,
+			witness_data: GeneralizedDestroyWitness,
+		) -> DispatchResultWithPostInfo {
+			let (who, _) = Self::ensure_sender(origin)?;
+			let origin: OriginFor<T> = frame_system::RawOrigin::Signed(who).into();
+
+			let destroy_witness = DestroyWitness {
+				item_metadatas: witness_data.item_meta.clone(),
+				item_configs: witness_data.item_configs.clone(),
+				attributes: witness_data.attributes.clone(),
+			};
+
+			pallet_nfts::Pallet::<T, I>::destroy(
+				origin.clone(),
+				collection_to_burn.clone(),
+				destroy_witness.clone(),
+			)?;
+
+			Ok(().into())
+		}
+
+		/// Receive function for collection_x_update function.
+		///
+		/// Doesn't differ from nfts pallet setCollectionMetadara function.
+		///
+		/// On success emits regular setCollectionMetadata function events.
+		#[pallet::call_index(13)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_collection_metadata(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			data: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let (who, _) = Self::ensure_sender(origin)?;
+			let origin: OriginFor<T> = frame_system::RawOrigin::Signed(who).into();
+
+			let data = Self::receive_metadata(&collection, None, data)?;
+			pallet_nfts::Pallet::<T, I>::set_collection_metadata(
+				origin.clone(),
+				collection.clone(),
+				data,
+			)?;
+
+			Ok(().into())
+		}
+
+		/// Receive function for collection_x_change_owner function.
+		///
+		/// Doesn't differ from nfts pallet transferOwnership function.
+		///
+		/// On success emits regular transferOwnership function events.
+		#[pallet::call_index(14)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_collection_owner(
+			origin: OriginFor<T>,
+			new_owner: AccountIdLookupOf<T>,
+			collection: T::CollectionId,
+		) -> DispatchResultWithPostInfo {
+			let (who, _) = Self::ensure_sender(origin)?;
+			let origin: OriginFor<T> = frame_system::RawOrigin::Signed(who).into();
+
+			pallet_nfts::Pallet::<T, I>::transfer_ownership(
+				origin.clone(),
+				collection.clone(),
+				new_owner.clone(),
+			)?;
+
+			Ok(().into())
+		}
+
+		/// Receive function for nft_x_burn function.
+		///
+		/// Doesn't differ from nfts pallet burn function.
+		///
+		/// On success emits regular burn function events.
+		#[pallet::call_index(15)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_nft_burn(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResultWithPostInfo {
+			let (who, _) = Self::ensure_sender(origin)?;
+			let origin: OriginFor<T> = frame_system::RawOrigin::Signed(who).into();
+
+			pallet_nfts::Pallet::<T, I>::burn(origin.clone(), collection.clone(), item.clone())?;
+
+			Ok(().into())
+		}
+
+		/// Receive function for  nft_x_update function.
+		///
+		/// Doesn't differ from nfts pallet setMetadata function.
+		///
+		/// On success emits regular setMetadata function events.
+		#[pallet::call_index(16)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_nft_metadata(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			data: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let (who, _) = Self::ensure_sender(origin)?;
+			let origin: OriginFor<T> = frame_system::RawOrigin::Signed(who).into();
+
+			let data = Self::receive_metadata(&collection, Some(&item), data)?;
+			pallet_nfts::Pallet::<T, I>::set_metadata(
+				origin.clone(),
+				collection.clone(),
+				item.clone(),
+				data,
+			)?;
+
+			Ok(().into())
+		}
+
+		/// Receive function for  nft_x_change_owner function.
+		///
+		/// Doesn't differ from nfts pallet transfer function.
+		///
+		/// On success emits regular transfer function events.
+		#[pallet::call_index(17)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_nft_owner(
+			origin: OriginFor<T>,
+			new_owner: AccountIdLookupOf<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResultWithPostInfo {
+			let (who, _) = Self::ensure_sender(origin)?;
+			let origin: OriginFor<T> = frame_system::RawOrigin::Signed(who).into();
+
+			pallet_nfts::Pallet::<T, I>::transfer(
+				origin.clone(),
+				collection.clone(),
+				item.clone(),
+				new_owner.clone(),
+			)?;
+
+			Ok(().into())
+		}
+
+		/// Receive function for  nft_x_transfer function.
+		///
+		/// Shouldn't be used as a regular call.
+		///
+		/// If origin collection of the asset was already received, asset is received into it. If
+		/// `item` is `None`, origin asset_id is kept when it is free, otherwise free asset_id is
+		/// allocated. Asset is received by `beneficiary`. Sender has to own the collection, unless
+		/// the call is verified to come from origin chain, whose assets are received into
+		/// derivative collection if collection owner did not allow their origin. Received
+		/// collection and asset are reported back to origin chain.
+		///
+		/// On success emits `NFTReceived` or `NFTReturnedToOrigin` events.
+		#[pallet::call_index(18)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_nft_transfer(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: Option<T::ItemId>,
+			data: Vec<u8>,
+			metadata_hashes: MetadataHashes,
+			origin_collection: T::CollectionId,
+			origin_item: T::ItemId,
+			origin_chain: ParaId,
+			beneficiary: Location,
+		) -> DispatchResultWithPostInfo {
+			let (signed_origin, sender_chain) = Self::ensure_sender(origin)?;
+			ensure!(
+				sender_chain.map_or(true, |chain| chain == origin_chain),
+				Error::<T, I>::InvalidSender
+			);
+			let verified = sender_chain.is_some();
+
+			// Received metadata has to match hashes computed on origin chain
+			Self::verify_metadata_hashes(&metadata_hashes, None, &data)?;
+
+			// NFT is received by the beneficiary chosen on origin chain
+			let recipient =
+				Self::location_to_account(&beneficiary).ok_or(Error::<T, I>::InvalidBeneficiary)?;
+			let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+
+			// Check if the collection exists
+			ensure!(
+				pallet_nfts::Collection::<T, I>::contains_key(&collection),
+				Error::<T, I>::CollectionDoesNotExist
+			);
+
+			let item = match item {
+				Some(item) => item,
+				None => Self::allocate_item_id(&collection, &origin_item)?,
+			};
+
+			// Check if not in receiving assets
+			ensure!(
+				!ReceivedAssets::<T, I>::contains_key(&(collection.clone(), item.clone())),
+				Error::<T, I>::NFTAlreadyReceived
+			);
+
+			if SentAssets::<T, I>::contains_key(&(collection.clone(), item.clone())) {
+				// User returns nft to origin collection, use collection owner to add metadata
+				let col_owner =
+					pallet_nfts::Pallet::<T, I>::collection_owner(collection.clone()).unwrap();
+				let signed_col: OriginFor<T> =
+					frame_system::RawOrigin::Signed(col_owner.clone()).into();
+				let sent_asset =
+					SentAssets::<T, I>::get(&(collection.clone(), item.clone())).unwrap();
+
+				if sent_asset.origin_para_id == parachain_info::Pallet::<T>::parachain_id() {
+					let nft_owner =
+						pallet_nfts::Pallet::<T, I>::owner(collection.clone(), item.clone());
+
+					// Unverified sender can only return NFT it still owns on origin chain
+					ensure!(
+						verified ||
+							nft_owner.as_ref().map_or(true, |owner| *owner == signed_origin),
+						Error::<T, I>::NotNFTOwner
+					);
+
+					// We know we are the origin chain, we can add only metadata
+					if !data.is_empty() {
+						let data = Self::receive_metadata(&collection, Some(&item), data)?;
+						pallet_nfts::Pallet::<T, I>::set_metadata(
+							signed_col.clone(),
+							collection.clone(),
+							item.clone(),
+							data,
+						)?;
+					}
+
+					// NFT stays with its owner on origin chain, it is transferred to beneficiary
+					// only if the owner sent it
+					if nft_owner.as_ref() == Some(&signed_origin) && signed_origin != recipient {
+						pallet_nfts::Pallet::<T, I>::transfer(
+							frame_system::RawOrigin::Signed(signed_origin.clone()).into(),
+							collection.clone(),
+							item.clone(),
+							recipient_lookup.clone(),
+						)?;
+					}
+
+					// We also remove sent assets and received assets
+					SentAssets::<T, I>::remove(&(collection.clone(), item.clone()));
+					PendingConfirmations::<T, I>::remove(&(collection.clone(), item.clone()));
+
+					// We emit event about return to origin chain
+					Self::deposit_event(Event::NFTReturnedToOrigin {
+						returned_from_collection_id: collection.clone(),
+						returned_from_asset_id: item.clone(),
+						to_address: recipient.clone(),
+					});
+
+					return Ok(().into())
+				} else {
+					// The item returns to chain, that sent it already, but it is not origin,
+					// proceeding as normal, but removing item from sent assets
+					SentAssets::<T, I>::remove(&(collection.clone(), item.clone()));
+					PendingConfirmations::<T, I>::remove(&(collection.clone(), item.clone()));
+				}
+			}
+
+			// If origin collection of the NFT was already received by this chain, the NFT is
+			// received directly into that collection, so it does not have to be claimed
+			if let Some(derivative_collection) = ReceivedCollectionsByOrigin::<T, I>::get(
+				origin_chain.clone(),
+				origin_collection.clone(),
+			) {
+				let derivative_owner = pallet_nfts::Pallet::<T, I>::owner(
+					derivative_collection.clone(),
+					origin_item.clone(),
+				);
+				let collection_owner =
+					pallet_nfts::Pallet::<T, I>::collection_owner(derivative_collection.clone());
+
+				// Index is followed for calls verified to come from origin chain or sent by owner
+				// of the received collection
+				if collection_owner.map_or(false, |owner| verified || owner == signed_origin) &&
+					derivative_owner.map_or(true, |owner| owner == recipient)
+				{
+					Self::receive_into_derivative(
+						derivative_collection.clone(),
+						origin_item.clone(),
+						recipient.clone(),
+						data,
+					)?;
+					Self::record_metadata_hashes(
+						&derivative_collection,
+						&origin_item,
+						metadata_hashes,
+					);
+
+					// Report received collection and asset back to origin chain
+					Self::confirm_nft_transfer(
+						origin_chain.clone(),
+						&signed_origin,
+						origin_collection.clone(),
+						origin_item.clone(),
+						derivative_collection.clone(),
+						origin_item.clone(),
+					);
+
+					// Emit a success event
+					Self::deposit_event(Event::NFTReceived {
+						origin_collection_id: origin_collection.clone(),
+						origin_asset_id: origin_item.clone(),
+						received_collection_id: derivative_collection.clone(),
+						received_asset_id: origin_item.clone(),
+						to_address: recipient_lookup.clone(),
+					});
+
+					return Ok(().into())
+				}
+			}
+
+			let col_owner =
+				pallet_nfts::Pallet::<T, I>::collection_owner(collection.clone()).unwrap();
+
+			// NFT is received into derivative collection owned by the pallet account if sender
+			// does not own the collection and collection owner did not allow its verified origin
+			if col_owner != signed_origin &&
+				!(verified &&
+					Self::is_incoming_allowed(&collection, &origin_chain, &origin_collection))
+			{
+				// Only origin chain can fill derivative collections, other senders have to own
+				// the collection
+				ensure!(verified, Error::<T, I>::NotCollectionOwner);
+
+				let derivative_collection =
+					Self::derivative_collection(origin_chain.clone(), origin_collection.clone())?;
+
+				// Check if the item exists
+				ensure!(
+					!pallet_nfts::Item::<T, I>::contains_key(
+						derivative_collection.clone(),
+						&origin_item
+					),
+					Error::<T, I>::NFTExists
+				);
+
+				Self::receive_into_derivative(
+					derivative_collection.clone(),
+					origin_item.clone(),
+					recipient.clone(),
+					data,
+				)?;
+
+				// Add the item to the received item storage
+				ReceivedAssets::<T, I>::insert(
+					(derivative_collection.clone(), origin_item.clone()),
+					ReceivedStruct {
+						origin_para_id: origin_chain.clone(),
+						origin_collection_id: origin_collection.clone(),
+						origin_asset_id: origin_item.clone(),
+						received_collection_id: derivative_collection.clone(),
+						received_asset_id: origin_item.clone(),
+					},
+				);
+				Self::record_metadata_hashes(&derivative_collection, &origin_item, metadata_hashes);
+
+				// Report received collection and asset back to origin chain
+				Self::confirm_nft_transfer(
+					origin_chain.clone(),
+					&signed_origin,
+					origin_collection.clone(),
+					origin_item.clone(),
+					derivative_collection.clone(),
+					origin_item.clone(),
+				);
+
+				// Emit a success event
+				Self::deposit_event(Event::NFTReceived {
+					origin_collection_id: origin_collection.clone(),
+					origin_asset_id: origin_item.clone(),
+					received_collection_id: derivative_collection.clone(),
+					received_asset_id: origin_item.clone(),
+					to_address: recipient_lookup.clone(),
+				});
+
+				return Ok(().into())
+			}
+
+			// Collection owner mints the NFT, because allowed sender does not have to own the
+			// collection
+			let signed_col: OriginFor<T> = frame_system::RawOrigin::Signed(col_owner).into();
+
+			// Check if the item exists
+			ensure!(
+				!pallet_nfts::Item::<T, I>::contains_key(&collection.clone(), &item),
+				Error::<T, I>::NFTExists
+			);
+
+			pallet_nfts::Pallet::<T, I>::mint(
+				signed_col.clone(),
+				collection.clone(),
+				item.clone(),
+				recipient_lookup.clone(),
+				None,
+			)?;
+
+			if !data.is_empty() {
+				let data = Self::receive_metadata(&collection, Some(&item), data)?;
+				pallet_nfts::Pallet::<T, I>::set_metadata(
+					signed_col.clone(),
+					collection.clone(),
+					item.clone(),
+					data,
+				)?;
+			}
+
+			// Check if the NFT was minted successfuly
+			ensure!(
+				pallet_nfts::Item::<T, I>::contains_key(&collection, &item),
+				Error::<T, I>::NFTDoesNotExist
+			);
+
+			// Add the item to the received item storage
+			ReceivedAssets::<T, I>::insert(
+				(collection.clone(), item.clone()),
+				ReceivedStruct {
+					origin_para_id: origin_chain.clone(),
+					origin_collection_id: origin_collection.clone(),
+					origin_asset_id: origin_item.clone(),
+					received_collection_id: collection.clone(),
+					received_asset_id: item.clone(),
+				},
+			);
+			Self::record_metadata_hashes(&collection, &item, metadata_hashes);
+
+			// Report received collection and asset back to origin chain
+			Self::confirm_nft_transfer(
+				origin_chain.clone(),
+				&signed_origin,
+				origin_collection.clone(),
+				origin_item.clone(),
+				collection.clone(),
+				item.clone(),
+			);
+
+			// Emit a success event
+			Self::deposit_event(Event::NFTReceived {
+				origin_collection_id: origin_collection.clone(),
+				origin_asset_id: origin_item.clone(),
+				received_collection_id: collection.clone(),
+				received_asset_id: item.clone(),
+				to_address: recipient_lookup.clone(),
+			});
+
+			Ok(().into())
+		}
+
+		/// Receive function for confirmation of parse_nft_transfer function.
+		///
+		/// Shouldn't be used as a regular call.
+		///
+		/// Confirmation is accepted once, only from chain the NFT was sent to.
+		///
+		/// On success emits `NFTTransferConfirmed` event.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::parse_nft_transfer_confirmation())]
+		pub fn parse_nft_transfer_confirmation(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			destination_collection: T::CollectionId,
+			destination_item: T::ItemId,
+		) -> DispatchResultWithPostInfo {
+			let (_, sender_chain) = Self::ensure_sender(origin)?;
+
+			// Check if the NFT was sent
+			ensure!(
+				SentAssets::<T, I>::contains_key(&(collection.clone(), item.clone())),
+				Error::<T, I>::NFTNotSent
+			);
+
+			// Only destination chain of the NFT can confirm it
+			let destination =
+				PendingConfirmations::<T, I>::get(&(collection.clone(), item.clone()));
+			ensure!(
+				destination.is_some() && sender_chain == destination,
+				Error::<T, I>::InvalidSender
+			);
+			PendingConfirmations::<T, I>::remove(&(collection.clone(), item.clone()));
+
+			SentAssets::<T, I>::mutate(&(collection.clone(), item.clone()), |sent| {
+				if let Some(sent) = sent {
+					sent.destination_collection_id = destination_collection.clone();
+					sent.destination_asset_id = destination_item.clone();
+				}
+			});
+
+			Self::deposit_event(Event::NFTTransferConfirmed {
+				collection_id: collection,
+				asset_id: item,
+				destination_collection_id: destination_collection,
+				destination_asset_id: destination_item,
+			});
+
+			Ok(().into())
+		}
+
+		/// Receive function for confirmation of parse_collection_diff_owners function.
+		///
+		/// Shouldn't be used as a regular call.
+		///
+		/// Confirmation is accepted only for residual collection, from chain its NFTs were
+		/// migrated to.
+		///
+		/// On success emits `CollectionTransferConfirmed` event.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::parse_collection_transfer_confirmation())]
+		pub fn parse_collection_transfer_confirmation(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			destination_collection: T::CollectionId,
+		) -> DispatchResultWithPostInfo {
+			let (_, sender_chain) = Self::ensure_sender(origin)?;
+
+			// Only collection that stayed on this chain is linked with its destination
+			let mut residual = ResidualCollections::<T, I>::get(&collection)
+				.ok_or(Error::<T, I>::CollectionNotResidual)?;
+
+			// Only destination chain of the collection can confirm it
+			ensure!(
+				sender_chain == Some(residual.destination_para_id),
+				Error::<T, I>::InvalidSender
+			);
+
+			residual.destination_collection_id = Some(destination_collection.clone());
+			ResidualCollections::<T, I>::insert(&collection, &residual);
+
+			Self::deposit_event(Event::CollectionTransferConfirmed {
+				collection_id: collection,
+				destination_para_id: residual.destination_para_id,
+				destination_collection_id: destination_collection,
+			});
+
+			Ok(().into())
+		}
+
+		/// Receive function for messages carrying nonce of their sender.
+		///
+		/// Shouldn't be used as a regular call.
+		///
+		/// Message is rejected if `nonce` was already received from sending account on its origin
+		/// chain, which is the chain of verified sender, or `origin_chain` for signed calls.
+		/// Metadata, owner and burn prompts are also rejected if a prompt with a later
+		/// `message_index` from the same chain was already applied to the collection. `call` is
+		/// then dispatched with the same origin.
+		///
+		/// On success emits events of the dispatched call.
+		#[pallet::call_index(31)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + call.get_dispatch_info().call_weight)]
+		pub fn parse_sequenced(
+			origin: OriginFor<T>,
+			origin_chain: ParaId,
+			nonce: u64,
+			message_index: u64,
+			call: Box<Call<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			let (who, sender_chain) = Self::verified_sender(origin.clone())?;
+			let origin_chain = sender_chain.unwrap_or(origin_chain);
+
+			// Sequenced calls are not nested, so each call is checked once
+			ensure!(
+				!InSequencedCall::<T, I>::get() && !matches!(*call, Call::parse_sequenced { .. }),
+				Error::<T, I>::UnsequencedMessage
+			);
+
+			let mut window = InboundNonces::<T, I>::get(origin_chain, &who);
+			ensure!(window.receive(nonce), Error::<T, I>::DuplicateMessage);
+			InboundNonces::<T, I>::insert(origin_chain, &who, window);
+
+			// Prompts are applied to collection in the order they were sent from origin chain
+			if let Some(collection) = Self::prompt_collection(&call) {
+				ensure!(
+					!matches!(
+						PromptNonces::<T, I>::get(&collection),
+						Some((chain, last)) if chain == origin_chain && last >= message_index
+					),
+					Error::<T, I>::StalePrompt
+				);
+
+				PromptNonces::<T, I>::insert(&collection, (origin_chain, message_index));
+			}
+
+			InSequencedCall::<T, I>::put(true);
+			let result = call.dispatch_bypass_filter(origin);
+			InSequencedCall::<T, I>::kill();
+
+			result
+		}
+
+		/// Receive function for  collection_x_transfer function.
+		///
+		/// Used when collection has nfts, but they are owned by the same owner.
+		///
+		/// Shouldn't be used as a regular call.
+		///
+		/// Collection is owned by `beneficiary`.
+		///
+		/// On success emits `CollectionWithNftsReceived` event.
+		#[pallet::call_index(19)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_collection_same_owner(
+			origin: OriginFor<T>,
+			config: Option<CollectionConfigFor<T, I>>,
+			collection_metadata: Vec<u8>,
+			nfts: Vec<(T::ItemId, Vec<u8>)>,
+			metadata_hashes: Vec<MetadataHashes>,
+			origin_para: ParaId,
+			origin_collection_id: T::CollectionId,
+			_dest_collection_id: Option<T::CollectionId>,
+			beneficiary: Location,
+		) -> DispatchResultWithPostInfo {
+			let (sender, sender_chain) = Self::ensure_sender(origin)?;
+
+			// Received metadata has to match hashes computed on origin chain
+			ensure!(metadata_hashes.len() == nfts.len(), Error::<T, I>::MetadataHashMismatch);
+			for ((_, data), hashes) in nfts.iter().zip(metadata_hashes.iter()) {
+				Self::verify_metadata_hashes(hashes, Some(collection_metadata.as_slice()), data)?;
+			}
+
+			// Collection is created by the beneficiary chosen on origin chain, other than the
+			// sender only if xcNFT pallet of origin chain is verified to send the call
+			let who =
+				Self::location_to_account(&beneficiary).ok_or(Error::<T, I>::InvalidBeneficiary)?;
+			ensure!(
+				sender_chain.map_or(who == sender, |chain| chain == origin_para),
+				Error::<T, I>::UnverifiedSender
+			);
+			let signed_origin_lookup = T::Lookup::unlookup(who.clone());
+			let origin: OriginFor<T> = frame_system::RawOrigin::Signed(who.clone()).into();
+
+			// Collection is created under id returned by the nfts pallet
+			let user_collection = Self::do_create_collection(&who, config)?;
+
+			// Set the collection metadata if present
+			if !collection_metadata.is_empty() {
+				let data = Self::receive_metadata(&user_collection, None, collection_metadata)?;
+				pallet_nfts::Pallet::<T, I>::set_collection_metadata(
+					origin.clone(),
+					user_collection.clone(),
+					data,
+				)?;
+			}
+
+			//Iterate through vector of nfts
+			for (nft, hashes) in nfts.clone().into_iter().zip(metadata_hashes) {
+				let item = nft.0;
+				let data = nft.1;
+
+				pallet_nfts::Pallet::<T, I>::mint(
+					origin.clone(),
+					user_collection.clone(),
+					item.clone(),
+					signed_origin_lookup.clone(),
+					None,
+				)?;
+				//If empty metadata, skip
+				if !data.is_empty() {
+					let data = Self::receive_metadata(&user_collection, Some(&item), data)?;
+					pallet_nfts::Pallet::<T, I>::set_metadata(
+						origin.clone(),
+						user_collection.clone(),
+						item.clone(),
+						data,
+					)?;
+				}
+
+				// Check if the NFT was minted if storage contains the item
+				ensure!(
+					pallet_nfts::Item::<T, I>::contains_key(&user_collection, &item),
+					Error::<T, I>::NFTDoesNotExist
+				);
+
+				// Record metadata hashes the NFT was received with
+				Self::record_metadata_hashes(&user_collection, &item, hashes);
+			}
+
+			// Add collection to received collections
+			ReceivedCollections::<T, I>::insert(
+				user_collection.clone(),
+				ReceivedCols {
+					origin_para_id: origin_para.clone(),
+					origin_collection_id: origin_collection_id.clone(),
+					received_collection_id: user_collection.clone(),
+				},
+			);
+
+			// Index received collection by its origin
+			Self::index_received_collection(sender_chain, &origin_collection_id, &user_collection);
+
+			// Emit event about successful cross-chain operation
+			Self::deposit_event(Event::CollectionWithNftsReceived {
+				collection_id: user_collection.clone(),
+				items: nfts.clone(),
+			});
+
+			Ok(().into())
+		}
+
+		/// Receive function for  collection_x_transfer_initiate function.
+		///
+		/// Used when collection has nfts, but they are not owned by the same owner.
+		///
+		/// Shouldn't be used as a regular call.
+		///
+		/// Collection is owned by `beneficiary`.
+		///
+		/// On success emits `CollectionWithNftsDiffOwnersReceived` event.
+		#[pallet::call_index(20)]
+		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
+		pub fn parse_collection_diff_owners(
+			origin: OriginFor<T>,
+			config: Option<CollectionConfigFor<T, I>>,
+			collection_metadata: Vec<u8>,
+			nfts: Vec<(T::ItemId, Location, Vec<u8>)>,
+			metadata_hashes: Vec<MetadataHashes>,
+			origin_para: ParaId,
+			origin_collection_id: T::CollectionId,
+			_dest_collection_id: Option<T::CollectionId>,
+			beneficiary: Location,
+		) -> DispatchResultWithPostInfo {
+			let (sender, sender_chain) = Self::ensure_sender(origin)?;
+
+			// Received metadata has to match hashes computed on origin chain
+			ensure!(metadata_hashes.len() == nfts.len(), Error::<T, I>::MetadataHashMismatch);
+			for ((_, _, data), hashes) in nfts.iter().zip(metadata_hashes.iter()) {
+				Self::verify_metadata_hashes(hashes, Some(collection_metadata.as_slice()), data)?;
+			}
+
+			// Collection is created by the beneficiary chosen on origin chain, other than the
+			// sender only if xcNFT pallet of origin chain is verified to send the call
+			let who =
+				Self::location_to_account(&beneficiary).ok_or(Error::<T, I>::InvalidBeneficiary)?;
+			ensure!(
+				sender_chain.map_or(who == sender, |chain| chain == origin_para),
+				Error::<T, I>::UnverifiedSender
+			);
+			let origin: OriginFor<T> = frame_system::RawOrigin::Signed(who.clone()).into();
+
+			// Collection is created under id returned by the nfts pallet
+			let user_collection = Self::do_create_collection(&who, config)?;
+
+			// Set the collection metadata if present
+			if !collection_metadata.is_empty() {
+				let data = Self::receive_metadata(&user_collection, None, collection_metadata)?;
+				pallet_nfts::Pallet::<T, I>::set_collection_metadata(
+					origin.clone(),
+					user_collection.clone(),
+					data,
+				)?;
+			}
+
+			//Iterate through vector of nfts
+			for (nft, hashes) in nfts.clone().into_iter().zip(metadata_hashes) {
+				let item = nft.0;
+				let nft_owner = T::Lookup::unlookup(
+					Self::location_to_account(&nft.1).ok_or(Error::<T, I>::InvalidBeneficiary)?,
+				);
+				let data = nft.2;
+
+				pallet_nfts::Pallet::<T, I>::mint(
+					origin.clone(),
+					user_collection.clone(),
+					item.clone(),
+					nft_owner.clone(),
+					None,
+				)?;
+
+				if !data.is_empty() {
+					let data = Self::receive_metadata(&user_collection, Some(&item), data)?;
+					pallet_nfts::Pallet::<T, I>::set_metadata(
+						origin.clone(),
+						user_collection.clone(),
+						item.clone(),
+						data,
+					)?;
+				}
+
+				//Check if the NFT was minted if storage contains the item
+				ensure!(
+					pallet_nfts::Item::<T, I>::contains_key(&user_collection, &item),
+					Error::<T, I>::NFTDoesNotExist
+				);
+
+				// Record metadata hashes the NFT was received with
+				Self::record_metadata_hashes(&user_collection, &item, hashes);
+			}
+
+			//Add collection to received collections
+			ReceivedCollections::<T, I>::insert(
+				user_collection.clone(),
+				ReceivedCols {
+					origin_para_id: origin_para.clone(),
+					origin_collection_id: origin_collection_id.clone(),
+					received_collection_id: user_collection.clone(),
+				},
+			);
+
+			// Index received collection by its origin
+			Self::index_received_collection(sender_chain, &origin_collection_id, &user_collection);
+
+			// Report received collection back to origin chain, NFTs of accounts that opted out of
+			// migration may stay there
+			Self::confirm_collection_transfer(
+				origin_para,
+				&sender,
+				origin_collection_id.clone(),
+				user_collection.clone(),
+			);
+
+			//If all went up to this point, emit event about successful cross-chain operation
+			Self::deposit_event(Event::CollectionWithNftsDiffOwnersReceived {
+				collection_id: user_collection.clone(),
+				items: nfts.clone(),
+			});
+
+			Ok(().into())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Returns current weighted tally of the proposal. Weight of Aye and Nay votes is kept as
+		/// running total, updated whenever a vote is cast, withdrawn or delegated.
+		pub fn proposal_tally(proposal: &Proposal<T, I>) -> Tally {
+			Tally {
+				aye: proposal.aye_weight,
+				nay: proposal.nay_weight,
+				total: proposal.total_weight,
+			}
+		}
+
+		/// Adds `added` weight to and subtracts `removed` weight from running weight of `vote`.
+		pub(crate) fn tally_vote(
+			proposal: &mut Proposal<T, I>,
+			vote: &Vote,
+			added: u32,
+			removed: u32,
+		) {
+			let weight = match vote {
+				Vote::Aye => &mut proposal.aye_weight,
+				Vote::Nay => &mut proposal.nay_weight,
+			};
+
+			*weight = weight.saturating_add(added).saturating_sub(removed);
+		}
+
+		/// Returns voting weight of an owner, number of NFTs held when snapshot was taken.
+		pub fn voting_power(proposal: &Proposal<T, I>, who: &T::AccountId) -> u32 {
+			ProposalOwners::<T, I>::get(proposal.proposal_id, who).unwrap_or(0)
+		}
+
+		/// Returns total voting weight owners delegated to the account.
+		pub fn delegated_weight(proposal_id: u64, who: &T::AccountId) -> u32 {
+			ProposalDelegators::<T, I>::get(proposal_id, who).map_or(0, |(_, weight)| weight)
+		}
+
+		/// Adds `weight` of a delegating owner to weight delegated to `delegate`, or removes it if
+		/// not `added`. Vote cast by the delegate carries delegated weight, so it is updated along
+		/// with the running tally.
+		pub(crate) fn update_delegated_weight(
+			proposal: &mut Proposal<T, I>,
+			delegate: &T::AccountId,
+			weight: u32,
+			added: bool,
+		) {
+			let proposal_id = proposal.proposal_id;
+
+			ProposalDelegators::<T, I>::mutate_exists(proposal_id, delegate, |delegators| {
+				let (count, delegated) = delegators.unwrap_or((0, 0));
+
+				*delegators = if added {
+					Some((count.saturating_add(1), delegated.saturating_add(weight)))
+				} else if count > 1 {
+					Some((count - 1, delegated.saturating_sub(weight)))
+				} else {
+					None
+				};
+			});
+
+			if let Some((vote, vote_weight)) = ProposalVotes::<T, I>::get(proposal_id, delegate) {
+				let (plus, minus) = if added { (weight, 0) } else { (0, weight) };
+
+				ProposalVotes::<T, I>::insert(
+					proposal_id,
+					delegate,
+					(vote.clone(), vote_weight.saturating_add(plus).saturating_sub(minus)),
+				);
+				Self::tally_vote(proposal, &vote, plus, minus);
+			}
+		}
+
+		/// Returns vote the account cast directly on the proposal.
+		pub fn direct_vote(proposal: &Proposal<T, I>, who: &T::AccountId) -> Option<Vote> {
+			ProposalVotes::<T, I>::get(proposal.proposal_id, who).map(|(vote, _)| vote)
+		}
+
+		/// Returns vote of the account, either cast directly or through its delegate.
+		pub fn effective_vote(proposal: &Proposal<T, I>, who: &T::AccountId) -> Option<Vote> {
+			match ProposalDelegations::<T, I>::get(proposal.proposal_id, who) {
+				Some(delegate) => Self::direct_vote(proposal, &delegate),
+				None => Self::direct_vote(proposal, who),
+			}
+		}
+
+		/// Returns true if any owner delegated their vote on the proposal to the account.
+		pub fn has_delegators(proposal_id: u64, who: &T::AccountId) -> bool {
+			ProposalDelegators::<T, I>::contains_key(proposal_id, who)
+		}
+
+		/// Returns true if every owner holding NFTs when snapshot was taken voted Aye, directly or
+		/// through their delegate, so that Aye votes carry total weight of the proposal.
+		pub fn all_owners_voted_aye(proposal: &Proposal<T, I>) -> bool {
+			proposal.aye_weight >= proposal.total_weight
+		}
+
+		/// Recounts weights of the proposal from NFTs covered by its snapshot that were not burned
+		/// since. Each NFT counts for vote of the account that owned it when snapshot was taken,
+		/// cast directly or through its delegate.
+		pub(crate) fn recount_votes(proposal: &mut Proposal<T, I>) {
+			let (mut total, mut aye, mut nay) = (0u32, 0u32, 0u32);
+
+			for (item_id, owner) in ProposalItems::<T, I>::iter_prefix(proposal.proposal_id) {
+				if !pallet_nfts::Item::<T, I>::contains_key(&proposal.collection_id, &item_id) {
+					continue;
+				}
+
+				total = total.saturating_add(1);
+
+				match Self::effective_vote(proposal, &owner) {
+					Some(Vote::Aye) => aye = aye.saturating_add(1),
+					Some(Vote::Nay) => nay = nay.saturating_add(1),
+					None => {},
+				}
+			}
+
+			proposal.total_weight = total;
+			proposal.aye_weight = aye;
+			proposal.nay_weight = nay;
+		}
+
+		/// Returns true if the proposal passed according to the voting rule it was created with.
+		pub fn proposal_passed(proposal: &Proposal<T, I>) -> bool {
+			proposal.voting_rule.passed(&Self::proposal_tally(proposal))
+		}
+
+		/// Returns true if location is a 32 or 20 byte account.
+		pub fn is_account_location(location: &Location) -> bool {
+			matches!(
+				location.unpack(),
+				(0, [Junction::AccountId32 { .. }]) | (0, [Junction::AccountKey20 { .. }])
+			)
+		}
+
+		/// Returns location of a local account, used as origin of cross-chain messages and when NFT
+		/// owner did not choose a beneficiary.
+		pub fn account_to_location(who: &T::AccountId) -> Option<Location> {
+			T::AccountToLocation::convert(who.clone())
+		}
+
+		/// Returns local account of a 32 or 20 byte account location.
+		pub fn location_to_account(location: &Location) -> Option<T::AccountId> {
+			T::LocationToAccount::convert(location.clone())
+		}
+
+		/// Returns account that sent received call, along with chain whose xcNFT pallet is
+		/// verified to send it. Calls from sibling chains are only accepted when dispatched from
+		/// `parse_sequenced`, so they are protected against replay.
+		pub(crate) fn ensure_sender(
+			origin: OriginFor<T>,
+		) -> Result<(T::AccountId, Option<ParaId>), DispatchError> {
+			let (who, sender_chain) = Self::verified_sender(origin)?;
+
+			ensure!(
+				sender_chain.is_none() || InSequencedCall::<T, I>::get(),
+				Error::<T, I>::UnsequencedMessage
+			);
+
+			Ok((who, sender_chain))
+		}
+
+		/// Returns account that sent received call, along with chain whose xcNFT pallet is
+		/// verified to send it. Calls descended from xcNFT pallet of a sibling chain, as set in
+		/// `SiblingPallets`, are verified, signed calls are sent by their signer and carry no
+		/// chain.
+		fn verified_sender(
+			origin: OriginFor<T>,
+		) -> Result<(T::AccountId, Option<ParaId>), DispatchError> {
+			let origin = match T::XcmOrigin::try_origin(origin) {
+				Ok(location) => match location.unpack() {
+					(1, [Junction::Parachain(para), Junction::PalletInstance(index), account]) => {
+						let para: ParaId = (*para).into();
+
+						// Other pallets of the sibling chain cannot send calls on behalf of its
+						// accounts
+						ensure!(
+							SiblingPallets::<T, I>::get(para) == Some(*index),
+							Error::<T, I>::InvalidSender
+						);
+
+						let who = Self::location_to_account(&account.clone().into())
+							.ok_or(Error::<T, I>::InvalidSender)?;
+
+						return Ok((who, Some(para)))
+					},
+					_ => return Err(Error::<T, I>::InvalidSender.into()),
+				},
+				Err(origin) => origin,
+			};
+
+			Ok((ensure_signed(origin)?, None))
+		}
+
+		/// Snapshots next page of NFTs of the proposal collection. Owners are recorded with number
+		/// of NFTs they hold as their voting weight and NFTs are locked against transfer, so that
+		/// ownership stays consistent with the snapshot. NFTs that were already locked are left as
+		/// they are. Snapshotted NFTs are recorded along with their owners, NFTs minted after the
+		/// snapshot are not transferred with the collection and votes are recounted from
+		/// snapshotted NFTs when the proposal is initiated.
+		pub(crate) fn snapshot_page(proposal: &mut Proposal<T, I>) -> DispatchResult {
+			let page_size = T::SnapshotPageSize::get();
+			let collection = proposal.collection_id;
+
+			// Continue after the last snapshotted NFT
+			let items: Vec<T::ItemId> = match proposal.snapshot_cursor {
+				Some(last_item) => pallet_nfts::Item::<T, I>::iter_key_prefix_from(
+					collection,
+					pallet_nfts::Item::<T, I>::hashed_key_for(collection, last_item),
+				)
+				.take(page_size as usize)
+				.collect(),
+				None => pallet_nfts::Item::<T, I>::iter_key_prefix(collection)
+					.take(page_size as usize)
+					.collect(),
+			};
+
+			let signed_owner: OriginFor<T> =
+				frame_system::RawOrigin::Signed(proposal.proposed_collection_owner.clone()).into();
+
+			for item_id in items.iter() {
+				if let Some(nft_owner) = pallet_nfts::Pallet::<T, I>::owner(collection, *item_id) {
+					match ProposalOwners::<T, I>::get(proposal.proposal_id, &nft_owner) {
+						Some(weight) => ProposalOwners::<T, I>::insert(
+							proposal.proposal_id,
+							&nft_owner,
+							weight.saturating_add(1),
+						),
+						None => {
+							ProposalOwners::<T, I>::insert(proposal.proposal_id, &nft_owner, 1);
+							VotableProposals::<T, I>::insert(&nft_owner, proposal.proposal_id, ());
+						},
+					}
+
+					proposal.total_weight = proposal.total_weight.saturating_add(1);
+					ProposalItems::<T, I>::insert(proposal.proposal_id, *item_id, &nft_owner);
+				}
+
+				if <pallet_nfts::Pallet<T, I> as NonFungiblesInspect<T::AccountId>>::can_transfer(
+					&collection,
+					item_id,
+				) {
+					pallet_nfts::Pallet::<T, I>::lock_item_transfer(
+						signed_owner.clone(),
+						collection,
+						*item_id,
+					)?;
+					ProposalLockedItems::<T, I>::insert(proposal.proposal_id, *item_id, ());
+				}
+			}
+
+			if let Some(last_item) = items.last() {
+				proposal.snapshot_cursor = Some(*last_item);
+			}
+
+			// Voting period starts once the snapshot is complete
+			if (items.len() as u32) < page_size {
+				proposal.snapshot_complete = true;
+				proposal.end_time = frame_system::Pallet::<T>::block_number() +
+					T::ProposalTimeInBlocks::get().into();
+			}
+
+			Ok(())
+		}
+
+		/// Returns the account of the pallet, that owns derivative collections.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Returns whether collection owner allowed receiving NFTs from origin parachain or origin
+		/// collection.
+		pub fn is_incoming_allowed(
+			collection: &T::CollectionId,
+			origin_para: &ParaId,
+			origin_collection: &T::CollectionId,
+		) -> bool {
+			IncomingAllowlist::<T, I>::contains_key(
+				collection,
+				(origin_para.clone(), None::<T::CollectionId>),
+			) || IncomingAllowlist::<T, I>::contains_key(
+				collection,
+				(origin_para.clone(), Some(origin_collection.clone())),
+			)
+		}
+
+		/// Creates collection owned by `owner` with `config`, or with all settings enabled if no
+		/// config is provided. `owner` has to pass `CreateOrigin` of the nfts pallet for the id the
+		/// collection is created under. Returns id of the created collection.
+		pub(crate) fn do_create_collection(
+			owner: &T::AccountId,
+			config: Option<CollectionConfigFor<T, I>>,
+		) -> Result<T::CollectionId, DispatchError> {
+			// Backend call does not check origin, so it is checked the same way `create` does
+			let collection = pallet_nfts::NextCollectionId::<T, I>::get()
+				.or(T::CollectionId::initial_value())
+				.ok_or(Error::<T, I>::NoNextCollectionId)?;
+			<T as pallet_nfts::Config<I>>::CreateOrigin::ensure_origin(
+				frame_system::RawOrigin::Signed(owner.clone()).into(),
+				&collection,
+			)?;
+
+			let config = config.unwrap_or_else(|| CollectionConfigFor::<T, I> {
+				settings: CollectionSettings::all_enabled(),
+				max_supply: None,
+				mint_settings: MintSettings::default(),
+			});
+
+			<pallet_nfts::Pallet<T, I> as NonFungiblesCreate<
+				T::AccountId,
+				CollectionConfigFor<T, I>,
+			>>::create_collection(owner, owner, &config)
+		}
+
+		/// Returns derivative collection of origin collection, the collection is created and owned
+		/// by the pallet account if it does not exist yet.
+		pub(crate) fn derivative_collection(
+			origin_para: ParaId,
+			origin_collection: T::CollectionId,
+		) -> Result<T::CollectionId, DispatchError> {
+			if let Some(collection) =
+				DerivativeCollections::<T, I>::get(origin_para.clone(), origin_collection.clone())
+			{
+				if pallet_nfts::Collection::<T, I>::contains_key(&collection) {
+					return Ok(collection);
+				}
+			}
+
+			let collection = Self::do_create_collection(&Self::account_id(), None)?;
+
+			DerivativeCollections::<T, I>::insert(
+				origin_para.clone(),
+				origin_collection.clone(),
+				collection.clone(),
+			);
+
+			Self::deposit_event(Event::DerivativeCollectionCreated {
+				collection_id: collection.clone(),
+				origin_para_id: origin_para,
+				origin_collection_id: origin_collection,
+			});
+
+			Ok(collection)
+		}
+
+		/// Returns asset_id for NFT received without requested asset_id. Origin asset_id is kept
+		/// if it is free in the collection, otherwise next free asset_id is allocated.
+		pub(crate) fn allocate_item_id(
+			collection: &T::CollectionId,
+			origin_item: &T::ItemId,
+		) -> Result<T::ItemId, DispatchError> {
+			if !pallet_nfts::Item::<T, I>::contains_key(collection, origin_item) {
+				return Ok(origin_item.clone());
+			}
+
+			let mut index = NextReceivedItem::<T, I>::get(collection).unwrap_or(0);
+
+			loop {
+				let item = T::ItemIdConverter::convert(index);
+
+				if !pallet_nfts::Item::<T, I>::contains_key(collection, &item) {
+					NextReceivedItem::<T, I>::insert(collection, index.saturating_add(1));
+					return Ok(item);
+				}
+
+				index = index.checked_add(1).ok_or(Error::<T, I>::NoNextItemId)?;
+			}
+		}
+
+		/// Settles NFT sent to destination chain, NFT received from another chain is burned and
+		/// NFT native to this chain only has its metadata cleared.
+		pub(crate) fn complete_nft_transfer(
+			message_hash: XcmHash,
+			owner: &T::AccountId,
+			origin_collection: T::CollectionId,
+			origin_asset: T::ItemId,
+			destination_para: ParaId,
+			destination_collection: T::CollectionId,
+			destination_asset: Option<T::ItemId>,
+		) {
+			// NFT is restored if it fails to be received on destination chain
+			let data = Self::sent_metadata(&origin_collection, Some(&origin_asset));
+			OverflowMetadata::<T, I>::remove(&origin_collection, Some(origin_asset.clone()));
+			ReceivedMetadataHashes::<T, I>::remove(&(
+				origin_collection.clone(),
+				origin_asset.clone(),
+			));
+			PendingTransfers::<T, I>::insert(
+				message_hash,
+				PendingTransfer::NFT {
+					owner: owner.clone(),
+					collection: origin_collection.clone(),
+					item: origin_asset.clone(),
+					data,
+				},
+			);
+
+			// Destination chain confirms asset the NFT was received into
+			PendingConfirmations::<T, I>::insert(
+				(origin_collection.clone(), origin_asset.clone()),
+				destination_para,
+			);
+
+			// If in received list, burn asset and remove from received list
+			if ReceivedAssets::<T, I>::contains_key(&(
+				origin_collection.clone(),
+				origin_asset.clone(),
+			)) {
+				let received =
+					ReceivedAssets::<T, I>::get(&(origin_collection.clone(), origin_asset.clone()))
+						.unwrap();
+
+				SentAssets::<T, I>::insert(
+					(origin_collection.clone(), origin_asset.clone()),
+					SentStruct {
+						origin_para_id: received.origin_para_id,
+						origin_collection_id: received.origin_collection_id,
+						origin_asset_id: received.origin_asset_id,
+						destination_collection_id: destination_collection.clone(),
+						// Updated once destination chain confirms received asset
+						destination_asset_id: destination_asset.unwrap_or(origin_asset.clone()),
+					},
+				);
+
+				// Remove from received assets
+				ReceivedAssets::<T, I>::remove(&(origin_collection.clone(), origin_asset.clone()));
+
+				// Burn the asset
+				let _ = pallet_nfts::Pallet::<T, I>::burn(
+					frame_system::RawOrigin::Signed(owner.clone()).into(),
+					origin_collection.clone(),
+					origin_asset.clone(),
+				);
+			}
+			//Only remove asset metadata, because we are sending from origin chain
+			else {
+				let col_owner =
+					pallet_nfts::Pallet::<T, I>::collection_owner(origin_collection.clone())
+						.unwrap();
+				let signed_col: OriginFor<T> =
+					frame_system::RawOrigin::Signed(col_owner.clone()).into();
+
+				let _ = pallet_nfts::Pallet::<T, I>::clear_metadata(
+					signed_col.clone(),
+					origin_collection.clone(),
+					origin_asset.clone(),
+				);
+
+				SentAssets::<T, I>::insert(
+					(origin_collection.clone(), origin_asset.clone()),
+					SentStruct {
+						origin_para_id: parachain_info::Pallet::<T>::parachain_id(),
+						origin_collection_id: origin_collection.clone(),
+						origin_asset_id: origin_asset.clone(),
+						destination_collection_id: destination_collection.clone(),
+						// Updated once destination chain confirms received asset
+						destination_asset_id: destination_asset.unwrap_or(origin_asset.clone()),
+					},
+				);
+			}
+			//Emit a success event
+			Self::deposit_event(Event::NFTTransferred {
+				message_hash,
+				origin_collection_id: origin_collection.clone(),
+				origin_asset_id: origin_asset.clone(),
+				destination_para_id: destination_para,
+				destination_collection_id: destination_collection.clone(),
+				destination_asset_id: destination_asset.clone(),
+			});
+		}
+
+		/// Reports collection and asset that NFT was received into back to origin chain, so it can
+		/// update its sent assets.
+		pub(crate) fn confirm_nft_transfer(
+			origin_chain: ParaId,
+			who: &T::AccountId,
+			origin_collection: T::CollectionId,
+			origin_item: T::ItemId,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) {
+			let Some(account) = Self::account_to_location(who) else { return };
+
+			let call = Call::<T, I>::parse_nft_transfer_confirmation {
+				collection: origin_collection.clone(),
+				item: origin_item,
+				destination_collection: collection,
+				destination_item: item,
+			};
+
+			Self::send_confirmation(origin_chain, who, account, origin_collection, call);
+		}
+
+		/// Reports collection that collection with NFTs of different owners was received into back
+		/// to origin chain, so it can link its residual collection with it.
+		pub(crate) fn confirm_collection_transfer(
+			origin_chain: ParaId,
+			who: &T::AccountId,
+			origin_collection: T::CollectionId,
+			collection: T::CollectionId,
+		) {
+			let Some(account) = Self::account_to_location(who) else { return };
+
+			let call = Call::<T, I>::parse_collection_transfer_confirmation {
+				collection: origin_collection.clone(),
+				destination_collection: collection,
+			};
+
+			Self::send_confirmation(origin_chain, who, account, origin_collection, call);
+		}
+
+		/// Sends confirmation `call` of received `origin_collection` back to origin chain.
+		fn send_confirmation(
+			origin_chain: ParaId,
+			who: &T::AccountId,
+			account: Location,
+			origin_collection: T::CollectionId,
+			call: Call<T, I>,
+		) {
+			if let Err((message_hash, e, call)) = Self::send_transact(
+				origin_chain,
+				account.clone(),
+				OriginKind::SovereignAccount,
+				call,
+				None,
+			) {
+				// Transient failures are retried from `on_idle`
+				if !Self::queue_message(
+					message_hash,
+					&e,
+					origin_chain,
+					account,
+					OriginKind::SovereignAccount,
+					call,
+					None,
+				) {
+					Self::deposit_event(Event::CollectionFailedToXCM {
+						e,
+						message_hash,
+						collection_id: origin_collection,
+						owner: who.clone(),
+						destination: origin_chain,
+					});
+				}
+			}
+		}
+
+		/// Sends call to destination chain and records the message in outbound messages. Returns id
+		/// of the message along with the result, so it can be included in events either way. Call
+		/// that failed to send is returned as sent, so it keeps its nonce when it is queued.
+		pub(crate) fn send_transact(
+			destination: ParaId,
+			who_location: Location,
+			origin_kind: OriginKind,
+			call: Call<T, I>,
+			deposit: Option<(Asset, Location)>,
+		) -> Result<(XcmHash, Assets), (XcmHash, SendError, Call<T, I>)> {
+			let index = OutboundMessageCount::<T, I>::get();
+			OutboundMessageCount::<T, I>::put(index.wrapping_add(1));
+
+			let message_hash = Self::message_id(index);
+
+			// Every message carries nonce of its sender, so destination rejects duplicates, and
+			// its index, so destination applies prompts in order
+			let nonce = OutboundNonces::<T, I>::get(&who_location);
+			OutboundNonces::<T, I>::insert(&who_location, nonce.wrapping_add(1));
+			let call = Self::sequenced(nonce, index, call);
+
+			Self::send_message(
+				message_hash,
+				destination,
+				who_location,
+				origin_kind,
+				call.clone(),
+				deposit,
+			)
+			.map(|price| (message_hash, price))
+			.map_err(|e| (message_hash, e, call))
+		}
+
+		/// Sends call as message with id `message_hash` and records it in outbound messages. Queued
+		/// messages are retried under the id of their first attempt.
+		fn send_message(
+			message_hash: XcmHash,
+			destination: ParaId,
+			who_location: Location,
+			origin_kind: OriginKind,
+			call: Call<T, I>,
+			deposit: Option<(Asset, Location)>,
+		) -> Result<Assets, SendError> {
+			let operation = Self::operation_of(&call);
+
+			let (query_id, price) = Self::deliver_transact(
+				destination,
+				who_location.clone(),
+				origin_kind,
+				call,
+				deposit,
+				message_hash,
+			)?;
+
+			PendingQueries::<T, I>::insert(query_id, message_hash);
+			OutboundMessages::<T, I>::insert(
+				message_hash,
+				OutboundMessage {
+					operation,
+					sender: who_location,
+					destination_para_id: destination,
+					query_id,
+					status: MessageStatus::Sent,
+				},
+			);
+
+			Ok(price)
+		}
+
+		/// Queues call that failed to send for a transient reason to be retried from `on_idle`, if
+		/// its operation is safe to retry and the queue is not full. Call is queued as it was sent,
+		/// so its retries carry the nonce of the failed attempt. NFT carried by the call is locked
+		/// in pallet account until the call is sent or cancelled. Returns whether the call was
+		/// queued.
+		pub(crate) fn queue_message(
+			message_hash: XcmHash,
+			error: &SendError,
+			destination: ParaId,
+			sender: Location,
+			origin_kind: OriginKind,
+			call: Call<T, I>,
+			deposit: Option<(Asset, Location)>,
+		) -> bool {
+			if !Self::is_transient(error) ||
+				!Self::operation_of(&call).is_retry_safe() ||
+				T::MaxSendAttempts::get() <= 1 ||
+				OutboundQueueLen::<T, I>::get() >= T::MaxQueuedMessages::get()
+			{
+				return false;
+			}
+
+			let Some(owner) = Self::location_to_account(&sender) else { return false };
+
+			let sent_call = match &call {
+				Call::parse_sequenced { call, .. } => call.as_ref(),
+				call => call,
+			};
+
+			let locked_nft = match sent_call {
+				Call::parse_nft_transfer { origin_collection, origin_item, .. } => {
+					let signed_owner: OriginFor<T> =
+						frame_system::RawOrigin::Signed(owner.clone()).into();
+
+					if pallet_nfts::Pallet::<T, I>::transfer(
+						signed_owner,
+						origin_collection.clone(),
+						origin_item.clone(),
+						T::Lookup::unlookup(Self::account_id()),
+					)
+					.is_err()
+					{
+						return false;
+					}
+
+					Some((origin_collection.clone(), origin_item.clone()))
+				},
+				_ => None,
+			};
+
+			let next_attempt = frame_system::Pallet::<T>::block_number() + Self::retry_delay(1);
+
+			OutboundQueue::<T, I>::insert(
+				message_hash,
+				QueuedMessage {
+					owner: owner.clone(),
+					destination_para_id: destination,
+					sender,
+					origin_kind,
+					call,
+					deposit,
+					attempts: 1,
+					next_attempt,
+					locked_nft,
+				},
+			);
+			OutboundQueueLen::<T, I>::mutate(|len| *len = len.saturating_add(1));
+
+			Self::deposit_event(Event::MessageQueued {
+				message_hash,
+				owner,
+				e: error.clone(),
+				next_attempt,
+			});
+
+			true
+		}
+
+		/// Retries queued messages whose next attempt is due. Message is dropped once it failed to
+		/// send `MaxSendAttempts` times or for a reason that is not transient. Returns consumed
+		/// weight.
+		pub(crate) fn retry_queued_messages(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+			// Whole queue is scanned for due messages
+			let scan = T::DbWeight::get().reads(OutboundQueueLen::<T, I>::get().into());
+			if !scan.all_lte(limit) {
+				return Weight::zero();
+			}
+
+			let per_message = T::DbWeight::get().reads_writes(6, 6);
+			let max_messages = limit
+				.saturating_sub(scan)
+				.ref_time()
+				.checked_div(per_message.ref_time())
+				.unwrap_or(u64::MAX);
+
+			let due: Vec<(XcmHash, QueuedMessage<T, I>)> = OutboundQueue::<T, I>::iter()
+				.filter(|(_, queued)| queued.next_attempt <= now)
+				.take(usize::try_from(max_messages).unwrap_or(usize::MAX))
+				.collect();
+			let consumed = scan.saturating_add(per_message.saturating_mul(due.len() as u64));
+
+			for (message_hash, mut queued) in due {
+				match Self::send_message(
+					message_hash,
+					queued.destination_para_id,
+					queued.sender.clone(),
+					queued.origin_kind,
+					queued.call.clone(),
+					queued.deposit.clone(),
+				) {
+					Ok(_) => {
+						Self::remove_queued_message(message_hash, &queued);
+
+						let call = match &queued.call {
+							Call::parse_sequenced { call, .. } => call.as_ref(),
+							call => call,
+						};
+
+						// Sent NFT is settled the same way as when it is sent on first attempt
+						if let Call::parse_nft_transfer {
+							origin_collection,
+							origin_item,
+							collection,
+							item,
+							..
+						} = call
+						{
+							Self::complete_nft_transfer(
+								message_hash,
+								&queued.owner,
+								origin_collection.clone(),
+								origin_item.clone(),
+								queued.destination_para_id,
+								collection.clone(),
+								item.clone(),
+							);
+						}
+
+						Self::deposit_event(Event::QueuedMessageSent {
+							message_hash,
+							attempts: queued.attempts.saturating_add(1),
+						});
+					},
+					Err(e) => {
+						queued.attempts = queued.attempts.saturating_add(1);
+
+						if !Self::is_transient(&e) || queued.attempts >= T::MaxSendAttempts::get() {
+							Self::remove_queued_message(message_hash, &queued);
+							Self::deposit_event(Event::QueuedMessageDropped { message_hash, e });
+						} else {
+							queued.next_attempt = now + Self::retry_delay(queued.attempts);
+							OutboundQueue::<T, I>::insert(message_hash, queued);
+						}
+					},
+				}
 			}
 
-			Ok(().into())
+			consumed
 		}
 
-		/// Receive function for nft_x_burn function.
-		///
-		/// Doesn't differ from nfts pallet burn function.
-		///
-		/// On success emits regular burn function events.
-		#[pallet::call_index(15)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_nft_burn(
-			origin: OriginFor<T>,
-			collection: T::CollectionId,
-			item: T::ItemId,
-		) -> DispatchResultWithPostInfo {
-			let signed_origin = ensure_signed(origin.clone())?;
-			let signed_origin_lookup = T::Lookup::unlookup(signed_origin.clone());
+		/// Removes message from the queue and returns NFT locked by the message to its owner.
+		pub(crate) fn remove_queued_message(message_hash: XcmHash, queued: &QueuedMessage<T, I>) {
+			OutboundQueue::<T, I>::remove(message_hash);
+			OutboundQueueLen::<T, I>::mutate(|len| *len = len.saturating_sub(1));
 
-			match pallet_nfts::Pallet::<T, I>::burn(
-				origin.clone(),
-				collection.clone(),
-				item.clone(),
-			) {
-				Ok(_) => {},
-				Err(e) => {
-					// Deposit event indicating failure to burn NFT
-					Self::deposit_event(Event::NFTBurnFailed {
-						collection_id: collection.clone(),
-						asset_id: item.clone(),
-						owner: signed_origin_lookup.clone(),
-						error: e,
-					});
-				},
+			if let Some((collection, item)) = &queued.locked_nft {
+				let signed_pallet: OriginFor<T> =
+					frame_system::RawOrigin::Signed(Self::account_id()).into();
+
+				let _ = pallet_nfts::Pallet::<T, I>::transfer(
+					signed_pallet,
+					collection.clone(),
+					item.clone(),
+					T::Lookup::unlookup(queued.owner.clone()),
+				);
 			}
+		}
 
-			Ok(().into())
+		/// Returns whether sending may succeed when attempted again. Only transport errors, such as
+		/// full channel to destination chain, are transient, destination without route is not.
+		fn is_transient(error: &SendError) -> bool {
+			matches!(error, SendError::Transport(_))
 		}
 
-		/// Receive function for  nft_x_update function.
-		///
-		/// Doesn't differ from nfts pallet setMetadata function.
-		///
-		/// On success emits regular setMetadata function events.
-		#[pallet::call_index(16)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_nft_metadata(
-			origin: OriginFor<T>,
-			collection: T::CollectionId,
-			item: T::ItemId,
-			data: BoundedVec<u8, T::StringLimit>,
-		) -> DispatchResultWithPostInfo {
-			let signed_origin = ensure_signed(origin.clone())?;
-			let signed_origin_lookup = T::Lookup::unlookup(signed_origin.clone());
+		/// Returns delay before next attempt to send queued message that failed `attempts` times.
+		fn retry_delay(attempts: u32) -> BlockNumberFor<T> {
+			let backoff = 2u32.saturating_pow(attempts.saturating_sub(1));
 
-			match pallet_nfts::Pallet::<T, I>::set_metadata(
-				origin.clone(),
-				collection.clone(),
-				item.clone(),
-				data.clone(),
-			) {
-				Ok(_) => {},
-				Err(e) => {
-					// Deposit event indicating failure to set metadata
-					Self::deposit_event(Event::NFTMetadataSetFailed {
-						collection_id: collection.clone(),
-						asset_id: item.clone(),
-						owner: signed_origin_lookup.clone(),
-						error: e,
-					});
-				},
+			T::RetryBackoffInBlocks::get().saturating_mul(backoff).into()
+		}
+
+		/// Wraps `call` in `parse_sequenced` carrying `nonce` of the sender on this chain and
+		/// `message_index` of the message.
+		fn sequenced(nonce: u64, message_index: u64, call: Call<T, I>) -> Call<T, I> {
+			Call::<T, I>::parse_sequenced {
+				origin_chain: parachain_info::Pallet::<T>::parachain_id(),
+				nonce,
+				message_index,
+				call: Box::new(call),
 			}
+		}
 
-			Ok(().into())
+		/// Returns collection targeted by `call`, if it is a metadata, owner or burn prompt.
+		fn prompt_collection(call: &Call<T, I>) -> Option<T::CollectionId> {
+			match call {
+				Call::parse_collection_metadata { collection, .. } |
+				Call::parse_nft_metadata { collection, .. } |
+				Call::parse_collection_owner { collection, .. } |
+				Call::parse_nft_owner { collection, .. } |
+				Call::parse_nft_burn { collection, .. } => Some(collection.clone()),
+				Call::parse_collection_burn { collection_to_burn, .. } =>
+					Some(collection_to_burn.clone()),
+				_ => None,
+			}
 		}
 
-		/// Receive function for  nft_x_change_owner function.
-		///
-		/// Doesn't differ from nfts pallet transfer function.
-		///
-		/// On success emits regular transfer function events.
-		#[pallet::call_index(17)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_nft_owner(
-			origin: OriginFor<T>,
-			new_owner: AccountIdLookupOf<T>,
-			collection: T::CollectionId,
-			item: T::ItemId,
-		) -> DispatchResultWithPostInfo {
-			let signed_origin = ensure_signed(origin.clone())?;
-			let signed_origin_lookup = T::Lookup::unlookup(signed_origin.clone());
+		/// Delivers call to destination chain, where it is dispatched on behalf of account at
+		/// `who_location`. Account is aliased or origin is descended into it within this pallet,
+		/// according to origin mode of destination chain. If destination has fee asset, execution
+		/// is bought with it, the sender is charged for it and surplus is deposited back to the
+		/// sender on destination chain. Destination reports dispatch result of the call to query,
+		/// whose id is returned. Report is matched against the origin the call is dispatched with
+		/// on destination chain.
+		fn deliver_transact(
+			destination: ParaId,
+			who_location: Location,
+			origin_kind: OriginKind,
+			call: Call<T, I>,
+			deposit: Option<(Asset, Location)>,
+			message_hash: XcmHash,
+		) -> Result<(QueryId, Assets), SendError> {
+			let dest: Location = (Parent, Junction::Parachain(destination.into())).into();
+
+			// Status report comes from the origin the call is dispatched with, as seen from this
+			// chain
+			let origin_mode = DestinationOriginModes::<T, I>::get(destination);
+			let (origin_instruction, origin_kind, sender, querier) = match origin_mode {
+				OriginMode::Alias => (
+					AliasOrigin(who_location.clone()),
+					origin_kind,
+					who_location.clone(),
+					dest.clone()
+						.appended_with(who_location.clone())
+						.map_err(|_| SendError::NotApplicable)?,
+				),
+				OriginMode::Descend => {
+					let account =
+						who_location.first_interior().cloned().ok_or(SendError::NotApplicable)?;
+					let sender = Location::new(
+						1,
+						[
+							Junction::Parachain(parachain_info::Pallet::<T>::parachain_id().into()),
+							account.clone(),
+						],
+					);
 
-			match pallet_nfts::Pallet::<T, I>::transfer(
-				origin.clone(),
-				collection.clone(),
-				item.clone(),
-				new_owner.clone(),
-			) {
-				Ok(_) => {},
-				Err(e) => {
-					// Deposit event indicating failure to transfer ownership
-					Self::deposit_event(Event::NFTOwnershipTransferFailed {
-						collection_id: collection.clone(),
-						asset_id: item.clone(),
-						owner: signed_origin_lookup.clone(),
-						error: e,
-					});
+					// Destination sees the sender within this pallet, so the call is verified
+					// to be sent by xcNFT pallet of this chain
+					let pallet = <Pallet<T, I> as frame_support::traits::PalletInfoAccess>::index();
+					let interior: Junctions =
+						[Junction::PalletInstance(pallet as u8), account].into();
+
+					(
+						DescendOrigin(interior.clone()),
+						OriginKind::Xcm,
+						sender,
+						Location::new(0, interior),
+					)
 				},
+			};
+
+			let transact_weight = Self::transact_weight(&call);
+			let transact = Transact {
+				origin_kind,
+				call: <T as Config<I>>::RuntimeCall::from(call).encode().into(),
+			};
+
+			let fee = DestinationFees::<T, I>::get(destination);
+			let reanchor = |asset: &Asset| {
+				asset
+					.clone()
+					.reanchored(&dest, &T::UniversalLocation::get())
+					.map_err(|_| SendError::NotApplicable)
+			};
+
+			// Assets charged from sender on this chain and withdrawn on destination chain
+			let mut charged = Assets::new();
+			let mut withdrawn = Assets::new();
+			for asset in fee.iter().chain(deposit.as_ref().map(|(asset, _)| asset)) {
+				charged.push(asset.clone());
+				withdrawn.push(reanchor(asset)?);
 			}
 
-			Ok(().into())
-		}
+			let mut instructions = Vec::new();
 
-		/// Receive function for  nft_x_transfer function.
-		///
-		/// Shouldn't be used as a regular call.
-		///
-		/// On success emits `NFTReceived` or `NFTReturnedToOrigin` events.
-		#[pallet::call_index(18)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_nft_transfer(
-			origin: OriginFor<T>,
-			collection: T::CollectionId,
-			item: T::ItemId,
-			data: BoundedVec<u8, T::StringLimit>,
-			origin_collection: T::CollectionId,
-			origin_item: T::ItemId,
-			origin_chain: ParaId,
-		) -> DispatchResultWithPostInfo {
-			let signed_origin = ensure_signed(origin.clone())?;
-			let signed_origin_lookup = T::Lookup::unlookup(signed_origin.clone());
+			// Storage deposits are funded before the call is dispatched
+			if let Some((asset, beneficiary)) = &deposit {
+				instructions.push(DepositAsset {
+					assets: Definite(reanchor(asset)?.into()),
+					beneficiary: beneficiary.clone(),
+				});
+			}
 
-			// Check if the collection exists
-			ensure!(
-				pallet_nfts::Collection::<T, I>::contains_key(&collection),
-				Error::<T, I>::CollectionDoesNotExist
-			);
+			instructions.push(origin_instruction);
+			instructions.push(transact);
+
+			// Destination reports dispatch result of the call back to this chain
+			let timeout =
+				frame_system::Pallet::<T>::block_number() + T::MessageTimeoutInBlocks::get().into();
+			let query_id = T::QueryHandler::new_query(dest.clone(), timeout, querier);
+			let reporter = Location::here()
+				.reanchored(&dest, &T::UniversalLocation::get())
+				.map_err(|_| SendError::NotApplicable)?;
+			instructions.push(ReportTransactStatus(QueryResponseInfo {
+				destination: reporter,
+				query_id,
+				max_weight: Weight::zero(),
+			}));
+
+			if fee.is_some() {
+				instructions.push(RefundSurplus);
+				instructions.push(DepositAsset {
+					assets: Wild(AllCounted(withdrawn.len() as u32)),
+					beneficiary: sender,
+				});
+			}
 
-			// Check if not in receiving assets
-			ensure!(
-				!ReceivedAssets::<T, I>::contains_key(&(collection.clone(), item.clone())),
-				Error::<T, I>::NFTAlreadyReceived
+			// Message id is set as topic, so routers and destination chain refer to it
+			instructions.push(SetTopic(message_hash));
+
+			// Execution is bought or requested for the estimated call weight and every instruction
+			let head_len = if fee.is_some() || !withdrawn.is_empty() { 2 } else { 1 };
+			let weight_limit = Limited(
+				transact_weight.saturating_add(
+					T::XcmInstructionWeight::get()
+						.saturating_mul((instructions.len() + head_len) as u64),
+				),
 			);
 
-			if SentAssets::<T, I>::contains_key(&(collection.clone(), item.clone())) {
-				// User returns nft to origin collection, use collection owner to add metadata
-				let col_owner =
-					pallet_nfts::Pallet::<T, I>::collection_owner(collection.clone()).unwrap();
-				let signed_col: OriginFor<T> =
-					frame_system::RawOrigin::Signed(col_owner.clone()).into();
-				let sent_asset =
-					SentAssets::<T, I>::get(&(collection.clone(), item.clone())).unwrap();
+			let mut message = match &fee {
+				Some(fee) => vec![
+					WithdrawAsset(withdrawn.clone()),
+					BuyExecution { fees: reanchor(fee)?, weight_limit },
+				],
+				None => {
+					let mut message = vec![UnpaidExecution { weight_limit, check_origin: None }];
+					if !withdrawn.is_empty() {
+						message.push(WithdrawAsset(withdrawn.clone()));
+					}
+					message
+				},
+			};
+			message.extend(instructions);
 
-				if sent_asset.origin_para_id == parachain_info::Pallet::<T>::parachain_id() {
-					// We know we are the origin chain, we can add only metadata
-					if !data.is_empty() {
-						match pallet_nfts::Pallet::<T, I>::set_metadata(
-							signed_col.clone(),
-							collection.clone(),
-							item.clone(),
-							data.clone(),
-						) {
-							Ok(_) => {},
-							Err(e) => {
-								// Deposit event indicating failure to set metadata
-								Self::deposit_event(Event::NFTMetadataSetFailed {
-									collection_id: collection.clone(),
-									asset_id: item.clone(),
-									owner: signed_origin_lookup.clone(),
-									error: e,
+			let (ticket, price) = validate_send::<T::XcmSender>(dest, Xcm(message))?;
+
+			// Sender pays for execution bought and deposits funded on destination chain, along
+			// with delivery of the message
+			for asset in price.inner() {
+				charged.push(asset.clone());
+			}
+
+			// Sender is charged only if the message is delivered, so failed attempts can be
+			// retried without charging again
+			with_transaction_opaque_err(|| {
+				let result = (|| {
+					if !charged.is_empty() {
+						T::XcmExecutor::charge_fees(who_location, charged)
+							.map_err(|_| SendError::Fees)?;
+					}
+
+					T::XcmSender::deliver(ticket)
+				})();
+
+				match result {
+					Ok(_) => TransactionOutcome::Commit(Ok(())),
+					Err(e) => TransactionOutcome::Rollback(Err(e)),
+				}
+			})
+			.map_err(|_| SendError::Transport("transactional layer limit reached"))??;
+
+			Ok((query_id, price))
+		}
+
+		/// Returns id of `index`-th message sent by this pallet instance.
+		pub(crate) fn message_id(index: u64) -> XcmHash {
+			let pallet_index = <Pallet<T, I> as frame_support::traits::PalletInfoAccess>::index();
+
+			<BlakeTwo256 as Hash>::hash_of(&(
+				b"xcnft",
+				parachain_info::Pallet::<T>::parachain_id(),
+				pallet_index as u32,
+				index,
+			))
+			.into()
+		}
+
+		/// Returns operation carried by `call`.
+		fn operation_of(call: &Call<T, I>) -> Operation {
+			match call {
+				Call::parse_nft_transfer { .. } => Operation::NFTTransfer,
+				Call::parse_collection_metadata { .. } => Operation::CollectionMetadata,
+				Call::parse_nft_metadata { .. } => Operation::NFTMetadata,
+				Call::parse_collection_burn { .. } => Operation::CollectionBurn,
+				Call::parse_nft_burn { .. } => Operation::NFTBurn,
+				Call::parse_collection_owner { .. } => Operation::CollectionOwnership,
+				Call::parse_nft_owner { .. } => Operation::NFTOwnership,
+				Call::parse_nft_transfer_confirmation { .. } |
+				Call::parse_collection_transfer_confirmation { .. } => Operation::TransferConfirmation,
+				Call::parse_sequenced { call: inner, .. } => Self::operation_of(inner),
+				_ => Operation::CollectionTransfer,
+			}
+		}
+
+		/// Updates status of sent messages from status reports of destination chains, messages
+		/// without report are timed out once their query expires. Assets of timed out transfers
+		/// are kept and their queries are checked until late status report comes. Queries are
+		/// checked in turns, each block continues after the last query checked in previous block.
+		/// Returns consumed weight.
+		pub(crate) fn update_outbound_messages(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+			let per_query = T::DbWeight::get().reads_writes(6, 7);
+			let max_queries =
+				limit.ref_time().checked_div(per_query.ref_time()).unwrap_or(u64::MAX);
+
+			let max_queries = usize::try_from(max_queries).unwrap_or(usize::MAX);
+			let queries: Vec<(QueryId, XcmHash)> = match PendingQueryCursor::<T, I>::get() {
+				Some(last_query) => PendingQueries::<T, I>::iter_from(
+					PendingQueries::<T, I>::hashed_key_for(last_query),
+				)
+				.take(max_queries)
+				.collect(),
+				None => PendingQueries::<T, I>::iter().take(max_queries).collect(),
+			};
+
+			// Checking starts over from the first query once the last one was checked
+			if queries.len() < max_queries {
+				PendingQueryCursor::<T, I>::kill();
+			} else if let Some((last_query, _)) = queries.last() {
+				PendingQueryCursor::<T, I>::put(last_query);
+			}
+
+			let mut consumed = per_query.saturating_mul(queries.len() as u64);
+
+			for (query_id, message_hash) in queries {
+				// Failed transfer is restored along with its status update, so both have to fit the
+				// limit
+				let transfer = PendingTransfers::<T, I>::get(message_hash);
+				let restore_weight = transfer.as_ref().map_or(Weight::zero(), Self::restore_weight);
+				if consumed.saturating_add(restore_weight).any_gt(limit) {
+					continue;
+				}
+
+				let status = match T::QueryHandler::take_response(query_id) {
+					QueryResponseStatus::Ready {
+						response: Response::DispatchResult(MaybeErrorCode::Success),
+						..
+					} => MessageStatus::Executed,
+					QueryResponseStatus::Ready {
+						response: Response::DispatchResult(error),
+						..
+					} => MessageStatus::Failed(error),
+					QueryResponseStatus::Pending { timeout } if timeout > now => continue,
+					// Transfer that timed out may still have been executed, so its assets are
+					// kept until status report proves whether it was
+					QueryResponseStatus::Pending { .. } if transfer.is_some() => {
+						if let Some(mut message) = OutboundMessages::<T, I>::get(message_hash)
+							.filter(|message| message.status == MessageStatus::Sent)
+						{
+							message.status = MessageStatus::TimedOut;
+							OutboundMessages::<T, I>::insert(message_hash, message);
+
+							Self::deposit_event(Event::OutboundMessageStatusUpdated {
+								message_hash,
+								status: MessageStatus::TimedOut,
+							});
+						}
+
+						continue
+					},
+					_ => MessageStatus::TimedOut,
+				};
+
+				PendingQueries::<T, I>::remove(query_id);
+				OutboundMessages::<T, I>::mutate(message_hash, |message| {
+					if let Some(message) = message {
+						message.status = status.clone();
+					}
+				});
+
+				Self::deposit_event(Event::OutboundMessageStatusUpdated {
+					message_hash,
+					status: status.clone(),
+				});
+
+				if let Some(transfer) = transfer {
+					// Assets of transfer without status report are kept, as it may still have
+					// been executed
+					if status == MessageStatus::TimedOut {
+						continue;
+					}
+
+					PendingTransfers::<T, I>::remove(message_hash);
+
+					// Assets of transfer that failed on destination chain are returned to owners
+					if let MessageStatus::Failed(error) = status {
+						consumed = consumed.saturating_add(restore_weight);
+
+						match frame_support::storage::with_storage_layer(|| {
+							Self::restore_transfer(transfer.clone())
+						}) {
+							Ok(collection_id) => Self::deposit_event(Event::TransferRestored {
+								message_hash,
+								collection_id,
+								error,
+							}),
+							Err(error) => {
+								// Owner restores the assets once the cause is removed
+								FailedTransfers::<T, I>::insert(message_hash, transfer);
+								Self::deposit_event(Event::TransferRestoreFailed {
+									message_hash,
+									error,
 								});
 							},
 						}
 					}
+				}
 
-					// We also remove sent assets and received assets
-					SentAssets::<T, I>::remove(&(collection.clone(), item.clone()));
+				Self::finalize_message(message_hash);
+			}
 
-					// We emit event about return to origin chain
-					Self::deposit_event(Event::NFTReturnedToOrigin {
-						returned_from_collection_id: collection.clone(),
-						returned_from_asset_id: item.clone(),
-						to_address: signed_origin.clone(),
-					});
+			consumed
+		}
 
-					return Ok(().into())
-				} else {
-					// The item returns to chain, that sent it already, but it is not origin,
-					// proceeding as normal, but removing item from sent assets
-					SentAssets::<T, I>::remove(&(collection.clone(), item.clone()));
-				}
+		/// Records that message `message_hash` reached final delivery status. Only last
+		/// `MaxFinalizedMessages` such messages are kept, the oldest one is removed from outbound
+		/// messages.
+		pub(crate) fn finalize_message(message_hash: XcmHash) {
+			let max = T::MaxFinalizedMessages::get();
+
+			if max == 0 {
+				OutboundMessages::<T, I>::remove(message_hash);
+				return;
 			}
 
-			// Check if the owner owns the collection
-			ensure!(
-				pallet_nfts::Pallet::<T, I>::collection_owner(collection.clone()).unwrap() ==
-					signed_origin.clone(),
-				Error::<T, I>::NotCollectionOwner
-			);
+			let count = FinalizedMessageCount::<T, I>::get();
+			FinalizedMessageCount::<T, I>::put(count.wrapping_add(1));
 
-			// Check if the item exists
-			ensure!(
-				!pallet_nfts::Item::<T, I>::contains_key(&collection.clone(), &item),
-				Error::<T, I>::NFTExists
+			let slot = (count % u64::from(max)) as u32;
+
+			if let Some(oldest) =
+				FinalizedMessages::<T, I>::mutate(slot, |message| message.replace(message_hash))
+			{
+				OutboundMessages::<T, I>::remove(oldest);
+			}
+		}
+
+		/// Records collection sent by message `message_hash` along with its `items`, so it can be
+		/// restored if the message fails on destination chain. Has to be called before the
+		/// collection is burned.
+		pub(crate) fn record_pending_collection(
+			message_hash: XcmHash,
+			owner: &T::AccountId,
+			collection: &T::CollectionId,
+			items: &[T::ItemId],
+		) {
+			let nfts = items
+				.iter()
+				.filter_map(|item| {
+					let nft_owner =
+						pallet_nfts::Pallet::<T, I>::owner(collection.clone(), item.clone())?;
+					let data = Self::sent_metadata(collection, Some(item));
+					OverflowMetadata::<T, I>::remove(collection, Some(item.clone()));
+					ReceivedMetadataHashes::<T, I>::remove(&(collection.clone(), item.clone()));
+
+					Some((item.clone(), nft_owner, data))
+				})
+				.collect();
+
+			PendingTransfers::<T, I>::insert(
+				message_hash,
+				PendingTransfer::Collection {
+					owner: owner.clone(),
+					collection: collection.clone(),
+					config: pallet_nfts::CollectionConfigOf::<T, I>::get(collection.clone()),
+					metadata: Self::sent_metadata(collection, None),
+					nfts,
+				},
 			);
+		}
 
-			match pallet_nfts::Pallet::<T, I>::mint(
-				origin.clone(),
-				collection.clone(),
-				item.clone(),
-				signed_origin_lookup.clone(),
-				None,
-			) {
-				Ok(_) => {},
-				Err(e) => {
-					// Deposit event indicating failure to mint NFT
-					Self::deposit_event(Event::NFTMintFailed {
-						collection_id: collection.clone(),
-						asset_id: item.clone(),
-						owner: signed_origin_lookup.clone(),
-						error: e,
-					});
+		/// Restores assets of transfer that failed on destination chain. Sent NFT gets its
+		/// metadata back or is minted again, sent collection is created again unless some of its
+		/// NFTs stayed in it. Returns collection the assets were restored into.
+		pub(crate) fn restore_transfer(
+			transfer: PendingTransfer<T, I>,
+		) -> Result<T::CollectionId, DispatchError> {
+			match transfer {
+				PendingTransfer::NFT { owner, collection, item, data } => {
+					PendingConfirmations::<T, I>::remove(&(collection.clone(), item.clone()));
+					// NFT received from another chain is listed as received again
+					if let Some(sent) =
+						SentAssets::<T, I>::take(&(collection.clone(), item.clone()))
+					{
+						if sent.origin_para_id != parachain_info::Pallet::<T>::parachain_id() {
+							ReceivedAssets::<T, I>::insert(
+								(collection.clone(), item.clone()),
+								ReceivedStruct {
+									origin_para_id: sent.origin_para_id,
+									origin_collection_id: sent.origin_collection_id,
+									origin_asset_id: sent.origin_asset_id,
+									received_collection_id: collection.clone(),
+									received_asset_id: item.clone(),
+								},
+							);
+						}
+					}
+
+					Self::receive_into_derivative(collection.clone(), item, owner, data)?;
+
+					Ok(collection)
+				},
+				PendingTransfer::Collection { owner, collection, config, metadata, nfts } => {
+					let collection = if pallet_nfts::Collection::<T, I>::contains_key(&collection) {
+						// Collection kept for NFTs that stayed on this chain is no longer residual
+						ResidualCollections::<T, I>::remove(&collection);
+						collection
+					} else {
+						// Collection is created again under new collection id
+						let restored = Self::do_create_collection(&owner, config)?;
+						let signed_owner: OriginFor<T> =
+							frame_system::RawOrigin::Signed(owner).into();
+
+						if !metadata.is_empty() {
+							let metadata = Self::receive_metadata(&restored, None, metadata)?;
+							pallet_nfts::Pallet::<T, I>::set_collection_metadata(
+								signed_owner,
+								restored.clone(),
+								metadata,
+							)?;
+						}
+
+						restored
+					};
+
+					for (item, nft_owner, data) in nfts {
+						Self::receive_into_derivative(collection.clone(), item, nft_owner, data)?;
+					}
+
+					Ok(collection)
+				},
+			}
+		}
+
+		/// Returns weight of restoring assets of failed `transfer`, which matches weight of
+		/// receiving them on destination chain.
+		fn restore_weight(transfer: &PendingTransfer<T, I>) -> Weight {
+			match transfer {
+				PendingTransfer::NFT { data, .. } =>
+					T::WeightInfo::parse_item().saturating_add(Self::metadata_weight(data)),
+				PendingTransfer::Collection { metadata, nfts, .. } => Self::collection_weight(
+					T::WeightInfo::parse_same_owner_col(),
+					metadata,
+					nfts.iter().map(|(_, _, data)| data.as_slice()),
+				),
+			}
+		}
+
+		/// Returns weight of restoring assets of failed transfer `message_hash`. The extrinsic is
+		/// benchmarked with a single NFT without metadata, assets beyond it are weighed the same
+		/// way as restoring them along with the failure report.
+		pub(crate) fn failed_transfer_weight(message_hash: &XcmHash) -> Weight {
+			let base = T::WeightInfo::restore_failed_transfer();
+
+			match FailedTransfers::<T, I>::get(message_hash) {
+				Some(transfer) => base.saturating_add(
+					Self::restore_weight(&transfer).saturating_sub(T::WeightInfo::parse_item()),
+				),
+				None => base,
+			}
+		}
+
+		/// Estimates weight of `call` executed on destination chain from its payload. Destination
+		/// chain is expected to use the same benchmarked weights as this chain.
+		pub(crate) fn transact_weight(call: &Call<T, I>) -> Weight {
+			let estimate = match call {
+				Call::parse_collection_empty { collection_metadata, .. } =>
+					T::WeightInfo::parse_empty_col()
+						.saturating_add(Self::metadata_weight(collection_metadata)),
+				Call::parse_collection_same_owner { collection_metadata, nfts, .. } =>
+					Self::collection_weight(
+						T::WeightInfo::parse_same_owner_col(),
+						collection_metadata,
+						nfts.iter().map(|(_, data)| data.as_slice()),
+					),
+				Call::parse_collection_diff_owners { collection_metadata, nfts, .. } =>
+					Self::collection_weight(
+						T::WeightInfo::parse_diff_owner_col(),
+						collection_metadata,
+						nfts.iter().map(|(_, _, data)| data.as_slice()),
+					),
+				Call::parse_nft_transfer { data, .. } =>
+					T::WeightInfo::parse_item().saturating_add(Self::metadata_weight(data)),
+				Call::parse_sequenced { call: inner, .. } => Self::transact_weight(inner),
+				_ => Weight::zero(),
+			};
+
+			// Calls without payload are bounded by their declared weight
+			estimate.max(call.get_dispatch_info().call_weight)
+		}
+
+		/// Returns weight of parsing a collection on destination chain, `base` is benchmarked with
+		/// a single asset without metadata.
+		fn collection_weight<'a>(
+			base: Weight,
+			collection_metadata: &[u8],
+			nfts_metadata: impl IntoIterator<Item = &'a [u8]>,
+		) -> Weight {
+			nfts_metadata.into_iter().enumerate().fold(
+				base.saturating_add(Self::metadata_weight(collection_metadata)),
+				|total, (index, data)| {
+					let item =
+						if index == 0 { Weight::zero() } else { T::WeightInfo::parse_item() };
+					total.saturating_add(item).saturating_add(Self::metadata_weight(data))
 				},
+			)
+		}
+
+		/// Returns weight of storing metadata `data` on destination chain.
+		fn metadata_weight(data: &[u8]) -> Weight {
+			if data.is_empty() {
+				return Weight::zero();
+			}
+
+			T::DbWeight::get()
+				.reads_writes(1, 1)
+				.saturating_add(Weight::from_parts(0, data.len() as u64))
+		}
+
+		/// Returns deposit reserved on destination chain for metadata `data`. Destination chain is
+		/// expected to use the same deposit constants as this chain.
+		pub(crate) fn metadata_deposit(data: &[u8]) -> BalanceOf<T, I> {
+			if data.is_empty() {
+				return Zero::zero();
+			}
+
+			T::MetadataDepositBase::get()
+				.saturating_add(T::DepositPerByte::get().saturating_mul((data.len() as u32).into()))
+		}
+
+		/// Returns deposit reserved on destination chain for minting an asset with metadata `data`.
+		pub(crate) fn item_deposit(data: &[u8]) -> BalanceOf<T, I> {
+			T::ItemDeposit::get().saturating_add(Self::metadata_deposit(data))
+		}
+
+		/// Returns deposit reserved on destination chain for creating a collection with metadata
+		/// `collection_metadata` and minting assets with metadata `nfts_metadata`.
+		pub(crate) fn collection_deposit<'a>(
+			collection_metadata: &[u8],
+			nfts_metadata: impl IntoIterator<Item = &'a [u8]>,
+		) -> BalanceOf<T, I> {
+			nfts_metadata.into_iter().fold(
+				T::CollectionDeposit::get()
+					.saturating_add(Self::metadata_deposit(collection_metadata)),
+				|total, data| total.saturating_add(Self::item_deposit(data)),
+			)
+		}
+
+		/// Returns `deposit` amount of `deposit_asset` to be deposited to `beneficiary` on
+		/// destination chain, if any.
+		pub(crate) fn deposit_funding(
+			deposit_asset: Option<AssetId>,
+			deposit: BalanceOf<T, I>,
+			beneficiary: &Location,
+		) -> Option<(Asset, Location)> {
+			let amount: u128 = deposit.unique_saturated_into();
+			deposit_asset
+				.filter(|_| amount > 0)
+				.map(|id| (Asset { id, fun: Fungible(amount) }, beneficiary.clone()))
+		}
+
+		/// Removes collection from received collections along with its origin index and its
+		/// overflowed metadata.
+		pub(crate) fn remove_received_collection(collection: &T::CollectionId) {
+			OverflowMetadata::<T, I>::remove(collection, None::<T::ItemId>);
+			if let Some(received) = ReceivedCollections::<T, I>::take(collection) {
+				ReceivedCollectionsByOrigin::<T, I>::mutate_exists(
+					received.origin_para_id,
+					received.origin_collection_id,
+					|indexed| {
+						if indexed.as_ref() == Some(collection) {
+							*indexed = None;
+						}
+					},
+				);
 			}
+		}
 
-			if !data.is_empty() {
-				match pallet_nfts::Pallet::<T, I>::set_metadata(
-					origin.clone(),
+		/// Indexes `collection` received from `origin_collection` by its origin, if the origin
+		/// chain is verified to send it. Collection already indexed for the origin is kept while
+		/// it exists, so later transfers cannot redirect NFTs of the origin collection.
+		pub(crate) fn index_received_collection(
+			origin_chain: Option<ParaId>,
+			origin_collection: &T::CollectionId,
+			collection: &T::CollectionId,
+		) {
+			let Some(origin_chain) = origin_chain else { return };
+
+			let indexed = ReceivedCollectionsByOrigin::<T, I>::get(origin_chain, origin_collection);
+			if indexed
+				.map_or(true, |indexed| !pallet_nfts::Collection::<T, I>::contains_key(&indexed))
+			{
+				ReceivedCollectionsByOrigin::<T, I>::insert(
+					origin_chain,
+					origin_collection,
+					collection,
+				);
+			}
+		}
+
+		/// Receives NFT into received collection of its origin collection. The NFT is minted if it
+		/// does not exist yet, otherwise only its metadata is updated.
+		pub(crate) fn receive_into_derivative(
+			collection: T::CollectionId,
+			item: T::ItemId,
+			owner: T::AccountId,
+			data: Vec<u8>,
+		) -> DispatchResult {
+			// Collection owner mints the NFT and sets its metadata
+			let col_owner = pallet_nfts::Pallet::<T, I>::collection_owner(collection.clone())
+				.ok_or(Error::<T, I>::CollectionDoesNotExist)?;
+			let signed_col: OriginFor<T> = frame_system::RawOrigin::Signed(col_owner).into();
+
+			if !pallet_nfts::Item::<T, I>::contains_key(collection.clone(), &item) {
+				pallet_nfts::Pallet::<T, I>::mint(
+					signed_col.clone(),
 					collection.clone(),
 					item.clone(),
-					data.clone(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						// Deposit event indicating failure to set metadata
-						Self::deposit_event(Event::NFTMetadataSetFailed {
-							collection_id: collection.clone(),
-							asset_id: item.clone(),
-							owner: signed_origin_lookup.clone(),
-							error: e,
-						});
-					},
-				}
+					T::Lookup::unlookup(owner),
+					None,
+				)?;
 			}
 
-			// Check if the NFT was minted successfuly
-			ensure!(
-				pallet_nfts::Item::<T, I>::contains_key(&collection, &item),
-				Error::<T, I>::NFTDoesNotExist
-			);
+			if !data.is_empty() {
+				let data = Self::receive_metadata(&collection, Some(&item), data)?;
+				pallet_nfts::Pallet::<T, I>::set_metadata(signed_col, collection, item, data)?;
+			}
 
-			// Add the item to the received item storage
-			ReceivedAssets::<T, I>::insert(
-				(collection.clone(), item.clone()),
-				ReceivedStruct {
-					origin_para_id: origin_chain.clone(),
-					origin_collection_id: origin_collection.clone(),
-					origin_asset_id: origin_item.clone(),
-					received_collection_id: collection.clone(),
-					received_asset_id: item.clone(),
+			Ok(())
+		}
+
+		/// Bounds metadata `data` received for `collection`, or for its `item`, by `StringLimit`.
+		/// Metadata exceeding the limit is handled by `MetadataPolicy`, the applied policy is
+		/// recorded by `MetadataPolicyApplied` event. Metadata that cannot be kept whole within
+		/// `MaxOverflowMetadata` is rejected under `MetadataPolicy::Overflow`.
+		pub(crate) fn receive_metadata(
+			collection: &T::CollectionId,
+			item: Option<&T::ItemId>,
+			data: Vec<u8>,
+		) -> Result<BoundedVec<u8, T::StringLimit>, DispatchError> {
+			// Received metadata replaces metadata that overflowed before
+			OverflowMetadata::<T, I>::remove(collection, item.cloned());
+
+			let length = data.len() as u32;
+			let data = match BoundedVec::try_from(data) {
+				Ok(data) => return Ok(data),
+				Err(data) => data,
+			};
+
+			let policy = T::MetadataPolicy::get();
+			let bounded = match policy {
+				MetadataPolicy::Reject => return Err(Error::<T, I>::MetadataTooLong.into()),
+				MetadataPolicy::Truncate => BoundedVec::truncate_from(data),
+				MetadataPolicy::Overflow => {
+					let whole = BoundedVec::<u8, T::MaxOverflowMetadata>::try_from(data.clone())
+						.map_err(|_| Error::<T, I>::MetadataTooLong)?;
+					OverflowMetadata::<T, I>::insert(collection, item.cloned(), whole);
+					BoundedVec::truncate_from(data)
 				},
-			);
+				MetadataPolicy::Hash => BlakeTwo256::hash(&data)
+					.as_ref()
+					.to_vec()
+					.try_into()
+					.map_err(|_| Error::<T, I>::MetadataTooLong)?,
+			};
 
-			// Emit a success event
-			Self::deposit_event(Event::NFTReceived {
-				origin_collection_id: origin_collection.clone(),
-				origin_asset_id: origin_item.clone(),
-				received_collection_id: collection.clone(),
-				received_asset_id: item.clone(),
-				to_address: signed_origin_lookup.clone(),
+			Self::deposit_event(Event::MetadataPolicyApplied {
+				collection_id: collection.clone(),
+				item_id: item.cloned(),
+				policy,
+				length,
 			});
 
-			Ok(().into())
+			Ok(bounded)
 		}
 
-		/// Receive function for  collection_x_transfer function.
-		///
-		/// Used when collection has nfts, but they are owned by the same owner.
-		///
-		/// Shouldn't be used as a regular call.
-		///
-		/// On success emits `CollectionWithNftsReceived` event.
-		#[pallet::call_index(19)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_collection_same_owner(
-			origin: OriginFor<T>,
-			config: Option<CollectionConfigFor<T, I>>,
-			collection_metadata: BoundedVec<u8, T::StringLimit>,
-			nfts: Vec<(T::ItemId, BoundedVec<u8, T::StringLimit>)>,
-			origin_para: ParaId,
-			origin_collection_id: T::CollectionId,
-			_dest_collection_id: Option<T::CollectionId>,
-		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin.clone())?;
-			let signed_origin_lookup = T::Lookup::unlookup(who.clone());
-
-			// Get next collection id
-			let mut next_collection_id = pallet_nfts::NextCollectionId::<T, I>::get()
-				.or(T::CollectionId::initial_value())
-				.unwrap();
-
-			// Check if config is present
-			if config.is_none() {
-				// Create default configfor collection
-				let def_config = CollectionConfigFor::<T, I> {
-					settings: CollectionSettings::all_enabled(), // Default settings (all enabled)
-					max_supply: None,                            /* No maximum supply defined
-					                                              * initially */
-					mint_settings: MintSettings::default(), // Use default mint settings
-				};
-
-				match pallet_nfts::Pallet::<T, I>::create(
-					origin.clone(),
-					signed_origin_lookup.clone(),
-					def_config.clone(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						//  Deposit event indicating failure to create collection
-						Self::deposit_event(Event::CollectionMintFailed { error: e });
-					},
-				}
-			} else {
-				match pallet_nfts::Pallet::<T, I>::create(
-					origin.clone(),
-					signed_origin_lookup.clone(),
-					config.clone().unwrap(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						//  Deposit event indicating failure to create collection
-						Self::deposit_event(Event::CollectionMintFailed { error: e });
-					},
-				}
+		/// Returns metadata of `collection`, or of its `item`, to be sent to another chain.
+		/// Metadata truncated under `MetadataPolicy::Overflow` is sent whole, unless it was
+		/// changed since it was received.
+		pub(crate) fn sent_metadata(
+			collection: &T::CollectionId,
+			item: Option<&T::ItemId>,
+		) -> Vec<u8> {
+			let stored = match item {
+				Some(item) => pallet_nfts::ItemMetadataOf::<T, I>::get(collection, item)
+					.map(|metadata| metadata.data),
+				None => pallet_nfts::CollectionMetadataOf::<T, I>::get(collection)
+					.map(|metadata| metadata.data),
 			}
+			.unwrap_or_default();
 
-			let mut user_collection = next_collection_id.clone();
-
-			// just to be sure, check if user is collection owner
-			if pallet_nfts::Pallet::<T, I>::collection_owner(next_collection_id.clone()).unwrap() !=
-				who.clone()
-			{
-				//Get current next_collection_id
-				let current_next_collection_id = pallet_nfts::NextCollectionId::<T, I>::get()
-					.ok_or(Error::<T, I>::NoNextCollectionId)?;
-
-				// Go from next_collection_id to current_next_collection_id and try to find the
-				// collection that is owned by the user
-				while next_collection_id != current_next_collection_id {
-					if pallet_nfts::Pallet::<T, I>::collection_owner(next_collection_id).unwrap() ==
-						who.clone()
-					{
-						// We have users collection
-						user_collection = next_collection_id.clone();
-						break;
-					}
-					next_collection_id =
-						next_collection_id.increment().ok_or(Error::<T, I>::NoNextCollectionId)?;
-				}
+			match OverflowMetadata::<T, I>::get(collection, item.cloned()) {
+				Some(data)
+					if BoundedVec::<u8, T::StringLimit>::truncate_from(data.to_vec()) == stored =>
+					data.into_inner(),
+				_ => stored.into_inner(),
 			}
+		}
 
-			// Set the collection metadata if present
-			if !collection_metadata.is_empty() {
-				match pallet_nfts::Pallet::<T, I>::set_collection_metadata(
-					origin.clone(),
-					user_collection.clone(),
-					collection_metadata.clone(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						// Deposit event indicating failure to set metadata
-						Self::deposit_event(Event::CollectionMetadataSetFailed {
-							collection_id: user_collection.clone(),
-							owner: signed_origin_lookup.clone(),
-							error: e,
-						});
-					},
-				}
+		/// Returns hashes of metadata of `item` of `collection`, `collection_metadata` if sent
+		/// along and item metadata `data` are metadata as sent by `sent_metadata`.
+		pub(crate) fn metadata_hashes(
+			collection: &T::CollectionId,
+			item: &T::ItemId,
+			collection_metadata: Option<&[u8]>,
+			data: &[u8],
+		) -> MetadataHashes {
+			MetadataHashes {
+				collection: collection_metadata.map(BlakeTwo256::hash),
+				item: BlakeTwo256::hash(data),
+				attributes: Self::attributes_hash(collection, item),
 			}
+		}
 
-			//Iterate through vector of nfts
-			for nft in nfts.clone() {
-				let item = nft.0;
-				let data = nft.1;
+		/// Returns hash of attributes of `item` of `collection`. Attributes are not sent along
+		/// with the NFT, so only their hash is carried to destination chain.
+		pub(crate) fn attributes_hash(
+			collection: &T::CollectionId,
+			item: &T::ItemId,
+		) -> MetadataHash {
+			let attributes: Vec<_> = pallet_nfts::Attribute::<T, I>::iter_prefix((
+				collection.clone(),
+				Some(item.clone()),
+			))
+			.map(|(key, (value, _))| (key, value))
+			.collect();
 
-				match pallet_nfts::Pallet::<T, I>::mint(
-					origin.clone(),
-					user_collection.clone(),
-					item.clone(),
-					signed_origin_lookup.clone(),
-					None,
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						// Deposit event indicating failure to mint NFT
-						Self::deposit_event(Event::NFTMintFailed {
-							collection_id: user_collection.clone(),
-							asset_id: item.clone(),
-							owner: signed_origin_lookup.clone(),
-							error: e,
-						});
-					},
-				}
-				//If empty metadata, skip
-				if !data.is_empty() {
-					match pallet_nfts::Pallet::<T, I>::set_metadata(
-						origin.clone(),
-						user_collection.clone(),
-						item.clone(),
-						data.clone(),
-					) {
-						Ok(_) => {},
-						Err(e) => {
-							// Deposit event indicating failure to set metadata
-							Self::deposit_event(Event::NFTMetadataSetFailed {
-								collection_id: user_collection.clone(),
-								asset_id: item.clone(),
-								owner: signed_origin_lookup.clone(),
-								error: e,
-							});
-						},
-					}
-				}
+			BlakeTwo256::hash_of(&attributes)
+		}
 
-				// Check if the NFT was minted if storage contains the item
-				ensure!(
-					pallet_nfts::Item::<T, I>::contains_key(&user_collection, &item),
-					Error::<T, I>::NFTDoesNotExist
-				);
-			}
+		/// Returns hashes of metadata `item` of `collection` currently holds, hash of collection
+		/// metadata is included only if `with_collection`.
+		pub(crate) fn stored_metadata_hashes(
+			collection: &T::CollectionId,
+			item: &T::ItemId,
+			with_collection: bool,
+		) -> MetadataHashes {
+			let collection_metadata =
+				with_collection.then(|| Self::sent_metadata(collection, None));
+			Self::metadata_hashes(
+				collection,
+				item,
+				collection_metadata.as_deref(),
+				&Self::sent_metadata(collection, Some(item)),
+			)
+		}
 
-			// Add collection to received collections
-			ReceivedCollections::<T, I>::insert(
-				user_collection.clone(),
-				ReceivedCols {
-					origin_para_id: origin_para.clone(),
-					origin_collection_id: origin_collection_id.clone(),
-					received_collection_id: user_collection.clone(),
-				},
+		/// Verifies received item metadata `data`, and `collection_metadata` if received along,
+		/// against `hashes` computed on origin chain.
+		pub(crate) fn verify_metadata_hashes(
+			hashes: &MetadataHashes,
+			collection_metadata: Option<&[u8]>,
+			data: &[u8],
+		) -> DispatchResult {
+			ensure!(hashes.item == BlakeTwo256::hash(data), Error::<T, I>::MetadataHashMismatch);
+			ensure!(
+				hashes.collection == collection_metadata.map(BlakeTwo256::hash),
+				Error::<T, I>::MetadataHashMismatch
 			);
 
-			// Emit event about successful cross-chain operation
-			Self::deposit_event(Event::CollectionWithNftsReceived {
-				collection_id: user_collection.clone(),
-				items: nfts.clone(),
-			});
+			Ok(())
+		}
 
-			Ok(().into())
+		/// Records metadata `hashes` `item` of `collection` was transferred with, along with
+		/// hashes of its metadata as stored on arrival.
+		pub(crate) fn record_metadata_hashes(
+			collection: &T::CollectionId,
+			item: &T::ItemId,
+			hashes: MetadataHashes,
+		) {
+			let arrived =
+				Self::stored_metadata_hashes(collection, item, hashes.collection.is_some());
+			ReceivedMetadataHashes::<T, I>::insert(
+				(collection.clone(), item.clone()),
+				ReceivedHashes { received: hashes, arrived },
+			);
 		}
 
-		/// Receive function for  collection_x_transfer_initiate function.
-		///
-		/// Used when collection has nfts, but they are not owned by the same owner.
-		///
-		/// Shouldn't be used as a regular call.
-		///
-		/// On success emits `CollectionWithNftsDiffOwnersReceived` event.
-		#[pallet::call_index(20)]
-		#[pallet::weight(Weight::from_parts(10_000, 0) + T::DbWeight::get().writes(1))]
-		pub fn parse_collection_diff_owners(
-			origin: OriginFor<T>,
-			config: Option<CollectionConfigFor<T, I>>,
-			collection_metadata: BoundedVec<u8, T::StringLimit>,
-			nfts: Vec<(T::ItemId, AccountIdLookupOf<T>, BoundedVec<u8, T::StringLimit>)>,
-			origin_para: ParaId,
-			origin_collection_id: T::CollectionId,
-			_dest_collection_id: Option<T::CollectionId>,
-		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin.clone())?;
-			let signed_origin_lookup = T::Lookup::unlookup(who.clone());
+		/// Returns metadata hashes `item` of `collection` was transferred with and hashes of its
+		/// metadata on arrival, along with hashes of its current metadata, so clients can check
+		/// whether the metadata was altered in transit or since arrival. Returns `None` if the
+		/// NFT was not received from another chain.
+		pub fn metadata_integrity(
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> Option<MetadataIntegrity> {
+			let ReceivedHashes { received, arrived } =
+				ReceivedMetadataHashes::<T, I>::get(&(collection.clone(), item.clone()))?;
+			let current =
+				Self::stored_metadata_hashes(&collection, &item, received.collection.is_some());
 
-			//Get next collection id
-			let mut next_collection_id = pallet_nfts::NextCollectionId::<T, I>::get()
-				.or(T::CollectionId::initial_value())
-				.unwrap();
-
-			// Check if config is present
-			if config.is_none() {
-				// Create default configfor collection
-				let def_config = CollectionConfigFor::<T, I> {
-					settings: CollectionSettings::all_enabled(), // Default settings (all enabled)
-					max_supply: None,                            /* No maximum supply defined
-					                                              * initially */
-					mint_settings: MintSettings::default(), // Use default mint settings
-				};
+			Some(MetadataIntegrity { received, arrived, current })
+		}
 
-				match pallet_nfts::Pallet::<T, I>::create(
-					origin.clone(),
-					signed_origin_lookup.clone(),
-					def_config.clone(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						//  Deposit event indicating failure to create collection
-						Self::deposit_event(Event::CollectionMintFailed { error: e });
-					},
-				}
-			} else {
-				match pallet_nfts::Pallet::<T, I>::create(
-					origin.clone(),
-					signed_origin_lookup.clone(),
-					config.clone().unwrap(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						//  Deposit event indicating failure to create collection
-						Self::deposit_event(Event::CollectionMintFailed { error: e });
-					},
+		/// Unlocks next page of NFTs locked by ended proposal of the collection and removes next
+		/// page of its owners, votes, delegations, beneficiaries and snapshotted NFTs. Returns true
+		/// once the cleanup is complete.
+		pub(crate) fn cleanup_page(collection: &T::CollectionId) -> bool {
+			let (proposal_id, locker) = match ProposalCleanups::<T, I>::get(collection) {
+				Some(cleanup) => cleanup,
+				None => return true,
+			};
+
+			let page_size = T::SnapshotPageSize::get() as usize;
+			let signed_owner: OriginFor<T> = frame_system::RawOrigin::Signed(locker).into();
+
+			let items: Vec<T::ItemId> = ProposalLockedItems::<T, I>::drain_prefix(proposal_id)
+				.take(page_size)
+				.map(|(item_id, _)| item_id)
+				.collect();
+
+			for &item_id in items.iter() {
+				// Burned NFTs no longer need to be unlocked
+				if pallet_nfts::Item::<T, I>::contains_key(collection, &item_id) {
+					let _ = pallet_nfts::Pallet::<T, I>::unlock_item_transfer(
+						signed_owner.clone(),
+						*collection,
+						item_id,
+					);
 				}
 			}
 
-			let mut user_collection = next_collection_id.clone();
+			let owners: Vec<T::AccountId> = ProposalOwners::<T, I>::drain_prefix(proposal_id)
+				.take(page_size)
+				.map(|(owner, _)| owner)
+				.collect();
 
-			// just to be sure, check if user is collection owner
-			if pallet_nfts::Pallet::<T, I>::collection_owner(next_collection_id.clone()).unwrap() !=
-				who.clone()
-			{
-				//Get current next_collection_id
-				let current_next_collection_id = pallet_nfts::NextCollectionId::<T, I>::get()
-					.ok_or(Error::<T, I>::NoNextCollectionId)?;
-
-				// Go from next_collection_id to current_next_collection_id and try to find the
-				// collection that is owned by the user
-				while next_collection_id != current_next_collection_id {
-					if pallet_nfts::Pallet::<T, I>::collection_owner(next_collection_id).unwrap() ==
-						who.clone()
-					{
-						// We have users collection
-						user_collection = next_collection_id.clone();
-						break;
-					}
-					next_collection_id =
-						next_collection_id.increment().ok_or(Error::<T, I>::NoNextCollectionId)?;
-				}
+			for owner in owners.iter() {
+				VotableProposals::<T, I>::remove(owner, proposal_id);
 			}
 
-			// Set the collection metadata if present
-			if !collection_metadata.is_empty() {
-				match pallet_nfts::Pallet::<T, I>::set_collection_metadata(
-					origin.clone(),
-					user_collection.clone(),
-					collection_metadata.clone(),
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						// Deposit event indicating failure to set metadata
-						Self::deposit_event(Event::CollectionMetadataSetFailed {
-							collection_id: user_collection.clone(),
-							owner: signed_origin_lookup.clone(),
-							error: e,
-						});
-					},
-				}
+			let delegates: Vec<T::AccountId> =
+				ProposalDelegations::<T, I>::drain_prefix(proposal_id)
+					.take(page_size)
+					.map(|(_, delegate)| delegate)
+					.collect();
+
+			for delegate in delegates.iter() {
+				VotableProposals::<T, I>::remove(delegate, proposal_id);
 			}
 
-			//Iterate through vector of nfts
-			for nft in nfts.clone() {
-				let item = nft.0;
-				let nft_owner = nft.1;
-				let data = nft.2;
+			let removed = [
+				items.len(),
+				owners.len(),
+				delegates.len(),
+				ProposalVotes::<T, I>::drain_prefix(proposal_id).take(page_size).count(),
+				ProposalDelegators::<T, I>::drain_prefix(proposal_id).take(page_size).count(),
+				ProposalBeneficiaries::<T, I>::drain_prefix(proposal_id).take(page_size).count(),
+				ProposalItems::<T, I>::drain_prefix(proposal_id).take(page_size).count(),
+			];
+
+			// Cleanup is complete once no page is full
+			let complete = removed.iter().all(|count| *count < page_size);
+
+			if complete {
+				ProposalCleanups::<T, I>::remove(collection);
+			}
 
-				match pallet_nfts::Pallet::<T, I>::mint(
-					origin.clone(),
-					user_collection.clone(),
-					item.clone(),
-					nft_owner.clone(),
-					None,
-				) {
-					Ok(_) => {},
-					Err(e) => {
-						// Deposit event indicating failure to mint NFT
-						Self::deposit_event(Event::NFTMintFailed {
-							collection_id: user_collection.clone(),
-							asset_id: item.clone(),
-							owner: nft_owner.clone(),
-							error: e,
-						});
-					},
-				}
+			complete
+		}
 
-				if !data.is_empty() {
-					match pallet_nfts::Pallet::<T, I>::set_metadata(
-						origin.clone(),
-						user_collection.clone(),
-						item.clone(),
-						data.clone(),
-					) {
-						Ok(_) => {},
-						Err(e) => {
-							// Deposit event indicating failure to set metadata
-							Self::deposit_event(Event::NFTMetadataSetFailed {
-								collection_id: user_collection.clone(),
-								asset_id: item.clone(),
-								owner: nft_owner.clone(),
-								error: e,
-							});
-						},
-					}
-				}
+		/// Removes proposal from storage along with its collection index and returns the deposit to
+		/// the proposer. Part of the deposit is slashed if the proposal `failed`. NFTs locked by
+		/// the proposal are unlocked and its voter indices removed in pages, the first page right
+		/// away and remaining pages through `collection_x_transfer_cleanup`.
+		pub(crate) fn remove_proposal(proposal: &Proposal<T, I>, failed: bool) {
+			ProposalCleanups::<T, I>::insert(
+				&proposal.collection_id,
+				(proposal.proposal_id, proposal.proposed_collection_owner.clone()),
+			);
+			Self::cleanup_page(&proposal.collection_id);
 
-				//Check if the NFT was minted if storage contains the item
-				ensure!(
-					pallet_nfts::Item::<T, I>::contains_key(&user_collection, &item),
-					Error::<T, I>::NFTDoesNotExist
-				);
+			if ProposalOfCollection::<T, I>::get(&proposal.collection_id) ==
+				Some(proposal.proposal_id)
+			{
+				ProposalOfCollection::<T, I>::remove(&proposal.collection_id);
 			}
 
-			//Add collection to received collections
-			ReceivedCollections::<T, I>::insert(
-				user_collection.clone(),
-				ReceivedCols {
-					origin_para_id: origin_para.clone(),
-					origin_collection_id: origin_collection_id.clone(),
-					received_collection_id: user_collection.clone(),
-				},
-			);
+			CrossChainProposals::<T, I>::remove(proposal.proposal_id);
 
-			//If all went up to this point, emit event about successful cross-chain operation
-			Self::deposit_event(Event::CollectionWithNftsDiffOwnersReceived {
-				collection_id: user_collection.clone(),
-				items: nfts.clone(),
-			});
+			let proposer = &proposal.proposed_collection_owner;
 
-			Ok(().into())
+			let slashed = if failed {
+				T::ProposalDepositSlash::get() * proposal.deposit
+			} else {
+				Zero::zero()
+			};
+
+			if !slashed.is_zero() {
+				let _ = T::Currency::slash_reserved(proposer, slashed);
+
+				Self::deposit_event(Event::ProposalDepositSlashed {
+					proposal_id: proposal.proposal_id,
+					proposer: proposer.clone(),
+					amount: slashed,
+				});
+			}
+
+			T::Currency::unreserve(proposer, proposal.deposit.saturating_sub(slashed));
+
+			ActiveProposals::<T, I>::mutate_exists(proposer, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+			});
 		}
 	}
 }
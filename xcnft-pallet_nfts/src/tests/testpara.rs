@@ -28,12 +28,13 @@ use frame_support::{
 	parameter_types,
 	traits::{ConstU128, ContainsPair, EnsureOrigin, EnsureOriginWithArg, Everything, Nothing},
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+	PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::ConstU32;
 use sp_runtime::{
 	traits::{ConstU64, IdentityLookup, Verify},
-	AccountId32, BuildStorage, MultiSignature,
+	AccountId32, BuildStorage, MultiSignature, Perbill,
 };
 use xcm::latest::prelude::*;
 use xcm_builder::{EnsureXcmOrigin, SignedToAccountId32};
@@ -79,8 +80,23 @@ parameter_types! {
 	pub const MaxAttributesPerCall: u32 = 10;
 	pub NftFeatures: pallet_nfts::PalletFeatures = pallet_nfts::PalletFeatures::all_enabled();
 	pub const proposal_time_in_blocks_parameter: u32 = 10;
-	pub const max_owners_parameter: u32 = 1000000;
+	pub const message_timeout_in_blocks_parameter: u32 = 10;
+	pub static max_finalized_messages_parameter: u32 = 16;
+	pub const max_queued_messages_parameter: u32 = 16;
+	pub const max_send_attempts_parameter: u32 = 3;
+	pub const retry_backoff_in_blocks_parameter: u32 = 2;
+	pub const snapshot_page_size_parameter: u32 = 10;
+	pub const proposal_deposit_parameter: Balance = 100 * UNIT;
+	pub const proposal_deposit_slash_parameter: Perbill = Perbill::from_percent(50);
+	pub const max_active_proposals_parameter: u32 = 2;
+	pub const max_transact_weight_parameter: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
+	pub const xcm_instruction_weight_parameter: Weight = Weight::from_parts(1, 1);
+	pub const pallet_id_parameter: PalletId = PalletId(*b"py/xcnft");
 	pub const max_votes: u32 = 1000000;
+	pub const migration_mode_parameter: crate::MigrationMode = crate::MigrationMode::BurnAll;
+	pub static metadata_policy_parameter: crate::MetadataPolicy = crate::MetadataPolicy::Reject;
+	pub const max_overflow_metadata_parameter: u32 = 64;
+	pub static collection_creation_allowed_parameter: bool = true;
 }
 
 pub type AccountPublic = <MultiSignature as Verify>::Signer;
@@ -91,7 +107,7 @@ impl pallet_nfts::Config for Runtime {
 	type ItemId = u32;
 	type Currency = Balances;
 	type ForceOrigin = EnsureRoot<AccountId>;
-	type CreateOrigin = EnsureSigned<AccountId>;
+	type CreateOrigin = CollectionCreators;
 	type CollectionDeposit = CollectionDeposit;
 	type Locker = ();
 	type ItemDeposit = ItemDeposit;
@@ -114,6 +130,25 @@ impl pallet_nfts::Config for Runtime {
 	type WeightInfo = ();
 }
 
+// `EnsureOriginWithArg` impl for `CreateOrigin` which allows signed origins while collection
+// creation is allowed.
+pub struct CollectionCreators;
+impl EnsureOriginWithArg<RuntimeOrigin, u32> for CollectionCreators {
+	type Success = AccountId;
+
+	fn try_origin(o: RuntimeOrigin, _: &u32) -> core::result::Result<Self::Success, RuntimeOrigin> {
+		if !collection_creation_allowed_parameter::get() {
+			return Err(o);
+		}
+		EnsureSigned::<AccountId>::try_origin(o)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_: &u32) -> Result<RuntimeOrigin, ()> {
+		EnsureSigned::<AccountId>::try_successful_origin()
+	}
+}
+
 // `EnsureOriginWithArg` impl for `CreateOrigin` which allows only XCM origins
 // which are locations containing the class location.
 pub struct ForeignCreators;
@@ -194,10 +229,33 @@ impl pallet_xcm::Config for Runtime {
 impl crate::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = crate::weights::SubstrateWeight<Runtime>;
+	type MaxTransactWeight = max_transact_weight_parameter;
+	type XcmInstructionWeight = xcm_instruction_weight_parameter;
 	type XcmSender = XcmRouter;
 	type RuntimeCall = RuntimeCall;
 	type ProposalTimeInBlocks = proposal_time_in_blocks_parameter;
-	type MaxOwners = max_owners_parameter;
+	type QueryHandler = PolkadotXcm;
+	type MessageTimeoutInBlocks = message_timeout_in_blocks_parameter;
+	type MaxFinalizedMessages = max_finalized_messages_parameter;
+	type MaxQueuedMessages = max_queued_messages_parameter;
+	type MaxSendAttempts = max_send_attempts_parameter;
+	type RetryBackoffInBlocks = retry_backoff_in_blocks_parameter;
+	type SnapshotPageSize = snapshot_page_size_parameter;
+	type VotingStrategy = crate::voting::SimpleMajority;
+	type ProposalMigrationMode = migration_mode_parameter;
+	type MetadataPolicy = metadata_policy_parameter;
+	type MaxOverflowMetadata = max_overflow_metadata_parameter;
+	type ProposalDeposit = proposal_deposit_parameter;
+	type ProposalDepositSlash = proposal_deposit_slash_parameter;
+	type MaxActiveProposals = max_active_proposals_parameter;
+	type PalletId = pallet_id_parameter;
+	type ItemIdConverter = sp_runtime::traits::ConvertInto;
+	type AccountToLocation = crate::location::AccountIdToLocation<AccountId>;
+	type LocationToAccount = crate::location::LocationToAccountId<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type UniversalLocation = constants::UniversalLocation;
+	type XcmOrigin = pallet_xcm::EnsureXcm<Everything>;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
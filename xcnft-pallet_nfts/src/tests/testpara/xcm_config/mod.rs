@@ -25,11 +25,31 @@ pub mod weigher;
 
 use crate::tests::testpara::{MsgQueue, PolkadotXcm, RuntimeCall};
 use frame_support::traits::{Everything, Nothing};
+use xcm::latest::prelude::*;
 use xcm_builder::{EnsureDecodableXcm, FixedRateOfFungible, FrameTransactionalProcessor};
 use xcm_executor::traits::WithOriginFilter;
 
-// Generated from `decl_test_network!`
-pub type XcmRouter = EnsureDecodableXcm<crate::tests::ParachainXcmRouter<MsgQueue>>;
+// Generated from `decl_test_network!`, channel to parachain 3000 is full
+pub type XcmRouter =
+	EnsureDecodableXcm<(CongestedRouter, crate::tests::ParachainXcmRouter<MsgQueue>)>;
+
+// Router failing with transport error for messages to parachain 3000, as its channel is full.
+// Reason of transport error is not encoded, so it is left empty to match emitted events.
+pub struct CongestedRouter;
+impl SendXcm for CongestedRouter {
+	type Ticket = ();
+
+	fn validate(destination: &mut Option<Location>, _: &mut Option<Xcm<()>>) -> SendResult<()> {
+		match destination.as_ref().map(|destination| destination.unpack()) {
+			Some((1, [Parachain(3000)])) => Err(SendError::Transport("")),
+			_ => Err(SendError::NotApplicable),
+		}
+	}
+
+	fn deliver(_: ()) -> Result<XcmHash, SendError> {
+		Err(SendError::NotApplicable)
+	}
+}
 
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
@@ -43,7 +63,7 @@ impl xcm_executor::Config for XcmConfig {
 	type Barrier = barrier::Barrier;
 	type Weigher = weigher::Weigher;
 	type Trader = FixedRateOfFungible<constants::KsmPerSecondPerByte, ()>;
-	type ResponseHandler = ();
+	type ResponseHandler = PolkadotXcm;
 	type AssetTrap = ();
 	type AssetLocker = PolkadotXcm;
 	type AssetExchanger = ();
@@ -45,6 +45,19 @@ pub trait WeightInfo {
 	fn parse_same_owner_col() -> Weight;
 	fn parse_diff_owner_col() -> Weight;
 	fn parse_item() -> Weight;
+	fn collection_x_transfer_cancel() -> Weight;
+	fn collection_x_transfer_withdraw_vote() -> Weight;
+	fn collection_x_transfer_delegate() -> Weight;
+	fn collection_x_transfer_snapshot() -> Weight;
+	fn collection_x_transfer_cleanup() -> Weight;
+	fn collection_x_allow_incoming() -> Weight;
+	fn set_destination_origin_mode() -> Weight;
+	fn set_destination_fee() -> Weight;
+	fn set_sibling_pallet() -> Weight;
+	fn cancel_queued_message() -> Weight;
+	fn restore_failed_transfer() -> Weight;
+	fn parse_nft_transfer_confirmation() -> Weight;
+	fn parse_collection_transfer_confirmation() -> Weight;
 }
  
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -228,4 +241,282 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
+	/// Storage: `XcnftModule::CrossChainProposals` (r:1 w:1)
+	/// Proof: `XcnftModule::CrossChainProposals` (`max_values`: None, `max_size`: Some(96000147), added: 96002622, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalCleanups` (r:1 w:2)
+	/// Proof: `XcnftModule::ProposalCleanups` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalLockedItems` (r:11 w:10)
+	/// Proof: `XcnftModule::ProposalLockedItems` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::Item` (r:10 w:0)
+	/// Proof: `NftModule::Item` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::CollectionRoleOf` (r:1 w:0)
+	/// Proof: `NftModule::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::ItemConfigOf` (r:10 w:10)
+	/// Proof: `NftModule::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalOwners` (r:11 w:10)
+	/// Proof: `XcnftModule::ProposalOwners` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalDelegations` (r:1 w:0)
+	/// Proof: `XcnftModule::ProposalDelegations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalVotes` (r:1 w:0)
+	/// Proof: `XcnftModule::ProposalVotes` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalDelegators` (r:1 w:0)
+	/// Proof: `XcnftModule::ProposalDelegators` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalBeneficiaries` (r:1 w:0)
+	/// Proof: `XcnftModule::ProposalBeneficiaries` (`max_values`: None, `max_size`: Some(672), added: 3147, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalOfCollection` (r:1 w:1)
+	/// Proof: `XcnftModule::ProposalOfCollection` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ActiveProposals` (r:1 w:1)
+	/// Proof: `XcnftModule::ActiveProposals` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::VotableProposals` (r:0 w:10)
+	/// Proof: `XcnftModule::VotableProposals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalItems` (r:11 w:10)
+	/// Proof: `XcnftModule::ProposalItems` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn collection_x_transfer_cancel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2981`
+		//  Estimated: `96002622`
+		// Minimum execution time: 301_000_000 picoseconds.
+		Weight::from_parts(309_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 96002622))
+			.saturating_add(T::DbWeight::get().reads(64))
+			.saturating_add(T::DbWeight::get().writes(56))
+	}
+	/// Storage: `XcnftModule::CrossChainProposals` (r:1 w:1)
+	/// Proof: `XcnftModule::CrossChainProposals` (`max_values`: None, `max_size`: Some(96000147), added: 96002622, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalVotes` (r:1 w:1)
+	/// Proof: `XcnftModule::ProposalVotes` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	fn collection_x_transfer_withdraw_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `713`
+		//  Estimated: `96002622`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 96002622))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `XcnftModule::CrossChainProposals` (r:1 w:1)
+	/// Proof: `XcnftModule::CrossChainProposals` (`max_values`: None, `max_size`: Some(96000147), added: 96002622, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalOwners` (r:2 w:0)
+	/// Proof: `XcnftModule::ProposalOwners` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalDelegations` (r:1 w:1)
+	/// Proof: `XcnftModule::ProposalDelegations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalDelegators` (r:2 w:2)
+	/// Proof: `XcnftModule::ProposalDelegators` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalVotes` (r:3 w:1)
+	/// Proof: `XcnftModule::ProposalVotes` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::VotableProposals` (r:0 w:2)
+	/// Proof: `XcnftModule::VotableProposals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn collection_x_transfer_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1104`
+		//  Estimated: `96002622`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(43_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 96002622))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `XcnftModule::CrossChainProposals` (r:1 w:1)
+	/// Proof: `XcnftModule::CrossChainProposals` (`max_values`: None, `max_size`: Some(96000147), added: 96002622, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::Item` (r:21 w:0)
+	/// Proof: `NftModule::Item` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalOwners` (r:10 w:10)
+	/// Proof: `XcnftModule::ProposalOwners` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `NftModule::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::CollectionRoleOf` (r:1 w:0)
+	/// Proof: `NftModule::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::ItemConfigOf` (r:10 w:10)
+	/// Proof: `NftModule::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::VotableProposals` (r:0 w:10)
+	/// Proof: `XcnftModule::VotableProposals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalLockedItems` (r:0 w:10)
+	/// Proof: `XcnftModule::ProposalLockedItems` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalItems` (r:0 w:10)
+	/// Proof: `XcnftModule::ProposalItems` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn collection_x_transfer_snapshot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4577`
+		//  Estimated: `96002622`
+		// Minimum execution time: 274_000_000 picoseconds.
+		Weight::from_parts(281_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 96002622))
+			.saturating_add(T::DbWeight::get().reads(44))
+			.saturating_add(T::DbWeight::get().writes(51))
+	}
+	/// Storage: `XcnftModule::ProposalCleanups` (r:1 w:0)
+	/// Proof: `XcnftModule::ProposalCleanups` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalLockedItems` (r:11 w:10)
+	/// Proof: `XcnftModule::ProposalLockedItems` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::Item` (r:10 w:0)
+	/// Proof: `NftModule::Item` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::CollectionRoleOf` (r:1 w:0)
+	/// Proof: `NftModule::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::ItemConfigOf` (r:10 w:10)
+	/// Proof: `NftModule::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalOwners` (r:11 w:10)
+	/// Proof: `XcnftModule::ProposalOwners` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalDelegations` (r:1 w:0)
+	/// Proof: `XcnftModule::ProposalDelegations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalVotes` (r:1 w:0)
+	/// Proof: `XcnftModule::ProposalVotes` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalDelegators` (r:1 w:0)
+	/// Proof: `XcnftModule::ProposalDelegators` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalBeneficiaries` (r:1 w:0)
+	/// Proof: `XcnftModule::ProposalBeneficiaries` (`max_values`: None, `max_size`: Some(672), added: 3147, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::VotableProposals` (r:0 w:10)
+	/// Proof: `XcnftModule::VotableProposals` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ProposalItems` (r:11 w:10)
+	/// Proof: `XcnftModule::ProposalItems` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn collection_x_transfer_cleanup() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2874`
+		//  Estimated: `28996`
+		// Minimum execution time: 262_000_000 picoseconds.
+		Weight::from_parts(270_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 28996))
+			.saturating_add(T::DbWeight::get().reads(59))
+			.saturating_add(T::DbWeight::get().writes(50))
+	}
+	/// Storage: `NftModule::Collection` (r:1 w:0)
+	/// Proof: `NftModule::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::IncomingAllowlist` (r:0 w:1)
+	/// Proof: `XcnftModule::IncomingAllowlist` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn collection_x_allow_incoming() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `401`
+		//  Estimated: `3549`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3549))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcnftModule::DestinationOriginModes` (r:0 w:1)
+	/// Proof: `XcnftModule::DestinationOriginModes` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_destination_origin_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcnftModule::DestinationFees` (r:0 w:1)
+	/// Proof: `XcnftModule::DestinationFees` (`max_values`: None, `max_size`: Some(638), added: 3113, mode: `MaxEncodedLen`)
+	fn set_destination_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcnftModule::SiblingPallets` (r:0 w:1)
+	/// Proof: `XcnftModule::SiblingPallets` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_sibling_pallet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcnftModule::OutboundQueue` (r:1 w:1)
+	/// Proof: `XcnftModule::OutboundQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcnftModule::OutboundQueueLen` (r:1 w:1)
+	/// Proof: `XcnftModule::OutboundQueueLen` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::Item` (r:1 w:1)
+	/// Proof: `NftModule::Item` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `NftModule::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::ItemConfigOf` (r:1 w:0)
+	/// Proof: `NftModule::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::Collection` (r:1 w:0)
+	/// Proof: `NftModule::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::Account` (r:0 w:2)
+	/// Proof: `NftModule::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::ItemPriceOf` (r:0 w:1)
+	/// Proof: `NftModule::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::PendingSwapOf` (r:0 w:1)
+	/// Proof: `NftModule::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	fn cancel_queued_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `872`
+		//  Estimated: `4337`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(49_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4337))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `XcnftModule::FailedTransfers` (r:1 w:1)
+	/// Proof: `XcnftModule::FailedTransfers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcnftModule::PendingConfirmations` (r:0 w:1)
+	/// Proof: `XcnftModule::PendingConfirmations` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::SentAssets` (r:1 w:1)
+	/// Proof: `XcnftModule::SentAssets` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ReceivedAssets` (r:0 w:1)
+	/// Proof: `XcnftModule::ReceivedAssets` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::Collection` (r:1 w:1)
+	/// Proof: `NftModule::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::Item` (r:1 w:1)
+	/// Proof: `NftModule::Item` (`max_values`: None, `max_size`: Some(158), added: 2633, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `NftModule::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::CollectionRoleOf` (r:1 w:0)
+	/// Proof: `NftModule::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::ItemConfigOf` (r:1 w:1)
+	/// Proof: `NftModule::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `NftModule::Account` (r:0 w:1)
+	/// Proof: `NftModule::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn restore_failed_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `614`
+		//  Estimated: `4079`
+		// Minimum execution time: 63_000_000 picoseconds.
+		Weight::from_parts(65_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4079))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `XcnftModule::SiblingPallets` (r:1 w:0)
+	/// Proof: `XcnftModule::SiblingPallets` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::InSequencedCall` (r:1 w:0)
+	/// Proof: `XcnftModule::InSequencedCall` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::SentAssets` (r:1 w:1)
+	/// Proof: `XcnftModule::SentAssets` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::PendingConfirmations` (r:1 w:1)
+	/// Proof: `XcnftModule::PendingConfirmations` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn parse_nft_transfer_confirmation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3509`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `XcnftModule::SiblingPallets` (r:1 w:0)
+	/// Proof: `XcnftModule::SiblingPallets` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::InSequencedCall` (r:1 w:0)
+	/// Proof: `XcnftModule::InSequencedCall` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `XcnftModule::ResidualCollections` (r:1 w:1)
+	/// Proof: `XcnftModule::ResidualCollections` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn parse_collection_transfer_confirmation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `248`
+		//  Estimated: `3502`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3502))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -3,16 +3,94 @@
 
 use super::*;
 
+use cumulus_primitives_core::ParaId;
 use frame_benchmarking::v2::*;
-use frame_support::{assert_ok, traits::Currency, BoundedVec};
+use frame_support::{
+	assert_ok,
+	traits::{Currency, EnsureOrigin},
+};
 use frame_system::RawOrigin;
 use pallet_uniques::BenchmarkHelper;
 use sp_runtime::traits::{Bounded, StaticLookup};
 use sp_std::vec;
+use xcm::latest::{Asset, Junction, Location, OriginKind, XcmHash};
 type DepositBalanceOf<T, I> = <<T as pallet_uniques::Config<I>>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
 
+// Creates collection with `items` NFTs, the first owned by the caller and the rest by other
+// accounts, and proposes its cross-chain transfer. Returns the caller and the proposal id.
+fn create_proposal<T: Config<I>, I: 'static>(items: u32) -> (T::AccountId, u64) {
+	let caller: T::AccountId = whitelisted_caller();
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	let collection = T::Helper::collection(0);
+
+	T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+	assert_ok!(pallet_uniques::Pallet::<T, I>::create(
+		RawOrigin::Signed(caller.clone()).into(),
+		collection.clone(),
+		caller_lookup.clone()
+	));
+
+	for i in 0..items {
+		let owner: T::AccountId = if i == 0 { caller.clone() } else { account("owner", i, 1) };
+
+		assert_ok!(pallet_uniques::Pallet::<T, I>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection.clone(),
+			T::Helper::item(i as u16),
+			T::Lookup::unlookup(owner)
+		));
+	}
+
+	let proposal_id = NextProposalId::<T, I>::get();
+	let beneficiary = Pallet::<T, I>::account_to_location(&caller).unwrap();
+
+	assert_ok!(Pallet::<T, I>::collection_x_transfer(
+		RawOrigin::Signed(caller.clone()).into(),
+		collection.clone(),
+		None,
+		1000.into(),
+		None,
+		beneficiary,
+		None,
+	));
+
+	(caller, proposal_id)
+}
+
+// Snapshots remaining pages of the proposal, so that voting can start.
+fn complete_snapshot<T: Config<I>, I: 'static>(caller: &T::AccountId, proposal_id: u64) {
+	while !CrossChainProposals::<T, I>::get(proposal_id).unwrap().snapshot_complete {
+		assert_ok!(Pallet::<T, I>::collection_x_transfer_snapshot(
+			RawOrigin::Signed(caller.clone()).into(),
+			proposal_id
+		));
+	}
+}
+
+// Returns origin of `XcmOrigin` along with its chain, which is registered as xcNFT pallet of
+// sibling chain. Calls are dispatched with it as within sequenced message.
+fn sibling_origin<T: Config<I>, I: 'static>() -> Result<(T::RuntimeOrigin, ParaId), BenchmarkError>
+{
+	let origin = T::XcmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let location =
+		T::XcmOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+
+	let (para, index): (ParaId, u8) = match location.unpack() {
+		(1, [Junction::Parachain(para), Junction::PalletInstance(index), account])
+			if Pallet::<T, I>::location_to_account(&account.clone().into()).is_some() =>
+			((*para).into(), *index),
+		_ => return Err(BenchmarkError::Weightless),
+	};
+
+	SiblingPallets::<T, I>::insert(para, index);
+	InSequencedCall::<T, I>::put(true);
+
+	Ok((origin, para))
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -32,6 +110,8 @@ mod benchmarks {
 			caller_lookup.clone()
 		));
 
+		let beneficiary = Pallet::<T, I>::account_to_location(&caller).unwrap();
+
 		#[extrinsic_call]
 		collection_x_transfer(
 			RawOrigin::Signed(caller.into()),
@@ -39,6 +119,8 @@ mod benchmarks {
 			None,
 			1000.into(),
 			None,
+			beneficiary,
+			None,
 		);
 	}
 
@@ -65,6 +147,8 @@ mod benchmarks {
 			caller_lookup.clone()
 		));
 
+		let beneficiary = Pallet::<T, I>::account_to_location(&caller).unwrap();
+
 		#[extrinsic_call]
 		collection_x_transfer(
 			RawOrigin::Signed(caller.into()),
@@ -72,6 +156,8 @@ mod benchmarks {
 			None,
 			1000.into(),
 			None,
+			beneficiary,
+			None,
 		);
 	}
 
@@ -107,6 +193,8 @@ mod benchmarks {
 			caller_lookup2.clone()
 		));
 
+		let beneficiary = Pallet::<T, I>::account_to_location(&caller).unwrap();
+
 		#[extrinsic_call]
 		collection_x_transfer(
 			RawOrigin::Signed(caller.into()),
@@ -114,6 +202,8 @@ mod benchmarks {
 			None,
 			1000.into(),
 			None,
+			beneficiary,
+			None,
 		);
 	}
 
@@ -139,6 +229,8 @@ mod benchmarks {
 			caller_lookup.clone()
 		));
 
+		let beneficiary = Pallet::<T, I>::account_to_location(&caller).unwrap();
+
 		#[extrinsic_call]
 		nft_x_transfer(
 			RawOrigin::Signed(caller.into()),
@@ -146,7 +238,9 @@ mod benchmarks {
 			item.clone(),
 			1000.into(),
 			collection.clone(),
-			item.clone(),
+			Some(item.clone()),
+			beneficiary,
+			None,
 		);
 	}
 
@@ -158,13 +252,16 @@ mod benchmarks {
 
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 
+		let beneficiary = Pallet::<T, I>::account_to_location(&caller).unwrap();
+
 		#[extrinsic_call]
 		parse_collection_empty(
 			RawOrigin::Signed(caller.into()),
 			collection.clone(),
 			None,
-			BoundedVec::new(),
+			vec![],
 			None,
+			beneficiary,
 		);
 	}
 
@@ -174,19 +271,25 @@ mod benchmarks {
 		let caller: T::AccountId = whitelisted_caller();
 		let collection = T::Helper::collection(0);
 		let item = T::Helper::item(0);
-		let nfts = vec![(item.clone(), BoundedVec::new())];
+		let nfts = vec![(item.clone(), vec![])];
+		let metadata_hashes =
+			vec![Pallet::<T, I>::metadata_hashes(&collection, &item, Some(&[][..]), &[])];
 
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 
+		let beneficiary = Pallet::<T, I>::account_to_location(&caller).unwrap();
+
 		#[extrinsic_call]
 		parse_collection_same_owner(
 			RawOrigin::Signed(caller.into()),
 			None,
-			BoundedVec::new(),
+			vec![],
 			nfts.clone(),
+			metadata_hashes,
 			1000.into(),
 			collection.clone(),
 			None,
+			beneficiary,
 		);
 	}
 
@@ -195,22 +298,28 @@ mod benchmarks {
 	fn parse_diff_owner_col<T: Config<I>, I: 'static>() {
 		let caller: T::AccountId = whitelisted_caller();
 		let caller2: T::AccountId = account("caller2", 1, 1);
-		let caller_lookup2 = T::Lookup::unlookup(caller2.clone());
+		let caller_location2 = Pallet::<T, I>::account_to_location(&caller2).unwrap();
 		let collection = T::Helper::collection(0);
 		let item = T::Helper::item(0);
-		let nfts = vec![(item.clone(), caller_lookup2, BoundedVec::new())];
+		let nfts = vec![(item.clone(), caller_location2, vec![])];
+		let metadata_hashes =
+			vec![Pallet::<T, I>::metadata_hashes(&collection, &item, Some(&[][..]), &[])];
 
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 
+		let beneficiary = Pallet::<T, I>::account_to_location(&caller).unwrap();
+
 		#[extrinsic_call]
 		parse_collection_diff_owners(
 			RawOrigin::Signed(caller.into()),
 			None,
-			BoundedVec::new(),
+			vec![],
 			nfts.clone(),
+			metadata_hashes,
 			1000.into(),
 			collection.clone(),
 			None,
+			beneficiary,
 		);
 	}
 
@@ -230,17 +339,313 @@ mod benchmarks {
 			caller_lookup.clone()
 		));
 
+		let beneficiary = Pallet::<T, I>::account_to_location(&caller).unwrap();
+		let metadata_hashes = Pallet::<T, I>::metadata_hashes(&collection, &item, None, &[]);
+
 		#[extrinsic_call]
 		parse_nft_transfer(
 			RawOrigin::Signed(caller.into()),
 			collection.clone(),
-			item.clone(),
-			BoundedVec::new(),
+			Some(item.clone()),
+			vec![],
+			metadata_hashes,
 			collection.clone(),
 			item.clone(),
 			1000.into(),
+			beneficiary,
 		);
 	}
 
+	//Benchmark tries proposal cancel, which unlocks the first page of NFTs
+	#[benchmark]
+	fn collection_x_transfer_cancel<T: Config<I>, I: 'static>() {
+		let (caller, proposal_id) = create_proposal::<T, I>(T::SnapshotPageSize::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), proposal_id);
+	}
+
+	//Benchmark tries vote withdrawal
+	#[benchmark]
+	fn collection_x_transfer_withdraw_vote<T: Config<I>, I: 'static>() {
+		let (caller, proposal_id) = create_proposal::<T, I>(2);
+		let voter: T::AccountId = account("owner", 1, 1);
+
+		complete_snapshot::<T, I>(&caller, proposal_id);
+
+		assert_ok!(Pallet::<T, I>::collection_x_transfer_vote(
+			RawOrigin::Signed(voter.clone()).into(),
+			proposal_id,
+			Vote::Aye,
+			None
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), proposal_id);
+	}
+
+	//Benchmark tries vote delegation, which replaces previous delegation and direct vote
+	#[benchmark]
+	fn collection_x_transfer_delegate<T: Config<I>, I: 'static>() {
+		let (caller, proposal_id) = create_proposal::<T, I>(2);
+		let voter: T::AccountId = account("owner", 1, 1);
+		let previous: T::AccountId = account("delegate", 0, 1);
+
+		complete_snapshot::<T, I>(&caller, proposal_id);
+
+		assert_ok!(Pallet::<T, I>::collection_x_transfer_vote(
+			RawOrigin::Signed(caller.clone()).into(),
+			proposal_id,
+			Vote::Aye,
+			None
+		));
+		assert_ok!(Pallet::<T, I>::collection_x_transfer_delegate(
+			RawOrigin::Signed(voter.clone()).into(),
+			proposal_id,
+			Some(previous)
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), proposal_id, Some(caller));
+	}
+
+	//Benchmark tries snapshot of a full page of NFTs owned by different accounts
+	#[benchmark]
+	fn collection_x_transfer_snapshot<T: Config<I>, I: 'static>() {
+		let (caller, proposal_id) = create_proposal::<T, I>(2 * T::SnapshotPageSize::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), proposal_id);
+	}
+
+	//Benchmark tries cleanup of a full page of NFTs locked by cancelled proposal
+	#[benchmark]
+	fn collection_x_transfer_cleanup<T: Config<I>, I: 'static>() {
+		let (caller, proposal_id) = create_proposal::<T, I>(2 * T::SnapshotPageSize::get());
+		let collection = T::Helper::collection(0);
+
+		assert_ok!(Pallet::<T, I>::collection_x_transfer_snapshot(
+			RawOrigin::Signed(caller.clone()).into(),
+			proposal_id
+		));
+		assert_ok!(Pallet::<T, I>::collection_x_transfer_cancel(
+			RawOrigin::Signed(caller.clone()).into(),
+			proposal_id
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection);
+	}
+
+	//Benchmark tries allowing NFTs from collection of another chain
+	#[benchmark]
+	fn collection_x_allow_incoming<T: Config<I>, I: 'static>() {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let collection = T::Helper::collection(0);
+
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		assert_ok!(pallet_uniques::Pallet::<T, I>::create(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection.clone(),
+			caller_lookup.clone()
+		));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			collection.clone(),
+			1000.into(),
+			Some(collection.clone()),
+			true,
+		);
+	}
+
+	//Benchmark tries setting origin mode of destination chain
+	#[benchmark]
+	fn set_destination_origin_mode<T: Config<I>, I: 'static>() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 1000.into(), OriginMode::Descend);
+
+		Ok(())
+	}
+
+	//Benchmark tries setting fee asset of destination chain
+	#[benchmark]
+	fn set_destination_fee<T: Config<I>, I: 'static>() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let fee: Asset = (Location::parent(), 1_000_000_000u128).into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 1000.into(), Some(fee));
+
+		Ok(())
+	}
+
+	//Benchmark tries registering xcNFT pallet of sibling chain
+	#[benchmark]
+	fn set_sibling_pallet<T: Config<I>, I: 'static>() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 1000.into(), Some(5));
+
+		Ok(())
+	}
+
+	//Benchmark tries cancel of queued message, which returns the locked NFT to its owner
+	#[benchmark]
+	fn cancel_queued_message<T: Config<I>, I: 'static>() {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let collection = T::Helper::collection(0);
+		let item = T::Helper::item(0);
+		let message_hash: XcmHash = [1; 32];
+
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		assert_ok!(pallet_uniques::Pallet::<T, I>::create(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection.clone(),
+			caller_lookup.clone()
+		));
+		assert_ok!(pallet_uniques::Pallet::<T, I>::mint(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection.clone(),
+			item.clone(),
+			caller_lookup.clone()
+		));
+		assert_ok!(pallet_uniques::Pallet::<T, I>::transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection.clone(),
+			item.clone(),
+			T::Lookup::unlookup(Pallet::<T, I>::account_id())
+		));
+
+		OutboundQueue::<T, I>::insert(
+			message_hash,
+			QueuedMessage {
+				owner: caller.clone(),
+				destination_para_id: 1000.into(),
+				sender: Pallet::<T, I>::account_to_location(&caller).unwrap(),
+				origin_kind: OriginKind::SovereignAccount,
+				call: Call::<T, I>::parse_nft_transfer_confirmation {
+					collection: collection.clone(),
+					item: item.clone(),
+					destination_collection: collection.clone(),
+					destination_item: item.clone(),
+				},
+				deposit: None,
+				attempts: 1,
+				next_attempt: frame_system::Pallet::<T>::block_number(),
+				locked_nft: Some((collection.clone(), item.clone())),
+			},
+		);
+		OutboundQueueLen::<T, I>::put(1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), message_hash);
+	}
+
+	//Benchmark tries restore of NFT received from another chain, whose transfer failed
+	#[benchmark]
+	fn restore_failed_transfer<T: Config<I>, I: 'static>() {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let collection = T::Helper::collection(0);
+		let item = T::Helper::item(0);
+		let message_hash: XcmHash = [1; 32];
+
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+
+		assert_ok!(pallet_uniques::Pallet::<T, I>::create(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection.clone(),
+			caller_lookup.clone()
+		));
+
+		SentAssets::<T, I>::insert(
+			(collection.clone(), item.clone()),
+			SentStruct {
+				origin_para_id: 2000.into(),
+				origin_collection_id: collection.clone(),
+				origin_asset_id: item.clone(),
+				destination_collection_id: collection.clone(),
+				destination_asset_id: item.clone(),
+			},
+		);
+		FailedTransfers::<T, I>::insert(
+			message_hash,
+			PendingTransfer::NFT {
+				owner: caller.clone(),
+				collection: collection.clone(),
+				item: item.clone(),
+				data: vec![],
+			},
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), message_hash);
+	}
+
+	//Benchmark tries confirmation of NFT received by sibling chain
+	#[benchmark]
+	fn parse_nft_transfer_confirmation<T: Config<I>, I: 'static>() -> Result<(), BenchmarkError> {
+		let (origin, para) = sibling_origin::<T, I>()?;
+		let collection = T::Helper::collection(0);
+		let item = T::Helper::item(0);
+
+		SentAssets::<T, I>::insert(
+			(collection.clone(), item.clone()),
+			SentStruct {
+				origin_para_id: 2000.into(),
+				origin_collection_id: collection.clone(),
+				origin_asset_id: item.clone(),
+				destination_collection_id: collection.clone(),
+				destination_asset_id: item.clone(),
+			},
+		);
+		PendingConfirmations::<T, I>::insert((collection.clone(), item.clone()), para);
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			collection.clone(),
+			item.clone(),
+			collection.clone(),
+			item.clone(),
+		);
+
+		Ok(())
+	}
+
+	//Benchmark tries confirmation of collection received by sibling chain
+	#[benchmark]
+	fn parse_collection_transfer_confirmation<T: Config<I>, I: 'static>(
+	) -> Result<(), BenchmarkError> {
+		let (origin, para) = sibling_origin::<T, I>()?;
+		let collection = T::Helper::collection(0);
+
+		ResidualCollections::<T, I>::insert(
+			&collection,
+			ResidualCols {
+				proposal_id: 0,
+				destination_para_id: para,
+				destination_collection_id: None,
+			},
+		);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, collection.clone(), collection.clone());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
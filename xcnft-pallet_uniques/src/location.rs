@@ -0,0 +1,52 @@
+//! Converters between local accounts and XCM locations.
+//!
+//! Runtime selects converters through `Config::AccountToLocation` and
+//! `Config::LocationToAccount`. Location is used as origin of messages sent to other chains and
+//! as beneficiary of transferred collections and NFTs, so chains with 32 byte and 20 byte
+//! accounts can exchange them.
+
+use codec::{Decode, DecodeAll, Encode};
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use sp_runtime::traits::Convert;
+use xcm::latest::prelude::*;
+
+/// Converts 32 byte account into `AccountId32` location and 20 byte account into `AccountKey20`
+/// location, both within `Network`.
+pub struct AccountIdToLocation<AccountId, Network = ()>(PhantomData<(AccountId, Network)>);
+
+impl<AccountId: Encode, Network: Get<Option<NetworkId>>> Convert<AccountId, Option<Location>>
+	for AccountIdToLocation<AccountId, Network>
+{
+	fn convert(who: AccountId) -> Option<Location> {
+		let network = Network::get();
+		let encoded = who.encode();
+
+		match encoded.len() {
+			32 => Some(Junction::AccountId32 { network, id: encoded.try_into().ok()? }.into()),
+			20 => Some(Junction::AccountKey20 { network, key: encoded.try_into().ok()? }.into()),
+			_ => None,
+		}
+	}
+}
+
+/// Converts `AccountId32` or `AccountKey20` location into account. Location without network or
+/// within `Network` is accepted.
+pub struct LocationToAccountId<AccountId, Network = ()>(PhantomData<(AccountId, Network)>);
+
+impl<AccountId: Decode, Network: Get<Option<NetworkId>>> Convert<Location, Option<AccountId>>
+	for LocationToAccountId<AccountId, Network>
+{
+	fn convert(location: Location) -> Option<AccountId> {
+		let in_network =
+			|network: &Option<NetworkId>| network.is_none() || *network == Network::get();
+
+		match location.unpack() {
+			(0, [Junction::AccountId32 { network, id }]) if in_network(network) =>
+				AccountId::decode_all(&mut &id[..]).ok(),
+			(0, [Junction::AccountKey20 { network, key }]) if in_network(network) =>
+				AccountId::decode_all(&mut &key[..]).ok(),
+			_ => None,
+		}
+	}
+}
@@ -1,10 +1,12 @@
-use frame_support::{parameter_types, traits::VariantCountOf, weights::constants::RocksDbWeight};
+use frame_support::{
+	parameter_types, traits::VariantCountOf, weights::constants::RocksDbWeight, PalletId,
+};
 use frame_system::{mocking::MockBlock, EnsureRoot, EnsureSigned, GenesisConfig};
 use pallet_balances::AccountData;
 use sp_core::ConstU32;
 use sp_runtime::{
 	traits::{ConstU64, Verify},
-	AccountId32 as AccountId, BuildStorage, MultiSignature,
+	AccountId32 as AccountId, BuildStorage, MultiSignature, Perbill,
 };
 use xcm_builder::WithUniqueTopic;
 
@@ -84,10 +86,66 @@ impl parachain_info::Config for Test {}
 impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = crate::weights::SubstrateWeight<Test>;
+	type MaxTransactWeight = max_transact_weight_parameter;
+	type XcmInstructionWeight = xcm_instruction_weight_parameter;
 	type XcmSender = XcmRouter;
 	type RuntimeCall = RuntimeCall;
 	type ProposalTimeInBlocks = proposal_time_in_blocks_parameter;
-	type MaxOwners = max_owners_parameter;
+	type QueryHandler = NoQueries;
+	type MessageTimeoutInBlocks = message_timeout_in_blocks_parameter;
+	type MaxFinalizedMessages = max_finalized_messages_parameter;
+	type MaxQueuedMessages = max_queued_messages_parameter;
+	type MaxSendAttempts = max_send_attempts_parameter;
+	type RetryBackoffInBlocks = retry_backoff_in_blocks_parameter;
+	type SnapshotPageSize = snapshot_page_size_parameter;
+	type VotingStrategy = crate::voting::SimpleMajority;
+	type ProposalMigrationMode = migration_mode_parameter;
+	type MetadataPolicy = metadata_policy_parameter;
+	type MaxOverflowMetadata = max_overflow_metadata_parameter;
+	type ProposalDeposit = proposal_deposit_parameter;
+	type ProposalDepositSlash = proposal_deposit_slash_parameter;
+	type MaxActiveProposals = max_active_proposals_parameter;
+	type PalletId = pallet_id_parameter;
+	type ItemIdConverter = sp_runtime::traits::ConvertInto;
+	type CollectionIdConverter = sp_runtime::traits::ConvertInto;
+	type AccountToLocation = crate::location::AccountIdToLocation<AccountId>;
+	type LocationToAccount = crate::location::LocationToAccountId<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type XcmExecutor = ();
+	type UniversalLocation = universal_location_parameter;
+	type XcmOrigin = frame_support::traits::NeverEnsureOrigin<xcm::latest::Location>;
+}
+
+/// Query handler of mock runtime, which never receives status reports of sent messages.
+pub struct NoQueries;
+
+impl xcm_executor::traits::QueryHandler for NoQueries {
+	type BlockNumber = u64;
+	type Error = ();
+	type UniversalLocation = universal_location_parameter;
+
+	fn new_query(
+		_responder: impl Into<xcm::latest::Location>,
+		_timeout: u64,
+		_match_querier: impl Into<xcm::latest::Location>,
+	) -> xcm::latest::QueryId {
+		0
+	}
+
+	fn report_outcome(
+		_message: &mut xcm::latest::Xcm<()>,
+		_responder: impl Into<xcm::latest::Location>,
+		_timeout: u64,
+	) -> Result<xcm::latest::QueryId, ()> {
+		Ok(0)
+	}
+
+	fn take_response(_id: xcm::latest::QueryId) -> xcm_executor::traits::QueryResponseStatus<u64> {
+		xcm_executor::traits::QueryResponseStatus::NotFound
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn expect_response(_id: xcm::latest::QueryId, _response: xcm::latest::Response) {}
 }
 
 pub const UNIT: Balance = 1;
@@ -128,8 +186,26 @@ parameter_types! {
 	pub const MaxDeadlineDuration: u32 = 1;
 	pub const MaxAttributesPerCall: u32 = 10;
 	pub const proposal_time_in_blocks_parameter: u32 = 10;
-	pub const max_owners_parameter: u32 = 1000000;
+	pub const message_timeout_in_blocks_parameter: u32 = 10;
+	pub const max_finalized_messages_parameter: u32 = 16;
+	pub const max_queued_messages_parameter: u32 = 16;
+	pub const max_send_attempts_parameter: u32 = 3;
+	pub const retry_backoff_in_blocks_parameter: u32 = 2;
+	pub const snapshot_page_size_parameter: u32 = 10;
+	pub const proposal_deposit_parameter: Balance = 100 * UNIT;
+	pub const proposal_deposit_slash_parameter: Perbill = Perbill::from_percent(50);
+	pub const max_active_proposals_parameter: u32 = 2;
+	pub const max_transact_weight_parameter: frame_support::weights::Weight =
+		frame_support::weights::Weight::from_parts(1_000_000_000_000, 5 * 1024 * 1024);
+	pub const xcm_instruction_weight_parameter: frame_support::weights::Weight =
+		frame_support::weights::Weight::from_parts(1_000_000, 1_000);
+	pub const pallet_id_parameter: PalletId = PalletId(*b"py/xcnft");
+	pub universal_location_parameter: xcm::latest::InteriorLocation =
+		xcm::latest::Junction::Parachain(1000).into();
 	pub const max_votes: u32 = 1000000;
+	pub const migration_mode_parameter: crate::MigrationMode = crate::MigrationMode::BurnAll;
+	pub const metadata_policy_parameter: crate::MetadataPolicy = crate::MetadataPolicy::Reject;
+	pub const max_overflow_metadata_parameter: u32 = 64;
 }
 
 pub type AccountPublic = <MultiSignature as Verify>::Signer;
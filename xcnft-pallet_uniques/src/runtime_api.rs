@@ -0,0 +1,22 @@
+//! Runtime API of the xcNFT pallet.
+//!
+//! Lets clients check whether metadata of NFT received from another chain was altered in transit
+//! or on this chain since it arrived, by comparing hashes computed on origin chain with hashes
+//! of metadata on arrival and hashes of current metadata.
+
+use crate::MetadataIntegrity;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// API to query metadata integrity of received NFTs.
+	pub trait XcnftApi<CollectionId, ItemId>
+	where
+		CollectionId: Codec,
+		ItemId: Codec,
+	{
+		/// Returns metadata hashes `item` of `collection` was transferred with and hashes of its
+		/// metadata on arrival, along with hashes of its current metadata, `None` if the NFT was
+		/// not received from another chain.
+		fn metadata_integrity(collection: CollectionId, item: ItemId) -> Option<MetadataIntegrity>;
+	}
+}
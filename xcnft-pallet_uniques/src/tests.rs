@@ -1,15 +1,19 @@
 use crate::{
-	mock::*, Error, Event, GeneralizedDestroyWitness, Proposal, ReceivedAssets,
-	ReceivedCollections, ReceivedCols, ReceivedStruct, SentAssets, SentStruct,
+	mock::*, Error, Event, GeneralizedDestroyWitness, MessageStatus, MetadataHashes,
+	MetadataIntegrity, Operation, OriginMode, Proposal, ReceivedAssets, ReceivedCollections,
+	ReceivedCols, ReceivedHashes, ReceivedStruct, SentAssets, SentStruct,
 };
 
 pub mod testpara;
 pub mod testrelay;
 
 use cumulus_primitives_core::Parachain;
-use frame_support::assert_noop;
+use frame_support::{assert_noop, dispatch::DispatchResultWithPostInfo};
 use pallet_uniques;
-use sp_runtime::{AccountId32, BoundedVec, BuildStorage};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	AccountId32, BoundedVec, BuildStorage,
+};
 use xcm::prelude::*;
 use xcm_executor::traits::ConvertLocation;
 
@@ -30,6 +34,97 @@ pub fn child_account_id(para: u32) -> testrelay::AccountId {
 	testrelay::location_converter::LocationConverter::convert_location(&location.into()).unwrap()
 }
 
+pub fn account_location(account: AccountId32) -> Location {
+	Junction::AccountId32 { network: None, id: account.into() }.into()
+}
+
+/// Index of xcNFT pallet in runtime of test chains.
+pub const XCNFT_PALLET_INDEX: u8 = 5;
+
+/// Returns origin of call descended from pallet at `index` of `para` on behalf of `account`.
+pub fn pallet_origin(para: u32, index: u8, account: AccountId32) -> testpara::RuntimeOrigin {
+	pallet_xcm::Origin::Xcm(Location::new(
+		1,
+		[
+			Parachain(para),
+			PalletInstance(index),
+			Junction::AccountId32 { network: None, id: account.into() },
+		],
+	))
+	.into()
+}
+
+/// Returns origin of call descended from xcNFT pallet of `para` on behalf of `account`.
+pub fn xcnft_origin(para: u32, account: AccountId32) -> testpara::RuntimeOrigin {
+	pallet_origin(para, XCNFT_PALLET_INDEX, account)
+}
+
+/// Dispatches `call` sent by `account` from xcNFT pallet of `para`, wrapped in `parse_sequenced`
+/// with next nonce of the account, which is also used as index of the message.
+pub fn sequenced_call(
+	para: u32,
+	account: AccountId32,
+	call: crate::Call<testpara::Runtime>,
+) -> DispatchResultWithPostInfo {
+	let nonce = crate::InboundNonces::<testpara::Runtime>::get(
+		cumulus_primitives_core::ParaId::from(para),
+		&account,
+	)
+	.next;
+
+	testpara::XcNFT::parse_sequenced(
+		xcnft_origin(para, account),
+		para.into(),
+		nonce,
+		nonce,
+		Box::new(call),
+	)
+}
+
+pub fn last_message_hash() -> XcmHash {
+	let count = crate::OutboundMessageCount::<testpara::Runtime>::get();
+	crate::Pallet::<testpara::Runtime>::message_id(count - 1)
+}
+
+/// Returns metadata hashes sent along with NFT without attributes transferred without its
+/// collection.
+pub fn metadata_hashes(data: &[u8]) -> MetadataHashes {
+	MetadataHashes {
+		collection: None,
+		item: BlakeTwo256::hash(data),
+		attributes: BlakeTwo256::hash_of(&Vec::<()>::new()),
+	}
+}
+
+/// Returns metadata hashes sent along with NFT without attributes transferred with its
+/// collection.
+pub fn collection_metadata_hashes(collection_metadata: &[u8], data: &[u8]) -> MetadataHashes {
+	MetadataHashes {
+		collection: Some(BlakeTwo256::hash(collection_metadata)),
+		..metadata_hashes(data)
+	}
+}
+
+/// Delivers status report of sent message from its destination chain.
+pub fn report_message_status(message_hash: XcmHash, result: MaybeErrorCode) {
+	use frame_support::weights::Weight;
+	use xcm_executor::traits::OnResponse;
+
+	let message = crate::OutboundMessages::<testpara::Runtime>::get(message_hash).unwrap();
+	let destination = Location::new(1, [Parachain(message.destination_para_id.into())]);
+
+	// Destination reports from the sender aliased on it
+	let querier = destination.clone().appended_with(message.sender).unwrap();
+	testpara::PolkadotXcm::on_response(
+		&destination,
+		message.query_id,
+		Some(&querier),
+		Response::DispatchResult(result),
+		Weight::MAX,
+		&XcmContext::with_message_id(message_hash),
+	);
+}
+
 decl_test_parachain! {
 	pub struct ParaA {
 		Runtime = testpara::Runtime,
@@ -86,6 +181,14 @@ pub fn para_ext(para_id: u32) -> sp_io::TestExternalities {
 		sp_tracing::try_init_simple();
 		System::set_block_number(1);
 		MsgQueue::set_para_id(para_id.into());
+
+		// Calls of sibling chains are verified only from their xcNFT pallet
+		for sibling in [1000, 2000] {
+			crate::SiblingPallets::<Runtime>::insert(
+				cumulus_primitives_core::ParaId::from(sibling),
+				XCNFT_PALLET_INDEX,
+			);
+		}
 	});
 	ext
 }
@@ -126,12 +229,15 @@ fn try_sending_collection_empty_success() {
 			Some(COLLECTION_ID),
 			2000.into(),
 			None,
+			account_location(ALICE),
+			None,
 		);
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
 			Event::CollectionTransferred {
+				message_hash: last_message_hash(),
 				origin_collection_id: 0,
-				origin_collection_metadata: BoundedVec::new(),
+				origin_collection_metadata: Vec::new(),
 				destination_para_id: 2000.into(),
 			},
 		));
@@ -154,12 +260,15 @@ fn try_sending_collection_same_owner_success() {
 			Some(COLLECTION_ID),
 			2000.into(),
 			None,
+			account_location(ALICE),
+			None,
 		);
 
 		let nft_ids = vec![0, 1];
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
 			Event::CollectionAndNFTsTransferred {
+				message_hash: last_message_hash(),
 				origin_collection_id: 0,
 				nft_ids,
 				destination_para_id: 2000.into(),
@@ -184,6 +293,8 @@ fn try_sending_collection_different_owners_success() {
 			Some(COLLECTION_ID),
 			2000.into(),
 			None,
+			account_location(ALICE),
+			None,
 		);
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
@@ -198,233 +309,251 @@ fn try_sending_collection_different_owners_success() {
 }
 
 #[test]
-fn try_sending_collection_that_user_doesnt_own() {
+fn try_sending_collection_with_active_proposal() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(1);
 		const COLLECTION_ID: u32 = 1;
 
 		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
 
 		assert_noop!(
 			testpara::XcNFT::collection_x_transfer(
-				testpara::RuntimeOrigin::signed(BOB),
+				testpara::RuntimeOrigin::signed(ALICE),
 				0,
 				Some(COLLECTION_ID),
 				2000.into(),
+				None,
+				account_location(ALICE),
 				None
 			),
-			Error::<Test>::NotCollectionOwner
+			Error::<Test>::ProposalAlreadyExists
 		);
 	});
 }
 
 #[test]
-fn try_voting_on_non_existing_proposal() {
+fn proposal_indexes_updated_successfuly() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
 
-		assert_noop!(
-			testpara::XcNFT::collection_x_transfer_vote(
-				testpara::RuntimeOrigin::signed(ALICE),
-				0,
-				crate::Vote::Aye
-			),
-			Error::<Test>::ProposalDoesNotExist
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		// Proposal is indexed by collection and by both owners
+		assert_eq!(crate::ProposalOfCollection::<testpara::Runtime>::get(0), Some(0));
+		assert!(crate::VotableProposals::<testpara::Runtime>::contains_key(ALICE, 0));
+		assert!(crate::VotableProposals::<testpara::Runtime>::contains_key(BOB, 0));
+
+		// Vote after the proposal ended, which removes it
+		testpara::System::set_block_number(12);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
 		);
+
+		assert_eq!(crate::ProposalOfCollection::<testpara::Runtime>::get(0), None);
+		assert!(!crate::VotableProposals::<testpara::Runtime>::contains_key(ALICE, 0));
+		assert!(!crate::VotableProposals::<testpara::Runtime>::contains_key(BOB, 0));
 	});
 }
 
 #[test]
-fn try_voting_on_proposal_when_no_owner() {
+fn proposal_locks_items_until_it_ends() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(1);
 		const COLLECTION_ID: u32 = 1;
 
-		//Create proposal
-		let proposal = Proposal::<Test> {
-			proposal_id: 1,
-			collection_id: COLLECTION_ID,
-			proposed_collection_owner: ALICE,
-			proposed_destination_para: 2000.into(),
-			proposed_dest_collection_id: None,
-			proposed_destination_config: None,
-			owners: BoundedVec::new(),
-			number_of_votes: crate::Votes { aye: BoundedVec::new(), nay: BoundedVec::new() },
-			end_time: 20u64.into(),
-		};
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
 
-		let _ = crate::CrossChainProposals::insert(1, proposal);
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		assert!(crate::ProposalLockedItems::<testpara::Runtime>::contains_key(0, 1));
+
+		// Items can not be transferred while the proposal is open
+		assert!(
+			testpara::NFTs::transfer(testpara::RuntimeOrigin::signed(BOB), 0, 1, ALICE).is_err()
+		);
+		assert_eq!(testpara::NFTs::owner(0, 1), Some(BOB));
 
 		assert_noop!(
-			testpara::XcNFT::collection_x_transfer_vote(
+			testpara::XcNFT::nft_x_transfer(
 				testpara::RuntimeOrigin::signed(BOB),
+				0,
 				1,
-				crate::Vote::Aye
+				2000.into(),
+				COLLECTION_ID,
+				Some(1),
+				account_location(BOB),
+				None
 			),
-			Error::<Test>::NotNFTOwner
+			Error::<Test>::CollectionUnderProposal
 		);
-	});
-}
-
-#[test]
-fn try_voting_on_proposal_expired() {
-	ParaA::execute_with(|| {
-		testpara::System::set_block_number(3);
-		const COLLECTION_ID: u32 = 1;
-
-		//Create owners vector
-		let mut owners = BoundedVec::new();
-		owners.try_push(ALICE).expect("Failed to push owner");
-
-		//Create proposal
-		let proposal = Proposal::<Test> {
-			proposal_id: 1,
-			collection_id: COLLECTION_ID,
-			proposed_collection_owner: ALICE,
-			proposed_destination_para: 2000.into(),
-			proposed_dest_collection_id: None,
-			proposed_destination_config: None,
-			owners: owners.clone(),
-			number_of_votes: crate::Votes { aye: owners.clone(), nay: BoundedVec::new() },
-			end_time: 1u64.into(),
-		};
 
-		let _ = crate::CrossChainProposals::insert(1, proposal);
+		// Vote after the proposal ended, which removes it and unlocks the items
+		testpara::System::set_block_number(12);
 
 		let _ = testpara::XcNFT::collection_x_transfer_vote(
 			testpara::RuntimeOrigin::signed(ALICE),
-			1,
+			0,
 			crate::Vote::Aye,
+			None,
 		);
 
-		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::ProposalExpired {
-			proposal_id: 1,
-		}));
+		assert!(!crate::ProposalLockedItems::<testpara::Runtime>::contains_key(0, 1));
+
+		let _ = testpara::NFTs::transfer(testpara::RuntimeOrigin::signed(BOB), 0, 1, ALICE);
+		assert_eq!(testpara::NFTs::owner(0, 1), Some(ALICE));
 	});
 }
 
 #[test]
-fn try_voting_on_proposal_did_not_pass() {
+fn proposal_snapshot_in_pages() {
 	ParaA::execute_with(|| {
-		testpara::System::set_block_number(3);
+		testpara::System::set_block_number(1);
 		const COLLECTION_ID: u32 = 1;
 
-		//Create owners vector
-		let mut owners = BoundedVec::new();
-		owners.try_push(ALICE).expect("Failed to push owner");
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		// Mint more items than fit into a single snapshot page
+		for item in 0..12 {
+			let owner = if item < 9 { ALICE } else { BOB };
+			let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, item, owner);
+		}
 
-		//Create proposal
-		let proposal = Proposal::<Test> {
-			proposal_id: 1,
-			collection_id: COLLECTION_ID,
-			proposed_collection_owner: ALICE,
-			proposed_destination_para: 2000.into(),
-			proposed_dest_collection_id: None,
-			proposed_destination_config: None,
-			owners: owners.clone(),
-			number_of_votes: crate::Votes { aye: BoundedVec::new(), nay: owners.clone() },
-			end_time: 1u64.into(),
-		};
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
 
-		let _ = crate::CrossChainProposals::insert(1, proposal);
+		let proposal = crate::CrossChainProposals::<testpara::Runtime>::get(0).unwrap();
+		assert!(!proposal.snapshot_complete);
+		assert_eq!(proposal.total_weight, 10);
 
-		let _ = testpara::XcNFT::collection_x_transfer_vote(
-			testpara::RuntimeOrigin::signed(ALICE),
-			1,
-			crate::Vote::Aye,
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_vote(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				crate::Vote::Aye,
+				None,
+			),
+			Error::<Test>::SnapshotIncomplete
+		);
+
+		// Any signed account can advance the snapshot
+		let _ = testpara::XcNFT::collection_x_transfer_snapshot(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
 		);
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
-			Event::ProposalDidNotPass { proposal_id: 1 },
+			Event::ProposalSnapshotUpdated { proposal_id: 0, complete: true },
 		));
-	});
-}
-
-#[test]
-fn try_voting_on_proposal_again_same_vote() {
-	ParaA::execute_with(|| {
-		testpara::System::set_block_number(1);
-		const COLLECTION_ID: u32 = 1;
 
-		//Create owners vector
-		let mut owners = BoundedVec::new();
-		owners.try_push(ALICE).expect("Failed to push owner");
-
-		//Create proposal
-		let proposal = Proposal::<Test> {
-			proposal_id: 1,
-			collection_id: COLLECTION_ID,
-			proposed_collection_owner: ALICE,
-			proposed_destination_para: 2000.into(),
-			proposed_dest_collection_id: None,
-			proposed_destination_config: None,
-			owners: owners.clone(),
-			number_of_votes: crate::Votes { aye: BoundedVec::new(), nay: owners.clone() },
-			end_time: 3u64.into(),
-		};
+		let proposal = crate::CrossChainProposals::<testpara::Runtime>::get(0).unwrap();
+		assert!(proposal.snapshot_complete);
+		assert_eq!(proposal.total_weight, 12);
+		assert_eq!(crate::ProposalOwners::<testpara::Runtime>::get(0, ALICE), Some(9));
+		assert_eq!(crate::ProposalOwners::<testpara::Runtime>::get(0, BOB), Some(3));
 
-		let _ = crate::CrossChainProposals::insert(1, proposal);
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_snapshot(
+				testpara::RuntimeOrigin::signed(BOB),
+				0,
+			),
+			Error::<Test>::SnapshotComplete
+		);
 
 		let _ = testpara::XcNFT::collection_x_transfer_vote(
-			testpara::RuntimeOrigin::signed(ALICE),
-			1,
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
 			crate::Vote::Aye,
+			None,
 		);
 
-		assert_noop!(
-			testpara::XcNFT::collection_x_transfer_vote(
-				testpara::RuntimeOrigin::signed(ALICE),
-				1,
-				crate::Vote::Aye
-			),
-			Error::<Test>::AlreadyVotedThis
+		assert_eq!(
+			crate::ProposalVotes::<testpara::Runtime>::get(0, BOB),
+			Some((crate::Vote::Aye, 3))
 		);
 	});
 }
 
 #[test]
-fn vote_on_proposal_successfuly() {
+fn proposal_deposit_returned_on_cancel() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(1);
 		const COLLECTION_ID: u32 = 1;
 
-		//Create owners vector
-		let mut owners = BoundedVec::new();
-		owners.try_push(ALICE).expect("Failed to push owner");
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
 
-		//Create proposal
-		let proposal = Proposal::<Test> {
-			proposal_id: 1,
-			collection_id: COLLECTION_ID,
-			proposed_collection_owner: ALICE,
-			proposed_destination_para: 2000.into(),
-			proposed_dest_collection_id: None,
-			proposed_destination_config: None,
-			owners: owners.clone(),
-			number_of_votes: crate::Votes { aye: BoundedVec::new(), nay: owners.clone() },
-			end_time: 2u64.into(),
-		};
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
 
-		let _ = crate::CrossChainProposals::insert(1, proposal);
+		assert_eq!(testpara::Balances::reserved_balance(ALICE), 100);
+		assert_eq!(crate::ActiveProposals::<testpara::Runtime>::get(ALICE), Some(1));
 
-		let _ = testpara::XcNFT::collection_x_transfer_vote(
+		let _ = testpara::XcNFT::collection_x_transfer_cancel(
 			testpara::RuntimeOrigin::signed(ALICE),
-			1,
-			crate::Vote::Aye,
+			0,
 		);
 
-		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
-			Event::CrossChainPropoposalVoteRegistered {
-				proposal_id: 1,
-				voter: ALICE,
-				vote: crate::Vote::Aye,
-			},
-		));
+		assert_eq!(testpara::Balances::reserved_balance(ALICE), 0);
+		assert_eq!(testpara::Balances::free_balance(ALICE), INITIAL_BALANCE);
+		assert_eq!(crate::ActiveProposals::<testpara::Runtime>::get(ALICE), None);
 	});
 }
 
 #[test]
-fn initiate_proposal_successfuly() {
+fn proposal_deposit_slashed_when_not_passed() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(1);
 		const COLLECTION_ID: u32 = 1;
@@ -439,154 +568,3536 @@ fn initiate_proposal_successfuly() {
 			Some(COLLECTION_ID),
 			2000.into(),
 			None,
+			account_location(ALICE),
+			None,
 		);
 
 		let _ = testpara::XcNFT::collection_x_transfer_vote(
-			testpara::RuntimeOrigin::signed(ALICE),
+			testpara::RuntimeOrigin::signed(BOB),
 			0,
-			crate::Vote::Aye,
+			crate::Vote::Nay,
+			None,
 		);
 
-		testpara::System::set_block_number(11);
+		testpara::System::set_block_number(12);
 
 		let _ = testpara::XcNFT::collection_x_transfer_initiate(
 			testpara::RuntimeOrigin::signed(ALICE),
 			0,
 		);
 
-		let nfts = vec![(0, ALICE, BoundedVec::new()), (1, BOB, BoundedVec::new())];
-
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
-			Event::CollectionAndNFTsDiffTransferred {
-				origin_collection_id: 0,
-				nfts,
-				destination_para_id: 2000.into(),
-				to_address: ALICE,
-			},
+			Event::ProposalDepositSlashed { proposal_id: 0, proposer: ALICE, amount: 50 },
 		));
+
+		assert_eq!(testpara::Balances::reserved_balance(ALICE), 0);
+		assert_eq!(testpara::Balances::free_balance(ALICE), INITIAL_BALANCE - 50);
 	});
 }
 
 #[test]
-fn try_initiating_proposal_doesnt_exist() {
+fn try_creating_too_many_proposals() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		// Create collections, each with NFT owned by other account
+		for collection in 0..3 {
+			let _ =
+				testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), collection, ALICE);
+			let _ =
+				testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), collection, 0, BOB);
+		}
+
+		for collection in 0..2 {
+			let _ = testpara::XcNFT::collection_x_transfer(
+				testpara::RuntimeOrigin::signed(ALICE),
+				collection,
+				Some(COLLECTION_ID),
+				2000.into(),
+				None,
+				account_location(ALICE),
+				None,
+			);
+		}
 
 		assert_noop!(
-			testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::XcNFT::collection_x_transfer(
 				testpara::RuntimeOrigin::signed(ALICE),
-				1
+				2,
+				Some(COLLECTION_ID),
+				2000.into(),
+				None,
+				account_location(ALICE),
+				None,
 			),
-			Error::<Test>::ProposalDoesNotExist
+			Error::<Test>::TooManyActiveProposals
 		);
 	});
 }
 
 #[test]
-fn try_initiating_proposal_collection_doesnt_exist() {
+fn try_sending_collection_that_user_doesnt_own() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(1);
-
 		const COLLECTION_ID: u32 = 1;
 
-		//Create owners vector
-		let mut owners = BoundedVec::new();
-		owners.try_push(ALICE).expect("Failed to push owner");
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer(
+				testpara::RuntimeOrigin::signed(BOB),
+				0,
+				Some(COLLECTION_ID),
+				2000.into(),
+				None,
+				account_location(BOB),
+				None
+			),
+			Error::<Test>::NotCollectionOwner
+		);
+	});
+}
+
+#[test]
+fn try_voting_on_non_existing_proposal() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_vote(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				crate::Vote::Aye,
+				None
+			),
+			Error::<Test>::ProposalDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn try_voting_on_proposal_when_no_owner() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
 
 		//Create proposal
 		let proposal = Proposal::<Test> {
 			proposal_id: 1,
 			collection_id: COLLECTION_ID,
 			proposed_collection_owner: ALICE,
+			proposed_beneficiary: account_location(ALICE),
+			proposed_deposit_asset: None,
 			proposed_destination_para: 2000.into(),
 			proposed_dest_collection_id: None,
 			proposed_destination_config: None,
-			owners: owners.clone(),
-			number_of_votes: crate::Votes { aye: BoundedVec::new(), nay: owners.clone() },
-			end_time: 2u64.into(),
+			total_weight: 0,
+			aye_weight: 0,
+			nay_weight: 0,
+			snapshot_cursor: None,
+			snapshot_complete: true,
+			voting_rule: crate::voting::VotingRule::SimpleMajority,
+			migration_mode: crate::MigrationMode::BurnAll,
+			deposit: 0,
+			end_time: 20u64.into(),
 		};
 
 		let _ = crate::CrossChainProposals::insert(1, proposal);
 
 		assert_noop!(
-			testpara::XcNFT::collection_x_transfer_initiate(
-				testpara::RuntimeOrigin::signed(ALICE),
-				1
+			testpara::XcNFT::collection_x_transfer_vote(
+				testpara::RuntimeOrigin::signed(BOB),
+				1,
+				crate::Vote::Aye,
+				None
 			),
-			Error::<Test>::CollectionDoesNotExist
+			Error::<Test>::NotNFTOwner
 		);
 	});
 }
 
 #[test]
-fn try_initiating_proposal_no_collection_owner() {
+fn try_voting_on_proposal_expired() {
 	ParaA::execute_with(|| {
-		testpara::System::set_block_number(2);
+		testpara::System::set_block_number(3);
+		const COLLECTION_ID: u32 = 1;
 
-		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(BOB), 0, ALICE);
+		// Create collection with NFT held by the voter
+		let _ =
+			testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), COLLECTION_ID, ALICE);
+		let _ =
+			testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), COLLECTION_ID, 0, ALICE);
+
+		//Create proposal
+		let proposal = Proposal::<Test> {
+			proposal_id: 1,
+			collection_id: COLLECTION_ID,
+			proposed_collection_owner: ALICE,
+			proposed_beneficiary: account_location(ALICE),
+			proposed_deposit_asset: None,
+			proposed_destination_para: 2000.into(),
+			proposed_dest_collection_id: None,
+			proposed_destination_config: None,
+			total_weight: 1,
+			aye_weight: 1,
+			nay_weight: 0,
+			snapshot_cursor: None,
+			snapshot_complete: true,
+			voting_rule: crate::voting::VotingRule::SimpleMajority,
+			migration_mode: crate::MigrationMode::BurnAll,
+			deposit: 0,
+			end_time: 1u64.into(),
+		};
+
+		let _ = crate::CrossChainProposals::insert(1, proposal);
+
+		// Insert owners of the proposal and their votes
+		crate::ProposalOwners::<Test>::insert(1, ALICE, 1);
+		crate::ProposalVotes::<Test>::insert(1, ALICE, (crate::Vote::Aye, 1));
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			1,
+			crate::Vote::Aye,
+			None,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::ProposalExpired {
+			proposal_id: 1,
+		}));
+	});
+}
 
-		//Create owners vector
-		let mut owners = BoundedVec::new();
-		owners.try_push(BOB).expect("Failed to push owner");
+#[test]
+fn try_voting_on_proposal_did_not_pass() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(3);
+		const COLLECTION_ID: u32 = 1;
 
 		//Create proposal
 		let proposal = Proposal::<Test> {
 			proposal_id: 1,
-			collection_id: 0,
-			proposed_collection_owner: BOB,
+			collection_id: COLLECTION_ID,
+			proposed_collection_owner: ALICE,
+			proposed_beneficiary: account_location(ALICE),
+			proposed_deposit_asset: None,
 			proposed_destination_para: 2000.into(),
 			proposed_dest_collection_id: None,
 			proposed_destination_config: None,
-			owners: owners.clone(),
-			number_of_votes: crate::Votes { aye: BoundedVec::new(), nay: owners.clone() },
+			total_weight: 1,
+			aye_weight: 0,
+			nay_weight: 1,
+			snapshot_cursor: None,
+			snapshot_complete: true,
+			voting_rule: crate::voting::VotingRule::SimpleMajority,
+			migration_mode: crate::MigrationMode::BurnAll,
+			deposit: 0,
 			end_time: 1u64.into(),
 		};
 
 		let _ = crate::CrossChainProposals::insert(1, proposal);
 
+		// Insert owners of the proposal and their votes
+		crate::ProposalOwners::<Test>::insert(1, ALICE, 1);
+		crate::ProposalVotes::<Test>::insert(1, ALICE, (crate::Vote::Nay, 1));
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			1,
+			crate::Vote::Aye,
+			None,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::ProposalDidNotPass { proposal_id: 1 },
+		));
+	});
+}
+
+#[test]
+fn try_voting_on_proposal_again_same_vote() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		// Create collection with NFT held by the voter
+		let _ =
+			testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), COLLECTION_ID, ALICE);
+		let _ =
+			testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), COLLECTION_ID, 0, ALICE);
+
+		//Create proposal
+		let proposal = Proposal::<Test> {
+			proposal_id: 1,
+			collection_id: COLLECTION_ID,
+			proposed_collection_owner: ALICE,
+			proposed_beneficiary: account_location(ALICE),
+			proposed_deposit_asset: None,
+			proposed_destination_para: 2000.into(),
+			proposed_dest_collection_id: None,
+			proposed_destination_config: None,
+			total_weight: 1,
+			aye_weight: 0,
+			nay_weight: 1,
+			snapshot_cursor: None,
+			snapshot_complete: true,
+			voting_rule: crate::voting::VotingRule::SimpleMajority,
+			migration_mode: crate::MigrationMode::BurnAll,
+			deposit: 0,
+			end_time: 3u64.into(),
+		};
+
+		let _ = crate::CrossChainProposals::insert(1, proposal);
+
+		// Insert owners of the proposal and their votes
+		crate::ProposalOwners::<Test>::insert(1, ALICE, 1);
+		crate::ProposalVotes::<Test>::insert(1, ALICE, (crate::Vote::Nay, 1));
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			1,
+			crate::Vote::Aye,
+			None,
+		);
+
 		assert_noop!(
-			testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::XcNFT::collection_x_transfer_vote(
 				testpara::RuntimeOrigin::signed(ALICE),
-				1
+				1,
+				crate::Vote::Aye,
+				None
 			),
-			Error::<Test>::NotCollectionOwner
+			Error::<Test>::AlreadyVotedThis
 		);
 	});
 }
 
 #[test]
-fn try_initiating_proposal_that_did_not_pass() {
+fn vote_on_proposal_successfuly() {
 	ParaA::execute_with(|| {
-		testpara::System::set_block_number(2);
-
-		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
 
-		//Create owners vector
-		let mut owners = BoundedVec::new();
-		owners.try_push(ALICE).expect("Failed to push owner");
+		// Create collection with NFT held by the voter
+		let _ =
+			testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), COLLECTION_ID, ALICE);
+		let _ =
+			testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), COLLECTION_ID, 0, ALICE);
 
 		//Create proposal
 		let proposal = Proposal::<Test> {
 			proposal_id: 1,
-			collection_id: 0,
+			collection_id: COLLECTION_ID,
 			proposed_collection_owner: ALICE,
+			proposed_beneficiary: account_location(ALICE),
+			proposed_deposit_asset: None,
 			proposed_destination_para: 2000.into(),
 			proposed_dest_collection_id: None,
 			proposed_destination_config: None,
-			owners: owners.clone(),
-			number_of_votes: crate::Votes { aye: BoundedVec::new(), nay: owners.clone() },
-			end_time: 1u64.into(),
+			total_weight: 1,
+			aye_weight: 0,
+			nay_weight: 1,
+			snapshot_cursor: None,
+			snapshot_complete: true,
+			voting_rule: crate::voting::VotingRule::SimpleMajority,
+			migration_mode: crate::MigrationMode::BurnAll,
+			deposit: 0,
+			end_time: 2u64.into(),
 		};
 
 		let _ = crate::CrossChainProposals::insert(1, proposal);
-		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+
+		// Insert owners of the proposal and their votes
+		crate::ProposalOwners::<Test>::insert(1, ALICE, 1);
+		crate::ProposalVotes::<Test>::insert(1, ALICE, (crate::Vote::Nay, 1));
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
 			testpara::RuntimeOrigin::signed(ALICE),
 			1,
+			crate::Vote::Aye,
+			None,
 		);
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
-			Event::ProposalDidNotPass { proposal_id: 1 },
+			Event::CrossChainPropoposalVoteRegistered {
+				proposal_id: 1,
+				voter: ALICE,
+				vote: crate::Vote::Aye,
+			},
+		));
+	});
+}
+
+#[test]
+fn vote_weighted_by_nfts_held() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 2, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 3, BOB);
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			crate::Vote::Nay,
+			None,
+		);
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		let proposal = crate::CrossChainProposals::<testpara::Runtime>::get(0).unwrap();
+
+		assert_eq!(
+			crate::ProposalVotes::<testpara::Runtime>::get(0, ALICE),
+			Some((crate::Vote::Aye, 3))
+		);
+		assert_eq!(
+			crate::ProposalVotes::<testpara::Runtime>::get(0, BOB),
+			Some((crate::Vote::Nay, 1))
+		);
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::proposal_tally(&proposal),
+			crate::voting::Tally { aye: 3, nay: 1, total: 4 }
+		);
+		assert!(crate::Pallet::<testpara::Runtime>::proposal_passed(&proposal));
+	});
+}
+
+#[test]
+fn cancel_proposal_successfuly() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		// Only the proposer can cancel
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_cancel(testpara::RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NotCollectionOwner
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_cancel(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::ProposalCancelled { proposal_id: 0 },
 		));
+
+		assert!(crate::CrossChainProposals::<testpara::Runtime>::get(0).is_none());
+		assert_eq!(crate::ProposalOfCollection::<testpara::Runtime>::get(0), None);
+		assert!(!crate::ProposalLockedItems::<testpara::Runtime>::contains_key(0, 1));
+	});
+}
+
+#[test]
+fn withdraw_vote_successfuly() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			crate::Vote::Nay,
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_withdraw_vote(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CrossChainProposalVoteWithdrawn { proposal_id: 0, voter: BOB },
+		));
+
+		assert!(!crate::ProposalVotes::<testpara::Runtime>::contains_key(0, BOB));
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_withdraw_vote(
+				testpara::RuntimeOrigin::signed(BOB),
+				0
+			),
+			Error::<Test>::NotVoted
+		);
+	});
+}
+
+#[test]
+fn delegated_vote_counts_for_delegate() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 2, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_delegate(
+				testpara::RuntimeOrigin::signed(BOB),
+				0,
+				Some(BOB)
+			),
+			Error::<Test>::CannotDelegateToSelf
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_delegate(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			Some(ALICE),
+		);
+
+		// BOB cannot vote directly after delegating
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_vote(
+				testpara::RuntimeOrigin::signed(BOB),
+				0,
+				crate::Vote::Nay,
+				None
+			),
+			Error::<Test>::VoteDelegated
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Nay,
+			None,
+		);
+
+		let proposal = crate::CrossChainProposals::<testpara::Runtime>::get(0).unwrap();
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::proposal_tally(&proposal),
+			crate::voting::Tally { aye: 0, nay: 3, total: 3 }
+		);
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::effective_vote(&proposal, &BOB),
+			Some(crate::Vote::Nay)
+		);
+
+		// Removing the delegation removes delegated weight
+		let _ = testpara::XcNFT::collection_x_transfer_delegate(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			None,
+		);
+
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::proposal_tally(&proposal),
+			crate::voting::Tally { aye: 0, nay: 1, total: 3 }
+		);
+	});
+}
+
+#[test]
+fn initiate_proposal_early_when_all_voted_aye() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		// Not every owner voted yet
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_initiate(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0
+			),
+			Error::<Test>::ProposalStillActive
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		let nfts =
+			vec![(0, account_location(ALICE), Vec::new()), (1, account_location(BOB), Vec::new())];
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionAndNFTsDiffTransferred {
+				message_hash: last_message_hash(),
+				origin_collection_id: 0,
+				nfts,
+				destination_para_id: 2000.into(),
+				to_address: ALICE,
+			},
+		));
+	});
+}
+
+#[test]
+fn initiate_proposal_successfuly() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		testpara::System::set_block_number(11);
+
+		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		let nfts =
+			vec![(0, account_location(ALICE), Vec::new()), (1, account_location(BOB), Vec::new())];
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionAndNFTsDiffTransferred {
+				message_hash: last_message_hash(),
+				origin_collection_id: 0,
+				nfts,
+				destination_para_id: 2000.into(),
+				to_address: ALICE,
+			},
+		));
+	});
+}
+
+#[test]
+fn initiate_proposal_of_emptied_collection() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		// All NFTs are burned while the proposal is open
+		let _ = testpara::NFTs::burn(testpara::RuntimeOrigin::signed(ALICE), 0, 0, None);
+		let _ = testpara::NFTs::burn(testpara::RuntimeOrigin::signed(BOB), 0, 1, None);
+
+		testpara::System::set_block_number(11);
+
+		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		// Empty collection is transferred once and the proposal is removed once
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionTransferred {
+				message_hash: last_message_hash(),
+				origin_collection_id: 0,
+				origin_collection_metadata: Vec::new(),
+				destination_para_id: 2000.into(),
+			},
+		));
+
+		assert!(!crate::CrossChainProposals::<testpara::Runtime>::contains_key(0));
+		assert_eq!(testpara::Balances::reserved_balance(ALICE), 0);
+		assert_eq!(crate::ActiveProposals::<testpara::Runtime>::get(ALICE), None);
+	});
+}
+
+#[test]
+fn initiate_proposal_keeps_nfts_minted_after_snapshot() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		// NFT minted after the snapshot is neither locked nor counted
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 2, BOB);
+
+		assert!(!crate::ProposalItems::<testpara::Runtime>::contains_key(0, 2));
+		assert!(!crate::ProposalLockedItems::<testpara::Runtime>::contains_key(0, 2));
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		let nfts =
+			vec![(0, account_location(ALICE), Vec::new()), (1, account_location(BOB), Vec::new())];
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionAndNFTsDiffTransferred {
+				message_hash: last_message_hash(),
+				origin_collection_id: 0,
+				nfts,
+				destination_para_id: 2000.into(),
+				to_address: ALICE,
+			},
+		));
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::ResidualCollectionRetained {
+				message_hash: last_message_hash(),
+				origin_collection_id: 0,
+				proposal_id: 0,
+				nfts: vec![2],
+				destination_para_id: 2000.into(),
+			},
+		));
+
+		// NFT that was not voted on stays on origin chain
+		assert_eq!(testpara::NFTs::owner(0, 1), None);
+		assert_eq!(testpara::NFTs::owner(0, 2), Some(BOB));
+	});
+}
+
+#[test]
+fn initiate_proposal_without_votes_of_burned_nfts() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		// Owner that did not vote burns their NFT, the remaining owner voted Aye
+		let _ = testpara::NFTs::burn(testpara::RuntimeOrigin::signed(BOB), 0, 1, None);
+
+		let mut proposal = crate::CrossChainProposals::<testpara::Runtime>::get(0).unwrap();
+		crate::Pallet::<testpara::Runtime>::recount_votes(&mut proposal);
+
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::proposal_tally(&proposal),
+			crate::voting::Tally { aye: 1, nay: 0, total: 1 }
+		);
+
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionAndNFTsDiffTransferred {
+				message_hash: last_message_hash(),
+				origin_collection_id: 0,
+				nfts: vec![(0, account_location(ALICE), Vec::new())],
+				destination_para_id: 2000.into(),
+				to_address: ALICE,
+			},
+		));
+	});
+}
+
+#[test]
+fn initiate_proposal_opt_out_keeps_nay_voters_nfts() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 2, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		crate::CrossChainProposals::<testpara::Runtime>::mutate(0, |proposal| {
+			proposal.as_mut().unwrap().migration_mode = crate::MigrationMode::OptOut;
+		});
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			crate::Vote::Nay,
+			None,
+		);
+
+		testpara::System::set_block_number(11);
+
+		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		let nfts = vec![
+			(0, account_location(ALICE), Vec::new()),
+			(1, account_location(ALICE), Vec::new()),
+		];
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionAndNFTsDiffTransferred {
+				message_hash: last_message_hash(),
+				origin_collection_id: 0,
+				nfts,
+				destination_para_id: 2000.into(),
+				to_address: ALICE,
+			},
+		));
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::ResidualCollectionRetained {
+				message_hash: last_message_hash(),
+				origin_collection_id: 0,
+				proposal_id: 0,
+				nfts: vec![2],
+				destination_para_id: 2000.into(),
+			},
+		));
+
+		// Collection stays on origin chain with NFT of Nay voter and is linked with destination
+		assert!(testpara::NFTs::collection_owner(0).is_some());
+		assert_eq!(testpara::NFTs::owner(0, 0), None);
+		assert_eq!(testpara::NFTs::owner(0, 2), Some(BOB));
+		assert!(!crate::ProposalLockedItems::<testpara::Runtime>::contains_key(0, 2));
+
+		let residual = crate::ResidualCollections::<testpara::Runtime>::get(0).unwrap();
+		assert_eq!(residual.proposal_id, 0);
+		assert_eq!(residual.destination_para_id, 2000.into());
+		assert_eq!(residual.destination_collection_id, None);
+
+		// Only destination chain of the collection can confirm collection it was received into
+		assert_noop!(
+			testpara::XcNFT::parse_collection_transfer_confirmation(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				COLLECTION_ID
+			),
+			Error::<Test>::InvalidSender
+		);
+		assert_noop!(
+			sequenced_call(
+				3000,
+				ALICE,
+				crate::Call::<testpara::Runtime>::parse_collection_transfer_confirmation {
+					collection: 0,
+					destination_collection: COLLECTION_ID,
+				},
+			),
+			Error::<Test>::InvalidSender
+		);
+		assert_noop!(
+			sequenced_call(
+				2000,
+				ALICE,
+				crate::Call::<testpara::Runtime>::parse_collection_transfer_confirmation {
+					collection: 1,
+					destination_collection: COLLECTION_ID,
+				},
+			),
+			Error::<Test>::CollectionNotResidual
+		);
+
+		let _ = sequenced_call(
+			2000,
+			ALICE,
+			crate::Call::<testpara::Runtime>::parse_collection_transfer_confirmation {
+				collection: 0,
+				destination_collection: COLLECTION_ID,
+			},
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionTransferConfirmed {
+				collection_id: 0,
+				destination_para_id: 2000.into(),
+				destination_collection_id: COLLECTION_ID,
+			},
+		));
+
+		let residual = crate::ResidualCollections::<testpara::Runtime>::get(0).unwrap();
+		assert_eq!(residual.destination_collection_id, Some(COLLECTION_ID));
+	});
+}
+
+#[test]
+fn initiate_proposal_to_chosen_beneficiary() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		// BOB wants to receive their NFT into 20 byte account
+		let beneficiary: Location = Junction::AccountKey20 { network: None, key: [2u8; 20] }.into();
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			crate::Vote::Aye,
+			Some(beneficiary.clone()),
+		);
+
+		assert_eq!(
+			crate::ProposalBeneficiaries::<testpara::Runtime>::get(0, BOB),
+			Some(beneficiary.clone())
+		);
+
+		testpara::System::set_block_number(11);
+
+		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		let nfts = vec![(0, account_location(ALICE), Vec::new()), (1, beneficiary, Vec::new())];
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionAndNFTsDiffTransferred {
+				message_hash: last_message_hash(),
+				origin_collection_id: 0,
+				nfts,
+				destination_para_id: 2000.into(),
+				to_address: ALICE,
+			},
+		));
+
+		assert_eq!(crate::ProposalBeneficiaries::<testpara::Runtime>::get(0, BOB), None);
+	});
+}
+
+#[test]
+fn try_voting_with_invalid_beneficiary() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_vote(
+				testpara::RuntimeOrigin::signed(BOB),
+				0,
+				crate::Vote::Aye,
+				Some(Location::parent())
+			),
+			Error::<Test>::InvalidBeneficiary
+		);
+	});
+}
+
+#[test]
+fn try_initiating_proposal_doesnt_exist() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_initiate(
+				testpara::RuntimeOrigin::signed(ALICE),
+				1
+			),
+			Error::<Test>::ProposalDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn try_initiating_proposal_collection_doesnt_exist() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+
+		const COLLECTION_ID: u32 = 1;
+
+		//Create proposal
+		let proposal = Proposal::<Test> {
+			proposal_id: 1,
+			collection_id: COLLECTION_ID,
+			proposed_collection_owner: ALICE,
+			proposed_beneficiary: account_location(ALICE),
+			proposed_deposit_asset: None,
+			proposed_destination_para: 2000.into(),
+			proposed_dest_collection_id: None,
+			proposed_destination_config: None,
+			total_weight: 1,
+			aye_weight: 0,
+			nay_weight: 1,
+			snapshot_cursor: None,
+			snapshot_complete: true,
+			voting_rule: crate::voting::VotingRule::SimpleMajority,
+			migration_mode: crate::MigrationMode::BurnAll,
+			deposit: 0,
+			end_time: 2u64.into(),
+		};
+
+		let _ = crate::CrossChainProposals::insert(1, proposal);
+
+		// Insert owners of the proposal and their votes
+		crate::ProposalOwners::<Test>::insert(1, ALICE, 1);
+		crate::ProposalVotes::<Test>::insert(1, ALICE, (crate::Vote::Nay, 1));
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_initiate(
+				testpara::RuntimeOrigin::signed(ALICE),
+				1
+			),
+			Error::<Test>::CollectionDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn try_initiating_proposal_no_collection_owner() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(BOB), 0, ALICE);
+
+		//Create proposal
+		let proposal = Proposal::<Test> {
+			proposal_id: 1,
+			collection_id: 0,
+			proposed_collection_owner: BOB,
+			proposed_beneficiary: account_location(BOB),
+			proposed_deposit_asset: None,
+			proposed_destination_para: 2000.into(),
+			proposed_dest_collection_id: None,
+			proposed_destination_config: None,
+			total_weight: 1,
+			aye_weight: 0,
+			nay_weight: 1,
+			snapshot_cursor: None,
+			snapshot_complete: true,
+			voting_rule: crate::voting::VotingRule::SimpleMajority,
+			migration_mode: crate::MigrationMode::BurnAll,
+			deposit: 0,
+			end_time: 1u64.into(),
+		};
+
+		let _ = crate::CrossChainProposals::insert(1, proposal);
+
+		// Insert owners of the proposal and their votes
+		crate::ProposalOwners::<Test>::insert(1, BOB, 1);
+		crate::ProposalVotes::<Test>::insert(1, BOB, (crate::Vote::Nay, 1));
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_initiate(
+				testpara::RuntimeOrigin::signed(ALICE),
+				1
+			),
+			Error::<Test>::NotCollectionOwner
+		);
+	});
+}
+
+#[test]
+fn try_initiating_proposal_that_did_not_pass() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		//Create proposal
+		let proposal = Proposal::<Test> {
+			proposal_id: 1,
+			collection_id: 0,
+			proposed_collection_owner: ALICE,
+			proposed_beneficiary: account_location(ALICE),
+			proposed_deposit_asset: None,
+			proposed_destination_para: 2000.into(),
+			proposed_dest_collection_id: None,
+			proposed_destination_config: None,
+			total_weight: 1,
+			aye_weight: 0,
+			nay_weight: 1,
+			snapshot_cursor: None,
+			snapshot_complete: true,
+			voting_rule: crate::voting::VotingRule::SimpleMajority,
+			migration_mode: crate::MigrationMode::BurnAll,
+			deposit: 0,
+			end_time: 1u64.into(),
+		};
+
+		let _ = crate::CrossChainProposals::insert(1, proposal);
+
+		// Insert owners of the proposal, their snapshotted NFTs and votes
+		crate::ProposalOwners::<Test>::insert(1, ALICE, 1);
+		crate::ProposalItems::<Test>::insert(1, 0, ALICE);
+		crate::ProposalVotes::<Test>::insert(1, ALICE, (crate::Vote::Nay, 1));
+		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::RuntimeOrigin::signed(ALICE),
+			1,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::ProposalDidNotPass { proposal_id: 1 },
+		));
+	});
+}
+
+#[test]
+fn try_initiating_proposal_that_did_not_pass_unanimous_rule() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+
+		//Create proposal
+		let proposal = Proposal::<Test> {
+			proposal_id: 1,
+			collection_id: 0,
+			proposed_collection_owner: ALICE,
+			proposed_beneficiary: account_location(ALICE),
+			proposed_deposit_asset: None,
+			proposed_destination_para: 2000.into(),
+			proposed_dest_collection_id: None,
+			proposed_destination_config: None,
+			total_weight: 2,
+			aye_weight: 1,
+			nay_weight: 0,
+			snapshot_cursor: None,
+			snapshot_complete: true,
+			voting_rule: crate::voting::VotingRule::Unanimous,
+			migration_mode: crate::MigrationMode::BurnAll,
+			deposit: 0,
+			end_time: 1u64.into(),
+		};
+
+		let _ = crate::CrossChainProposals::insert(1, proposal);
+
+		// Insert owners of the proposal, their snapshotted NFTs and votes
+		crate::ProposalOwners::<Test>::insert(1, ALICE, 1);
+		crate::ProposalOwners::<Test>::insert(1, BOB, 1);
+		crate::ProposalItems::<Test>::insert(1, 0, ALICE);
+		crate::ProposalItems::<Test>::insert(1, 1, BOB);
+		crate::ProposalVotes::<Test>::insert(1, ALICE, (crate::Vote::Aye, 1));
+		let _ = testpara::XcNFT::collection_x_transfer_initiate(
+			testpara::RuntimeOrigin::signed(ALICE),
+			1,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::ProposalDidNotPass { proposal_id: 1 },
+		));
+	});
+}
+
+#[test]
+fn voting_rules_evaluate_tally() {
+	use crate::voting::{Tally, VotingRule};
+	use sp_runtime::Percent;
+
+	let tally = Tally { aye: 3, nay: 2, total: 10 };
+
+	assert!(VotingRule::SimpleMajority.passed(&tally));
+	assert!(!VotingRule::MajorityOfOwners.passed(&tally));
+	assert!(!VotingRule::Unanimous.passed(&tally));
+	assert!(!VotingRule::SupermajorityWithQuorum {
+		threshold: Percent::from_percent(66),
+		quorum: Percent::from_percent(50),
+	}
+	.passed(&tally));
+	assert!(VotingRule::SupermajorityWithQuorum {
+		threshold: Percent::from_percent(60),
+		quorum: Percent::from_percent(50),
+	}
+	.passed(&tally));
+	assert!(!VotingRule::SimpleMajority.passed(&Tally { aye: 0, nay: 0, total: 2 }));
+	assert!(VotingRule::Unanimous.passed(&Tally { aye: 2, nay: 0, total: 2 }));
+}
+
+#[test]
+fn locations_convert_32_and_20_byte_accounts() {
+	use crate::location::{AccountIdToLocation, LocationToAccountId};
+	use frame_support::parameter_types;
+	use sp_runtime::traits::Convert;
+
+	parameter_types! {
+		pub const Network: Option<NetworkId> = Some(NetworkId::Polkadot);
+	}
+
+	let key = [2u8; 20];
+	let key_location: Location = Junction::AccountKey20 { network: None, key }.into();
+
+	assert_eq!(AccountIdToLocation::<AccountId32>::convert(ALICE), Some(account_location(ALICE)));
+	assert_eq!(AccountIdToLocation::<[u8; 20]>::convert(key), Some(key_location.clone()));
+	assert_eq!(AccountIdToLocation::<u64>::convert(1), None);
+	assert_eq!(
+		AccountIdToLocation::<AccountId32, Network>::convert(BOB),
+		Some(Junction::AccountId32 { network: Some(NetworkId::Polkadot), id: BOB.into() }.into())
+	);
+
+	assert_eq!(LocationToAccountId::<AccountId32>::convert(account_location(ALICE)), Some(ALICE));
+	assert_eq!(LocationToAccountId::<[u8; 20]>::convert(key_location), Some(key));
+	assert_eq!(LocationToAccountId::<AccountId32>::convert(Location::parent()), None);
+
+	// Location within other network is not accepted
+	let kusama_location: Location =
+		Junction::AccountId32 { network: Some(NetworkId::Kusama), id: BOB.into() }.into();
+	assert_eq!(LocationToAccountId::<AccountId32, Network>::convert(kusama_location), None);
+	assert_eq!(
+		LocationToAccountId::<AccountId32, Network>::convert(account_location(BOB)),
+		Some(BOB)
+	);
+}
+
+#[test]
+fn try_setting_destination_origin_mode_not_admin() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		assert_noop!(
+			testpara::XcNFT::set_destination_origin_mode(
+				testpara::RuntimeOrigin::signed(ALICE),
+				2000.into(),
+				OriginMode::Descend
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn setting_sibling_pallet() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		assert_noop!(
+			testpara::XcNFT::set_sibling_pallet(
+				testpara::RuntimeOrigin::signed(ALICE),
+				2000.into(),
+				Some(6)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		let _ = testpara::XcNFT::set_sibling_pallet(
+			testpara::RuntimeOrigin::root(),
+			2000.into(),
+			Some(6),
+		);
+
+		assert_eq!(
+			testpara::XcNFT::sibling_pallets(cumulus_primitives_core::ParaId::from(2000)),
+			Some(6)
+		);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::SiblingPalletSet { sibling_para_id: 2000.into(), pallet_index: Some(6) },
+		));
+
+		// Calls descended from former index of xcNFT pallet are no longer verified
+		assert_noop!(
+			sequenced_call(
+				2000,
+				ALICE,
+				crate::Call::<testpara::Runtime>::parse_collection_empty {
+					origin_collection: 0,
+					destination_collection: None,
+					collection_metadata: Vec::new(),
+					config: None,
+					beneficiary: account_location(BOB),
+				},
+			),
+			Error::<Test>::InvalidSender
+		);
+	});
+}
+
+#[test]
+fn try_parse_from_other_sibling_pallet() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		// Other pallets of sibling chain cannot send calls on behalf of its accounts
+		assert_noop!(
+			testpara::XcNFT::parse_collection_empty(
+				pallet_origin(2000, XCNFT_PALLET_INDEX + 1, ALICE),
+				0,
+				None,
+				Vec::new(),
+				None,
+				account_location(BOB)
+			),
+			Error::<Test>::InvalidSender
+		);
+
+		// Calls of chains without known xcNFT pallet are not verified
+		assert_noop!(
+			sequenced_call(
+				3000,
+				ALICE,
+				crate::Call::<testpara::Runtime>::parse_collection_empty {
+					origin_collection: 0,
+					destination_collection: None,
+					collection_metadata: Vec::new(),
+					config: None,
+					beneficiary: account_location(BOB),
+				},
+			),
+			Error::<Test>::InvalidSender
+		);
+	});
+}
+
+#[test]
+fn sending_nft_with_descended_origin() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		let _ = testpara::XcNFT::set_destination_origin_mode(
+			testpara::RuntimeOrigin::root(),
+			2000.into(),
+			OriginMode::Descend,
+		);
+		assert_eq!(
+			crate::DestinationOriginModes::<testpara::Runtime>::get(
+				cumulus_primitives_core::ParaId::from(2000)
+			),
+			OriginMode::Descend
+		);
+
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			2000.into(),
+			1,
+			Some(1),
+			account_location(ALICE),
+			None,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTTransferred {
+			message_hash: last_message_hash(),
+			origin_collection_id: 0,
+			origin_asset_id: 0,
+			destination_para_id: 2000.into(),
+			destination_collection_id: 1,
+			destination_asset_id: Some(1),
+		}));
+	});
+}
+
+#[test]
+fn sending_nft_with_paid_execution() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		let fee: Asset = (Parent, 1_000u128).into();
+		let _ = testpara::XcNFT::set_destination_fee(
+			testpara::RuntimeOrigin::root(),
+			2000.into(),
+			Some(fee.clone()),
+		);
+		assert_eq!(
+			crate::DestinationFees::<testpara::Runtime>::get(
+				cumulus_primitives_core::ParaId::from(2000)
+			),
+			Some(fee)
+		);
+
+		let balance = testpara::Balances::free_balance(ALICE);
+
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			2000.into(),
+			1,
+			Some(1),
+			account_location(ALICE),
+			None,
+		);
+
+		// Sender is charged for execution bought on destination
+		assert_eq!(testpara::Balances::free_balance(ALICE), balance - 1_000);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTTransferred {
+			message_hash: last_message_hash(),
+			origin_collection_id: 0,
+			origin_asset_id: 0,
+			destination_para_id: 2000.into(),
+			destination_collection_id: 1,
+			destination_asset_id: Some(1),
+		}));
+	});
+}
+
+#[test]
+fn try_sending_nft_without_fee_funds() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, BOB);
+
+		let _ = testpara::XcNFT::set_destination_fee(
+			testpara::RuntimeOrigin::root(),
+			2000.into(),
+			Some((Parent, 1_000u128).into()),
+		);
+
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			0,
+			2000.into(),
+			1,
+			Some(1),
+			account_location(BOB),
+			None,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionFailedToXCM {
+				message_hash: last_message_hash(),
+				e: SendError::Fees,
+				collection_id: 0,
+				owner: BOB,
+				destination: 2000.into(),
+			},
+		));
+		assert!(crate::SentAssets::<testpara::Runtime>::get((0, 0)).is_none());
+	});
+}
+
+#[test]
+fn try_setting_destination_fee_not_fungible() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		assert_noop!(
+			testpara::XcNFT::set_destination_fee(
+				testpara::RuntimeOrigin::root(),
+				2000.into(),
+				Some((Parent, AssetInstance::Index(1)).into())
+			),
+			Error::<Test>::InvalidFeeAsset
+		);
+	});
+}
+
+#[test]
+fn deposit_funding_follows_expected_deposit() {
+	ParaA::execute_with(|| {
+		let deposit_asset: AssetId = Parent.into();
+
+		// Nothing is funded when no deposit is expected on destination
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::deposit_funding(
+				Some(deposit_asset.clone()),
+				0,
+				&account_location(BOB)
+			),
+			None
+		);
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::deposit_funding(None, 100, &account_location(BOB)),
+			None
+		);
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::deposit_funding(
+				Some(deposit_asset.clone()),
+				100,
+				&account_location(BOB)
+			),
+			Some(((deposit_asset, 100u128).into(), account_location(BOB)))
+		);
+	});
+}
+
+#[test]
+fn sending_nft_with_deposit_asset() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		let balance = testpara::Balances::free_balance(ALICE);
+
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			2000.into(),
+			1,
+			Some(1),
+			account_location(BOB),
+			Some(Parent.into()),
+		);
+
+		// Test runtime reserves no deposits, so sender is not charged
+		assert_eq!(testpara::Balances::free_balance(ALICE), balance);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTTransferred {
+			message_hash: last_message_hash(),
+			origin_collection_id: 0,
+			origin_asset_id: 0,
+			destination_para_id: 2000.into(),
+			destination_collection_id: 1,
+			destination_asset_id: Some(1),
+		}));
+	});
+}
+
+#[test]
+fn try_sending_collection_too_heavy() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		// Mint more items than fit into a single destination call
+		for item in 0..20 {
+			let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, item, ALICE);
+		}
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				Some(1),
+				2000.into(),
+				None,
+				account_location(ALICE),
+				None,
+			),
+			Error::<Test>::PayloadTooHeavy
+		);
+	});
+}
+
+#[test]
+fn transact_weight_counts_items_and_metadata() {
+	use crate::weights::WeightInfo;
+
+	ParaA::execute_with(|| {
+		let nft_transfer = |data: Vec<u8>| crate::Call::<testpara::Runtime>::parse_nft_transfer {
+			origin_collection: 0,
+			origin_item: 0,
+			collection: 0,
+			item: None,
+			metadata_hashes: metadata_hashes(&data),
+			data,
+			origin_chain: 1000.into(),
+			beneficiary: account_location(ALICE),
+		};
+		let collection =
+			|items: u32| crate::Call::<testpara::Runtime>::parse_collection_same_owner {
+				config: None,
+				collection_metadata: Vec::new(),
+				nfts: (0..items).map(|item| (item, Vec::new())).collect(),
+				metadata_hashes: (0..items).map(|_| collection_metadata_hashes(&[], &[])).collect(),
+				origin_para: 1000.into(),
+				origin_collection_id: 0,
+				dest_collection_id: None,
+				beneficiary: account_location(ALICE),
+			};
+
+		let weight = |call| crate::Pallet::<testpara::Runtime>::transact_weight(&call);
+
+		assert_eq!(
+			weight(nft_transfer(Vec::new())),
+			<testpara::Runtime as crate::Config>::WeightInfo::parse_item()
+		);
+		// Metadata bytes add to proof size
+		assert_eq!(
+			weight(nft_transfer(vec![0; 10])).proof_size(),
+			weight(nft_transfer(Vec::new())).proof_size() + 10
+		);
+		// Every item after the benchmarked one is weighed as separate asset transfer
+		assert_eq!(
+			weight(collection(3)),
+			weight(collection(1)) +
+				<testpara::Runtime as crate::Config>::WeightInfo::parse_item() * 2
+		);
+	});
+}
+
+#[test]
+fn sent_message_is_tracked_until_timeout() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			2000.into(),
+			1,
+			Some(1),
+			account_location(ALICE),
+			None,
+		);
+
+		let message_hash = last_message_hash();
+		let message = crate::OutboundMessages::<testpara::Runtime>::get(message_hash).unwrap();
+		assert_eq!(message.operation, Operation::NFTTransfer);
+		assert_eq!(message.sender, account_location(ALICE));
+		assert_eq!(message.destination_para_id, 2000.into());
+		assert_eq!(message.status, MessageStatus::Sent);
+
+		// Status report is awaited until query times out
+		testpara::XcNFT::on_idle(5, Weight::MAX);
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(message_hash).unwrap().status,
+			MessageStatus::Sent
+		);
+
+		testpara::System::set_block_number(13);
+		testpara::XcNFT::on_idle(13, Weight::MAX);
+
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(message_hash).unwrap().status,
+			MessageStatus::TimedOut
+		);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::OutboundMessageStatusUpdated { message_hash, status: MessageStatus::TimedOut },
+		));
+
+		// Late status report of the transfer is still awaited
+		assert!(crate::PendingQueries::<testpara::Runtime>::contains_key(message.query_id));
+		assert!(crate::PendingTransfers::<testpara::Runtime>::contains_key(message_hash));
+
+		report_message_status(message_hash, MaybeErrorCode::Success);
+		testpara::XcNFT::on_idle(14, Weight::MAX);
+
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(message_hash).unwrap().status,
+			MessageStatus::Executed
+		);
+		assert!(!crate::PendingQueries::<testpara::Runtime>::contains_key(message.query_id));
+		assert!(!crate::PendingTransfers::<testpara::Runtime>::contains_key(message_hash));
+	});
+}
+
+#[test]
+fn failed_transfer_is_restored_by_owner() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			2000.into(),
+			1,
+			Some(1),
+			account_location(ALICE),
+			None,
+		);
+
+		let message_hash = last_message_hash();
+
+		// Assets of timed out transfer are kept, as the message may still have been executed
+		testpara::System::set_block_number(13);
+		testpara::XcNFT::on_idle(13, Weight::MAX);
+
+		assert!(crate::PendingTransfers::<testpara::Runtime>::contains_key(message_hash));
+		assert_eq!(pallet_uniques::Pallet::<testpara::Runtime>::owner(0, 0), None);
+
+		assert_noop!(
+			testpara::XcNFT::restore_failed_transfer(
+				testpara::RuntimeOrigin::signed(ALICE),
+				message_hash
+			),
+			Error::<Test>::TransferNotPending
+		);
+
+		// Collection owner cannot mint the NFT again, so it fails to be restored along with late
+		// report of failure
+		let _ =
+			testpara::NFTs::set_team(testpara::RuntimeOrigin::signed(ALICE), 0, BOB, ALICE, ALICE);
+
+		let error = MaybeErrorCode::from(vec![1]);
+		report_message_status(message_hash, error.clone());
+		testpara::XcNFT::on_idle(14, Weight::MAX);
+
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(message_hash).unwrap().status,
+			MessageStatus::Failed(error)
+		);
+		assert!(!crate::PendingTransfers::<testpara::Runtime>::contains_key(message_hash));
+		assert!(crate::FailedTransfers::<testpara::Runtime>::contains_key(message_hash));
+		assert_eq!(pallet_uniques::Pallet::<testpara::Runtime>::owner(0, 0), None);
+
+		assert_noop!(
+			testpara::XcNFT::restore_failed_transfer(
+				testpara::RuntimeOrigin::signed(BOB),
+				message_hash
+			),
+			Error::<Test>::NotTransferOwner
+		);
+
+		// Owner restores the assets once the cause is removed
+		let _ = testpara::NFTs::set_team(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			ALICE,
+			ALICE,
+			ALICE,
+		);
+
+		let _ = testpara::XcNFT::restore_failed_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			message_hash,
+		);
+
+		assert_eq!(pallet_uniques::Pallet::<testpara::Runtime>::owner(0, 0), Some(ALICE));
+		assert!(!crate::FailedTransfers::<testpara::Runtime>::contains_key(message_hash));
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::FailedTransferRestored { message_hash, collection_id: 0, owner: ALICE },
+		));
+
+		assert_noop!(
+			testpara::XcNFT::restore_failed_transfer(
+				testpara::RuntimeOrigin::signed(ALICE),
+				message_hash
+			),
+			Error::<Test>::TransferNotPending
+		);
+	});
+}
+
+#[test]
+fn pending_queries_are_checked_in_turns() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::XcNFT::collection_x_update(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			2000.into(),
+			BoundedVec::new(),
+		);
+		let _ = testpara::XcNFT::collection_x_update(
+			testpara::RuntimeOrigin::signed(ALICE),
+			1,
+			2000.into(),
+			BoundedVec::new(),
+		);
+
+		let mut queries = crate::PendingQueries::<testpara::Runtime>::iter();
+		let (first_query, first_message) = queries.next().unwrap();
+		let (_, second_message) = queries.next().unwrap();
+
+		// Checking continues after the query checked last in previous block
+		crate::PendingQueryCursor::<testpara::Runtime>::put(first_query);
+		testpara::System::set_block_number(13);
+		testpara::XcNFT::on_idle(13, Weight::MAX);
+
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(first_message).unwrap().status,
+			MessageStatus::Sent
+		);
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(second_message)
+				.unwrap()
+				.status,
+			MessageStatus::TimedOut
+		);
+		assert_eq!(crate::PendingQueryCursor::<testpara::Runtime>::get(), None);
+
+		// Checking starts over once the last query was checked
+		testpara::XcNFT::on_idle(13, Weight::MAX);
+
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(first_message).unwrap().status,
+			MessageStatus::TimedOut
+		);
+	});
+}
+
+#[test]
+fn finalized_messages_are_pruned() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+		testpara::max_finalized_messages_parameter::set(&1);
+
+		let _ = testpara::XcNFT::collection_x_update(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			2000.into(),
+			BoundedVec::new(),
+		);
+		let first_message = last_message_hash();
+		let _ = testpara::XcNFT::collection_x_update(
+			testpara::RuntimeOrigin::signed(ALICE),
+			1,
+			2000.into(),
+			BoundedVec::new(),
+		);
+		let second_message = last_message_hash();
+
+		testpara::System::set_block_number(13);
+		testpara::XcNFT::on_idle(13, Weight::MAX);
+
+		// Only the last message with final status is kept
+		let kept = [first_message, second_message]
+			.iter()
+			.filter(|message| crate::OutboundMessages::<testpara::Runtime>::contains_key(message))
+			.count();
+		assert_eq!(kept, 1);
+		assert_eq!(crate::FinalizedMessageCount::<testpara::Runtime>::get(), 2);
+	});
+}
+
+#[test]
+fn delivered_nft_transfer_is_reported_executed() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	MockNet::reset();
+
+	ParaB::execute_with(|| {
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+	});
+
+	let message_hash = ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			2000.into(),
+			0,
+			Some(0),
+			account_location(ALICE),
+			None,
+		);
+
+		last_message_hash()
+	});
+
+	ParaB::execute_with(|| {
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(ALICE));
+	});
+
+	// Destination reported dispatch result of the call back along with its delivery
+	ParaA::execute_with(|| {
+		testpara::XcNFT::on_idle(3, Weight::MAX);
+
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(message_hash).unwrap().status,
+			MessageStatus::Executed
+		);
+	});
+}
+
+#[test]
+fn unroutable_nft_transfer_is_not_queued() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		// No route leads to parachain 4000, sending there is not retried
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			4000.into(),
+			1,
+			Some(1),
+			account_location(ALICE),
+			None,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionFailedToXCM {
+				message_hash: last_message_hash(),
+				e: SendError::NotApplicable,
+				collection_id: 0,
+				owner: ALICE,
+				destination: 4000.into(),
+			},
+		));
+
+		assert_eq!(crate::OutboundQueueLen::<testpara::Runtime>::get(), 0);
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(ALICE));
+		assert!(crate::SentAssets::<testpara::Runtime>::get((0, 0)).is_none());
+	});
+}
+
+#[test]
+fn unreachable_nft_transfer_is_queued_until_dropped() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		// Channel to parachain 3000 is full, so the message is queued
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			3000.into(),
+			1,
+			Some(1),
+			account_location(ALICE),
+			None,
+		);
+
+		let message_hash = last_message_hash();
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::MessageQueued {
+			message_hash,
+			owner: ALICE,
+			e: SendError::Transport(""),
+			next_attempt: 4,
+		}));
+
+		// NFT is locked in pallet account while queued
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(testpara::XcNFT::account_id()));
+		assert_eq!(crate::OutboundQueueLen::<testpara::Runtime>::get(), 1);
+
+		testpara::XcNFT::on_idle(4, Weight::MAX);
+
+		let queued = crate::OutboundQueue::<testpara::Runtime>::get(message_hash).unwrap();
+		assert_eq!(queued.attempts, 2);
+		assert_eq!(queued.next_attempt, 8);
+
+		// Last attempt fails and the NFT is returned to its owner
+		testpara::XcNFT::on_idle(8, Weight::MAX);
+
+		assert!(crate::OutboundQueue::<testpara::Runtime>::get(message_hash).is_none());
+		assert_eq!(crate::OutboundQueueLen::<testpara::Runtime>::get(), 0);
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(ALICE));
+		assert!(crate::SentAssets::<testpara::Runtime>::get((0, 0)).is_none());
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::QueuedMessageDropped { message_hash, e: SendError::Transport("") },
+		));
+	});
+}
+
+#[test]
+fn queued_nft_transfer_is_sent_on_retry() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		// Channel to parachain 3000 is full, so the message is queued
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			3000.into(),
+			1,
+			Some(1),
+			account_location(ALICE),
+			None,
+		);
+
+		let message_hash = last_message_hash();
+
+		// Destination becomes reachable before the retry
+		crate::OutboundQueue::<testpara::Runtime>::mutate(message_hash, |queued| {
+			queued.as_mut().unwrap().destination_para_id = 2000.into();
+		});
+
+		testpara::XcNFT::on_idle(4, Weight::MAX);
+
+		assert!(crate::OutboundQueue::<testpara::Runtime>::get(message_hash).is_none());
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(ALICE));
+		assert!(crate::SentAssets::<testpara::Runtime>::get((0, 0)).is_some());
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(message_hash).unwrap().status,
+			MessageStatus::Sent
+		);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTTransferred {
+			message_hash,
+			origin_collection_id: 0,
+			origin_asset_id: 0,
+			destination_para_id: 2000.into(),
+			destination_collection_id: 1,
+			destination_asset_id: Some(1),
+		}));
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::QueuedMessageSent { message_hash, attempts: 2 },
+		));
+	});
+}
+
+#[test]
+fn queued_nft_transfer_is_delivered_after_later_messages() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	MockNet::reset();
+
+	ParaB::execute_with(|| {
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+	});
+
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		// Channel to parachain 3000 is full, so the message is queued
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			3000.into(),
+			0,
+			Some(0),
+			account_location(ALICE),
+			None,
+		);
+
+		let message_hash = last_message_hash();
+
+		// Sender sends more messages before the retry than `MAX_SKIPPED_NONCES`
+		for _ in 0..crate::MAX_SKIPPED_NONCES + 1 {
+			let _ = testpara::XcNFT::collection_x_update(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				2000.into(),
+				BoundedVec::new(),
+			);
+		}
+
+		// Destination becomes reachable before the retry
+		crate::OutboundQueue::<testpara::Runtime>::mutate(message_hash, |queued| {
+			queued.as_mut().unwrap().destination_para_id = 2000.into();
+		});
+
+		testpara::XcNFT::on_idle(4, Weight::MAX);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::QueuedMessageSent { message_hash, attempts: 2 },
+		));
+	});
+
+	// Retry keeps nonce of its first attempt and is accepted after the later messages
+	ParaB::execute_with(|| {
+		let window = crate::InboundNonces::<testpara::Runtime>::get(
+			cumulus_primitives_core::ParaId::from(1000),
+			&ALICE,
+		);
+		assert_eq!(window.next, crate::MAX_SKIPPED_NONCES as u64 + 2);
+		assert!(window.skipped.is_empty());
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(ALICE));
+	});
+}
+
+#[test]
+fn cancel_queued_nft_transfer() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		// Channel to parachain 3000 is full, so the message is queued
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			3000.into(),
+			1,
+			Some(1),
+			account_location(ALICE),
+			None,
+		);
+
+		let message_hash = last_message_hash();
+
+		assert_noop!(
+			testpara::XcNFT::cancel_queued_message(
+				testpara::RuntimeOrigin::signed(BOB),
+				message_hash
+			),
+			Error::<Test>::NotQueuedMessageOwner
+		);
+
+		let _ = testpara::XcNFT::cancel_queued_message(
+			testpara::RuntimeOrigin::signed(ALICE),
+			message_hash,
+		);
+
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(ALICE));
+		assert_eq!(crate::OutboundQueueLen::<testpara::Runtime>::get(), 0);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::QueuedMessageCancelled { message_hash, owner: ALICE },
+		));
+
+		assert_noop!(
+			testpara::XcNFT::cancel_queued_message(
+				testpara::RuntimeOrigin::signed(ALICE),
+				message_hash
+			),
+			Error::<Test>::QueuedMessageNotFound
+		);
+	});
+}
+
+#[test]
+fn sent_messages_carry_sender_nonce() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::XcNFT::collection_x_update(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			2000.into(),
+			BoundedVec::new(),
+		);
+		let _ = testpara::XcNFT::collection_x_update(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			2000.into(),
+			BoundedVec::new(),
+		);
+
+		assert_eq!(crate::OutboundNonces::<testpara::Runtime>::get(account_location(ALICE)), 2);
+		assert_eq!(crate::OutboundNonces::<testpara::Runtime>::get(account_location(BOB)), 0);
+	});
+}
+
+#[test]
+fn try_parse_sequenced_duplicate_nonce() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
+		let call = crate::Call::<testpara::Runtime>::parse_collection_metadata {
+			collection: 0,
+			data: Vec::new(),
+		};
+
+		let _ = testpara::XcNFT::parse_sequenced(
+			testpara::RuntimeOrigin::signed(ALICE),
+			2000.into(),
+			0,
+			0,
+			Box::new(call.clone()),
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::NFTs(
+			pallet_uniques::Event::CollectionMetadataSet {
+				collection: 0,
+				data: BoundedVec::new(),
+				is_frozen: false,
+			},
+		));
+
+		assert_noop!(
+			testpara::XcNFT::parse_sequenced(
+				testpara::RuntimeOrigin::signed(ALICE),
+				2000.into(),
+				0,
+				1,
+				Box::new(call)
+			),
+			Error::<Test>::DuplicateMessage
+		);
+	});
+}
+
+#[test]
+fn try_parse_sequenced_stale_prompt() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		let metadata = crate::Call::<testpara::Runtime>::parse_collection_metadata {
+			collection: 0,
+			data: Vec::new(),
+		};
+		let burn = crate::Call::<testpara::Runtime>::parse_nft_burn { collection: 0, item: 0 };
+
+		let _ = testpara::XcNFT::parse_sequenced(
+			testpara::RuntimeOrigin::signed(ALICE),
+			2000.into(),
+			0,
+			2,
+			Box::new(metadata),
+		);
+
+		// Burn prompt was sent by other account before metadata prompt, but arrives after it
+		assert_noop!(
+			testpara::XcNFT::parse_sequenced(
+				testpara::RuntimeOrigin::signed(BOB),
+				2000.into(),
+				0,
+				1,
+				Box::new(burn)
+			),
+			Error::<Test>::StalePrompt
+		);
+
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(ALICE));
+	});
+}
+
+#[test]
+fn parse_sequenced_accepts_skipped_nonce() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let call = crate::Call::<testpara::Runtime>::parse_collection_metadata {
+			collection: 0,
+			data: Vec::new(),
+		};
+
+		for (nonce, message_index) in [(0, 0), (100, 1)] {
+			let _ = testpara::XcNFT::parse_sequenced(
+				testpara::RuntimeOrigin::signed(ALICE),
+				2000.into(),
+				nonce,
+				message_index,
+				Box::new(call.clone()),
+			);
+		}
+
+		// Message that failed to send is accepted when it is retried after later messages
+		let _ = testpara::XcNFT::parse_sequenced(
+			testpara::RuntimeOrigin::signed(ALICE),
+			2000.into(),
+			90,
+			2,
+			Box::new(call.clone()),
+		);
+
+		let window = crate::InboundNonces::<testpara::Runtime>::get(
+			cumulus_primitives_core::ParaId::from(2000),
+			&ALICE,
+		);
+		assert_eq!(window.next, 101);
+		assert!(!window.skipped.contains(&90));
+
+		assert_noop!(
+			testpara::XcNFT::parse_sequenced(
+				testpara::RuntimeOrigin::signed(ALICE),
+				2000.into(),
+				90,
+				3,
+				Box::new(call.clone())
+			),
+			Error::<Test>::DuplicateMessage
+		);
+
+		// Only the latest skipped nonces are kept
+		assert_noop!(
+			testpara::XcNFT::parse_sequenced(
+				testpara::RuntimeOrigin::signed(ALICE),
+				2000.into(),
+				30,
+				3,
+				Box::new(call)
+			),
+			Error::<Test>::DuplicateMessage
+		);
+	});
+}
+
+#[test]
+fn try_parse_unsequenced_from_sibling() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let call = crate::Call::<testpara::Runtime>::parse_collection_empty {
+			origin_collection: 0,
+			destination_collection: None,
+			collection_metadata: Vec::new(),
+			config: None,
+			beneficiary: account_location(ALICE),
+		};
+
+		// Calls from sibling chains are not protected against replay unless they are sequenced
+		assert_noop!(
+			testpara::XcNFT::parse_collection_empty(
+				xcnft_origin(2000, ALICE),
+				0,
+				None,
+				Vec::new(),
+				None,
+				account_location(ALICE)
+			),
+			Error::<Test>::UnsequencedMessage
+		);
+
+		assert_noop!(
+			sequenced_call(
+				2000,
+				ALICE,
+				crate::Call::<testpara::Runtime>::parse_sequenced {
+					origin_chain: 2000.into(),
+					nonce: 1,
+					message_index: 1,
+					call: Box::new(call.clone()),
+				},
+			),
+			Error::<Test>::UnsequencedMessage
+		);
+
+		// Nonce is recorded for chain of the verified sender, not the chain named in the call
+		let _ = testpara::XcNFT::parse_sequenced(
+			xcnft_origin(2000, ALICE),
+			3000.into(),
+			0,
+			0,
+			Box::new(call),
+		);
+
+		assert_eq!(
+			crate::InboundNonces::<testpara::Runtime>::get(
+				cumulus_primitives_core::ParaId::from(2000),
+				&ALICE
+			)
+			.next,
+			1
+		);
+		assert_eq!(
+			crate::InboundNonces::<testpara::Runtime>::get(
+				cumulus_primitives_core::ParaId::from(3000),
+				&ALICE
+			),
+			crate::NonceWindow::default()
+		);
+	});
+}
+
+#[test]
+fn try_parse_collection_same_owner_duplicate_nft() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let nfts: Vec<(u32, Vec<u8>)> = vec![(1, Vec::new()), (1, Vec::new())];
+
+		let result = testpara::XcNFT::parse_collection_same_owner(
+			testpara::RuntimeOrigin::signed(ALICE),
+			None,
+			Vec::new(),
+			nfts.clone(),
+			nfts.iter().map(|(_, data)| collection_metadata_hashes(&[], data)).collect(),
+			1000.into(),
+			0,
+			None,
+			account_location(ALICE),
+		);
+		assert!(result.is_err());
+
+		// Collection is not created when any of its NFTs fails to be minted
+		assert!(!pallet_uniques::Collection::<testpara::Runtime>::contains_key(0));
+		assert!(!ReceivedCollections::<testpara::Runtime>::contains_key(0));
+	});
+}
+
+#[test]
+fn failed_nft_transfer_is_restored() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		let data: BoundedVec<u8, testpara::UniquesStringLimit> =
+			BoundedVec::truncate_from(vec![1, 2]);
+		let _ = testpara::NFTs::set_metadata(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			data.clone(),
+			false,
+		);
+
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			2000.into(),
+			1,
+			Some(1),
+			account_location(ALICE),
+			None,
+		);
+
+		let message_hash = last_message_hash();
+		assert!(pallet_uniques::ItemMetadataOf::<testpara::Runtime>::get(0, 0).is_none());
+
+		// Destination chain reports that NFT failed to be received
+		let error = MaybeErrorCode::from(vec![1]);
+		report_message_status(message_hash, error.clone());
+		testpara::XcNFT::on_idle(3, Weight::MAX);
+
+		assert_eq!(
+			crate::OutboundMessages::<testpara::Runtime>::get(message_hash).unwrap().status,
+			MessageStatus::Failed(error.clone())
+		);
+		assert_eq!(
+			pallet_uniques::ItemMetadataOf::<testpara::Runtime>::get(0, 0).unwrap().data,
+			data
+		);
+		assert_eq!(pallet_uniques::Pallet::<testpara::Runtime>::owner(0, 0), Some(ALICE));
+		assert!(!SentAssets::<testpara::Runtime>::contains_key((0, 0)));
+		assert!(!crate::PendingTransfers::<testpara::Runtime>::contains_key(message_hash));
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::TransferRestored { message_hash, collection_id: 0, error },
+		));
+	});
+}
+
+#[test]
+fn failed_collection_transfer_is_restored() {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, ALICE);
+
+		let data: BoundedVec<u8, testpara::UniquesStringLimit> =
+			BoundedVec::truncate_from(vec![1, 2]);
+		let _ = testpara::NFTs::set_collection_metadata(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			data.clone(),
+			false,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			None,
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let message_hash = last_message_hash();
+		assert!(!pallet_uniques::Collection::<testpara::Runtime>::contains_key(0));
+
+		// Destination chain reports that collection failed to be received
+		let error = MaybeErrorCode::from(vec![1]);
+		report_message_status(message_hash, error.clone());
+		testpara::XcNFT::on_idle(3, Weight::MAX);
+
+		// Collection is created again under its original id
+		assert_eq!(pallet_uniques::Pallet::<testpara::Runtime>::collection_owner(0), Some(ALICE));
+		assert_eq!(
+			pallet_uniques::CollectionMetadataOf::<testpara::Runtime>::get(0).unwrap().data,
+			data
+		);
+		assert_eq!(pallet_uniques::Pallet::<testpara::Runtime>::owner(0, 0), Some(ALICE));
+		assert_eq!(pallet_uniques::Pallet::<testpara::Runtime>::owner(0, 1), Some(ALICE));
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::TransferRestored { message_hash, collection_id: 0, error },
+		));
+	});
+}
+
+#[test]
+fn parse_collection_same_owner_records_created_id() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		// Requested destination collection 0 is already taken on this chain
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
+		let nfts: Vec<(u32, Vec<u8>)> = vec![(1, Vec::new())];
+
+		let _ = sequenced_call(
+			1000,
+			ALICE,
+			crate::Call::<testpara::Runtime>::parse_collection_same_owner {
+				config: None,
+				collection_metadata: Vec::new(),
+				nfts: nfts.clone(),
+				metadata_hashes: nfts
+					.iter()
+					.map(|(_, data)| collection_metadata_hashes(&[], data))
+					.collect(),
+				origin_para: 1000.into(),
+				origin_collection_id: 1,
+				dest_collection_id: Some(0),
+				beneficiary: account_location(ALICE),
+			},
+		);
+
+		assert_eq!(pallet_uniques::Pallet::<testpara::Runtime>::owner(1, 1), Some(ALICE));
+		assert_eq!(
+			ReceivedCollections::<testpara::Runtime>::get(1).unwrap().received_collection_id,
+			1
+		);
+		assert_eq!(
+			crate::ReceivedCollectionsByOrigin::<testpara::Runtime>::get(
+				cumulus_primitives_core::ParaId::from(1000),
+				1
+			),
+			Some(1)
+		);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionWithNftsReceived { collection_id: 1, items: nfts },
+		));
+	});
+}
+
+#[test]
+fn received_metadata_over_limit_is_rejected() {
+	ParaB::execute_with(|| {
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		// Metadata longer than StringLimit fails the call, so it is reported back to origin
+		assert_noop!(
+			testpara::XcNFT::parse_nft_metadata(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				0,
+				vec![1; 40]
+			),
+			Error::<testpara::Runtime>::MetadataTooLong
+		);
+	});
+}
+
+#[test]
+fn received_metadata_over_limit_is_truncated() {
+	ParaB::execute_with(|| {
+		testpara::System::set_block_number(2);
+		testpara::metadata_policy_parameter::set(&crate::MetadataPolicy::Truncate);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
+		let data: Vec<u8> = (0..40).collect();
+		let _ = testpara::XcNFT::parse_nft_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(0),
+			data.clone(),
+			metadata_hashes(&data),
+			0,
+			0,
+			1000.into(),
+			account_location(ALICE),
+		);
+
+		assert_eq!(
+			pallet_uniques::ItemMetadataOf::<testpara::Runtime>::get(0, 0)
+				.unwrap()
+				.data
+				.to_vec(),
+			data[..32].to_vec()
+		);
+		assert!(!crate::OverflowMetadata::<testpara::Runtime>::contains_key(0, Some(0)));
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::MetadataPolicyApplied {
+				collection_id: 0,
+				item_id: Some(0),
+				policy: crate::MetadataPolicy::Truncate,
+				length: 40,
+			},
+		));
+
+		// Truncated metadata differs from transferred metadata, but is unaltered since arrival
+		let integrity = testpara::XcNFT::metadata_integrity(0, 0).unwrap();
+		assert_eq!(integrity.received, metadata_hashes(&data));
+		assert_eq!(integrity.arrived, metadata_hashes(&data[..32]));
+		assert_eq!(integrity.current, integrity.arrived);
+	});
+}
+
+#[test]
+fn received_metadata_over_limit_overflows() {
+	ParaB::execute_with(|| {
+		testpara::System::set_block_number(2);
+		testpara::metadata_policy_parameter::set(&crate::MetadataPolicy::Overflow);
+
+		let data: Vec<u8> = (0..40).collect();
+		let _ = testpara::XcNFT::parse_collection_empty(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			None,
+			data.clone(),
+			None,
+			account_location(ALICE),
+		);
+
+		// Truncated metadata is stored, whole metadata is kept aside and sent on
+		assert_eq!(
+			pallet_uniques::CollectionMetadataOf::<testpara::Runtime>::get(0)
+				.unwrap()
+				.data
+				.to_vec(),
+			data[..32].to_vec()
+		);
+		assert_eq!(
+			crate::OverflowMetadata::<testpara::Runtime>::get(0, None::<u32>)
+				.map(|data| data.into_inner()),
+			Some(data.clone())
+		);
+		assert_eq!(testpara::XcNFT::sent_metadata(&0, None), data);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::MetadataPolicyApplied {
+				collection_id: 0,
+				item_id: None,
+				policy: crate::MetadataPolicy::Overflow,
+				length: 40,
+			},
+		));
+
+		// Metadata changed by the owner since it was received is sent instead
+		let _ = testpara::NFTs::set_collection_metadata(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			BoundedVec::truncate_from(vec![1, 2]),
+			false,
+		);
+		assert_eq!(testpara::XcNFT::sent_metadata(&0, None), vec![1, 2]);
+	});
+}
+
+#[test]
+fn received_metadata_over_limit_is_hashed() {
+	ParaB::execute_with(|| {
+		testpara::System::set_block_number(2);
+		testpara::metadata_policy_parameter::set(&crate::MetadataPolicy::Hash);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
+		let data: Vec<u8> = (0..40).collect();
+		let _ = testpara::XcNFT::parse_collection_metadata(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			data.clone(),
+		);
+
+		assert_eq!(
+			pallet_uniques::CollectionMetadataOf::<testpara::Runtime>::get(0)
+				.unwrap()
+				.data
+				.to_vec(),
+			BlakeTwo256::hash(&data).as_bytes().to_vec()
+		);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::MetadataPolicyApplied {
+				collection_id: 0,
+				item_id: None,
+				policy: crate::MetadataPolicy::Hash,
+				length: 40,
+			},
+		));
+	});
+}
+
+#[test]
+fn sent_nft_carries_metadata_hashes() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		let hashes = testpara::XcNFT::metadata_hashes(&0, &0, None, &[1, 2]);
+		assert_eq!(hashes, metadata_hashes(&[1, 2]));
+
+		// Collection metadata is covered by the hashes only if sent along with the NFT
+		let hashes = testpara::XcNFT::metadata_hashes(&0, &0, Some(&[3][..]), &[1, 2]);
+		assert_eq!(hashes, collection_metadata_hashes(&[3], &[1, 2]));
+
+		// Attributes of the NFT are covered by the hashes
+		let _ = testpara::NFTs::set_attribute(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(0),
+			BoundedVec::truncate_from(vec![4]),
+			BoundedVec::truncate_from(vec![5]),
+		);
+
+		let hashes = testpara::XcNFT::metadata_hashes(&0, &0, None, &[1, 2]);
+		assert_ne!(hashes.attributes, metadata_hashes(&[1, 2]).attributes);
+	});
+}
+
+#[test]
+fn received_nft_records_metadata_hashes() {
+	ParaB::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
+		let hashes = metadata_hashes(&[1, 2]);
+		let _ = testpara::XcNFT::parse_nft_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(0),
+			vec![1, 2],
+			hashes,
+			0,
+			0,
+			1000.into(),
+			account_location(ALICE),
+		);
+
+		assert_eq!(
+			testpara::XcNFT::received_metadata_hashes((0, 0)),
+			Some(ReceivedHashes { received: hashes, arrived: hashes })
+		);
+		assert_eq!(
+			testpara::XcNFT::metadata_integrity(0, 0),
+			Some(MetadataIntegrity { received: hashes, arrived: hashes, current: hashes })
+		);
+
+		// Metadata altered by the owner since arrival is reflected in current hashes
+		let _ = testpara::NFTs::set_metadata(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			BoundedVec::truncate_from(vec![3]),
+			false,
+		);
+
+		let integrity = testpara::XcNFT::metadata_integrity(0, 0).unwrap();
+		assert_eq!(integrity.received, hashes);
+		assert_eq!(integrity.arrived, hashes);
+		assert_eq!(integrity.current.collection, None);
+		assert_ne!(integrity.current.item, hashes.item);
+	});
+}
+
+#[test]
+fn received_collection_records_metadata_hashes() {
+	ParaB::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let hashes = collection_metadata_hashes(&[3], &[1, 2]);
+		let _ = testpara::XcNFT::parse_collection_same_owner(
+			testpara::RuntimeOrigin::signed(ALICE),
+			None,
+			vec![3],
+			vec![(0, vec![1, 2])],
+			vec![hashes],
+			1000.into(),
+			0,
+			None,
+			account_location(ALICE),
+		);
+
+		let ((collection, item), received) =
+			crate::ReceivedMetadataHashes::<Test>::iter().next().unwrap();
+		assert_eq!(received, ReceivedHashes { received: hashes, arrived: hashes });
+
+		// Metadata of the collection and the NFT is unaltered since arrival
+		assert_eq!(
+			testpara::XcNFT::metadata_integrity(collection, item),
+			Some(MetadataIntegrity { received: hashes, arrived: hashes, current: hashes })
+		);
+	});
+}
+
+#[test]
+fn try_parse_nft_transfer_metadata_hash_mismatch() {
+	ParaB::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
+		// Collection metadata is not sent along with the NFT, so it cannot be covered by the hashes
+		assert_noop!(
+			testpara::XcNFT::parse_nft_transfer(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				Some(0),
+				vec![1, 2],
+				collection_metadata_hashes(&[], &[1, 2]),
+				0,
+				0,
+				1000.into(),
+				account_location(ALICE),
+			),
+			Error::<Test>::MetadataHashMismatch
+		);
+
+		// Metadata tampered with in transit does not match hash computed on origin chain
+		assert_noop!(
+			testpara::XcNFT::parse_nft_transfer(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				Some(0),
+				vec![1, 3],
+				metadata_hashes(&[1, 2]),
+				0,
+				0,
+				1000.into(),
+				account_location(ALICE),
+			),
+			Error::<Test>::MetadataHashMismatch
+		);
+	});
+}
+
+#[test]
+fn try_parse_collection_metadata_hash_mismatch() {
+	ParaB::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let nfts: Vec<(u32, Vec<u8>)> = vec![(0, vec![1, 2])];
+
+		// Collection metadata sent along with the NFTs has to be covered by the hashes
+		assert_noop!(
+			testpara::XcNFT::parse_collection_same_owner(
+				testpara::RuntimeOrigin::signed(ALICE),
+				None,
+				vec![3],
+				nfts.clone(),
+				vec![metadata_hashes(&[1, 2])],
+				1000.into(),
+				0,
+				None,
+				account_location(ALICE),
+			),
+			Error::<Test>::MetadataHashMismatch
+		);
+
+		// Collection metadata and NFT metadata tampered with in transit are rejected
+		assert_noop!(
+			testpara::XcNFT::parse_collection_same_owner(
+				testpara::RuntimeOrigin::signed(ALICE),
+				None,
+				vec![4],
+				nfts.clone(),
+				vec![collection_metadata_hashes(&[3], &[1, 2])],
+				1000.into(),
+				0,
+				None,
+				account_location(ALICE),
+			),
+			Error::<Test>::MetadataHashMismatch
+		);
+		assert_noop!(
+			testpara::XcNFT::parse_collection_same_owner(
+				testpara::RuntimeOrigin::signed(ALICE),
+				None,
+				vec![3],
+				nfts.clone(),
+				vec![collection_metadata_hashes(&[3], &[1, 3])],
+				1000.into(),
+				0,
+				None,
+				account_location(ALICE),
+			),
+			Error::<Test>::MetadataHashMismatch
+		);
+
+		// Every received NFT has to carry its hashes
+		assert_noop!(
+			testpara::XcNFT::parse_collection_same_owner(
+				testpara::RuntimeOrigin::signed(ALICE),
+				None,
+				vec![3],
+				nfts,
+				Vec::new(),
+				1000.into(),
+				0,
+				None,
+				account_location(ALICE),
+			),
+			Error::<Test>::MetadataHashMismatch
+		);
+	});
+}
+
+#[test]
+fn try_parse_collection_empty_create_origin() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		// Received collection is created only by account allowed to create collections
+		testpara::collection_creation_allowed_parameter::set(&false);
+
+		assert_noop!(
+			testpara::XcNFT::parse_collection_empty(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				None,
+				Vec::new(),
+				None,
+				account_location(ALICE)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn try_parse_collection_empty_unverified_beneficiary() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		// Signed sender cannot create collection owned by other account
+		assert_noop!(
+			testpara::XcNFT::parse_collection_empty(
+				testpara::RuntimeOrigin::signed(BOB),
+				0,
+				None,
+				Vec::new(),
+				None,
+				account_location(ALICE)
+			),
+			Error::<Test>::UnverifiedSender
+		);
+	});
+}
+
+#[test]
+fn try_parse_collection_same_owner_unverified_beneficiary() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let mut nfts: Vec<(u32, Vec<u8>)> = Vec::new();
+		nfts.push((1, Vec::new()));
+
+		assert_noop!(
+			testpara::XcNFT::parse_collection_same_owner(
+				testpara::RuntimeOrigin::signed(BOB),
+				None,
+				Vec::new(),
+				nfts.clone(),
+				nfts.iter().map(|(_, data)| collection_metadata_hashes(&[], data)).collect(),
+				1000.into(),
+				0,
+				None,
+				account_location(ALICE)
+			),
+			Error::<Test>::UnverifiedSender
+		);
+
+		// Sender verified on other chain than origin of the collection is rejected as well
+		assert_noop!(
+			sequenced_call(
+				2000,
+				BOB,
+				crate::Call::<testpara::Runtime>::parse_collection_same_owner {
+					config: None,
+					collection_metadata: Vec::new(),
+					nfts: nfts.clone(),
+					metadata_hashes: nfts
+						.iter()
+						.map(|(_, data)| collection_metadata_hashes(&[], data))
+						.collect(),
+					origin_para: 1000.into(),
+					origin_collection_id: 0,
+					dest_collection_id: None,
+					beneficiary: account_location(ALICE),
+				},
+			),
+			Error::<Test>::UnverifiedSender
+		);
+	});
+}
+
+#[test]
+fn try_parse_nft_transfer_return_to_origin_not_owner() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let sent = SentStruct::<Test> {
+			origin_para_id: ParachainInfo::parachain_id(),
+			origin_collection_id: 0,
+			origin_asset_id: 0,
+			destination_collection_id: 0,
+			destination_asset_id: 0,
+		};
+
+		let _ = SentAssets::<Test>::insert((0, 0), sent);
+
+		// NFT owned by ALICE cannot be taken by other local account
+		assert_noop!(
+			testpara::XcNFT::parse_nft_transfer(
+				testpara::RuntimeOrigin::signed(BOB),
+				0,
+				Some(0),
+				Vec::new(),
+				metadata_hashes(&[]),
+				0,
+				0,
+				ParachainInfo::parachain_id(),
+				account_location(BOB)
+			),
+			Error::<Test>::NotNFTOwner
+		);
+	});
+}
+
+#[test]
+fn parse_nft_transfer_return_to_origin_keeps_owner() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let sent = SentStruct::<Test> {
+			origin_para_id: ParachainInfo::parachain_id(),
+			origin_collection_id: 0,
+			origin_asset_id: 0,
+			destination_collection_id: 0,
+			destination_asset_id: 0,
+		};
+
+		let _ = SentAssets::<Test>::insert((0, 0), sent);
+
+		let _ = sequenced_call(
+			2000,
+			BOB,
+			crate::Call::<testpara::Runtime>::parse_nft_transfer {
+				collection: 0,
+				item: Some(0),
+				data: Vec::new(),
+				metadata_hashes: metadata_hashes(&[]),
+				origin_collection: 0,
+				origin_item: 0,
+				origin_chain: 2000.into(),
+				beneficiary: account_location(BOB),
+			},
+		);
+
+		// Sender does not own the NFT, so it stays with its owner
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(ALICE));
+		assert!(!SentAssets::<Test>::contains_key((0, 0)));
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::NFTReturnedToOrigin {
+				returned_from_collection_id: 0,
+				returned_from_asset_id: 0,
+				to_address: BOB,
+			},
+		));
+	});
+}
+
+#[test]
+fn parse_collection_empty_indexes_received_collection() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = sequenced_call(
+			1000,
+			ALICE,
+			crate::Call::<testpara::Runtime>::parse_collection_empty {
+				origin_collection: 5,
+				destination_collection: None,
+				collection_metadata: Vec::new(),
+				config: None,
+				beneficiary: account_location(ALICE),
+			},
+		);
+
+		let received = crate::ReceivedCollectionsByOrigin::<testpara::Runtime>::get(
+			cumulus_primitives_core::ParaId::from(1000),
+			5,
+		);
+		assert!(received.is_some());
+
+		// Existing collection of the origin stays indexed
+		let _ = sequenced_call(
+			1000,
+			BOB,
+			crate::Call::<testpara::Runtime>::parse_collection_empty {
+				origin_collection: 5,
+				destination_collection: None,
+				collection_metadata: Vec::new(),
+				config: None,
+				beneficiary: account_location(BOB),
+			},
+		);
+
+		assert_eq!(
+			crate::ReceivedCollectionsByOrigin::<testpara::Runtime>::get(
+				cumulus_primitives_core::ParaId::from(1000),
+				5
+			),
+			received
+		);
+	});
+}
+
+#[test]
+fn unverified_collection_is_not_indexed() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let nfts: Vec<(u32, Vec<u8>)> = vec![(1, Vec::new())];
+
+		let _ = testpara::XcNFT::parse_collection_same_owner(
+			testpara::RuntimeOrigin::signed(ALICE),
+			None,
+			Vec::new(),
+			nfts.clone(),
+			nfts.iter().map(|(_, data)| collection_metadata_hashes(&[], data)).collect(),
+			1000.into(),
+			5,
+			None,
+			account_location(ALICE),
+		);
+
+		assert_eq!(
+			crate::ReceivedCollectionsByOrigin::<testpara::Runtime>::get(
+				cumulus_primitives_core::ParaId::from(1000),
+				5
+			),
+			None
+		);
+	});
+}
+
+#[test]
+fn try_parse_nft_transfer_into_received_origin_collection_unverified() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let nfts: Vec<(u32, Vec<u8>)> = vec![(0, Vec::new())];
+
+		let _ = sequenced_call(
+			1000,
+			ALICE,
+			crate::Call::<testpara::Runtime>::parse_collection_same_owner {
+				config: None,
+				collection_metadata: Vec::new(),
+				nfts: nfts.clone(),
+				metadata_hashes: nfts
+					.iter()
+					.map(|(_, data)| collection_metadata_hashes(&[], data))
+					.collect(),
+				origin_para: 1000.into(),
+				origin_collection_id: 5,
+				dest_collection_id: None,
+				beneficiary: account_location(ALICE),
+			},
+		);
+
+		let received = crate::ReceivedCollectionsByOrigin::<testpara::Runtime>::get(
+			cumulus_primitives_core::ParaId::from(1000),
+			5,
+		)
+		.unwrap();
+
+		// Signed sender that does not own received collection cannot mint into it
+		let _ = testpara::XcNFT::parse_nft_transfer(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			Some(0),
+			Vec::new(),
+			metadata_hashes(&[]),
+			5,
+			1,
+			1000.into(),
+			account_location(BOB),
+		);
+
+		assert_eq!(testpara::NFTs::owner(received, 1), None);
+	});
+}
+
+#[test]
+fn received_metadata_over_overflow_limit_is_rejected() {
+	ParaB::execute_with(|| {
+		testpara::metadata_policy_parameter::set(&crate::MetadataPolicy::Overflow);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		// Metadata that cannot be kept whole within MaxOverflowMetadata is not stored at all
+		assert_noop!(
+			testpara::XcNFT::parse_nft_metadata(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				0,
+				vec![1; 65]
+			),
+			Error::<testpara::Runtime>::MetadataTooLong
+		);
+		assert!(!crate::OverflowMetadata::<testpara::Runtime>::contains_key(0, Some(0)));
+	});
+}
+
+#[test]
+fn delegation_updates_tally_of_cast_vote() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 1, BOB);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 2, BOB);
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Aye,
+			None,
+		);
+
+		let proposal = crate::CrossChainProposals::<testpara::Runtime>::get(0).unwrap();
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::proposal_tally(&proposal),
+			crate::voting::Tally { aye: 1, nay: 0, total: 3 }
+		);
+
+		// Weight delegated after the vote was cast is added to it
+		let _ = testpara::XcNFT::collection_x_transfer_delegate(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			Some(ALICE),
+		);
+
+		let proposal = crate::CrossChainProposals::<testpara::Runtime>::get(0).unwrap();
+		assert_eq!(
+			crate::ProposalVotes::<testpara::Runtime>::get(0, ALICE),
+			Some((crate::Vote::Aye, 3))
+		);
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::proposal_tally(&proposal),
+			crate::voting::Tally { aye: 3, nay: 0, total: 3 }
+		);
+		assert!(crate::Pallet::<testpara::Runtime>::all_owners_voted_aye(&proposal));
+
+		// Changed vote moves delegated weight along
+		let _ = testpara::XcNFT::collection_x_transfer_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			crate::Vote::Nay,
+			None,
+		);
+
+		let proposal = crate::CrossChainProposals::<testpara::Runtime>::get(0).unwrap();
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::proposal_tally(&proposal),
+			crate::voting::Tally { aye: 0, nay: 3, total: 3 }
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_delegate(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+			None,
+		);
+		let _ = testpara::XcNFT::collection_x_transfer_withdraw_vote(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		let proposal = crate::CrossChainProposals::<testpara::Runtime>::get(0).unwrap();
+		assert_eq!(
+			crate::Pallet::<testpara::Runtime>::proposal_tally(&proposal),
+			crate::voting::Tally { aye: 0, nay: 0, total: 3 }
+		);
+	});
+}
+
+#[test]
+fn ended_proposal_cleaned_up_in_pages() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(1);
+		const COLLECTION_ID: u32 = 1;
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		// Mint more items than fit into a single snapshot page
+		for item in 0..12 {
+			let owner = if item < 9 { ALICE } else { BOB };
+			let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, item, owner);
+		}
+
+		let _ = testpara::XcNFT::collection_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(COLLECTION_ID),
+			2000.into(),
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		let _ = testpara::XcNFT::collection_x_transfer_snapshot(
+			testpara::RuntimeOrigin::signed(BOB),
+			0,
+		);
+		let _ = testpara::XcNFT::collection_x_transfer_cancel(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+		);
+
+		// First page of NFTs is unlocked right away, the rest once the cleanup is continued
+		assert_eq!(crate::ProposalLockedItems::<testpara::Runtime>::iter_prefix(0).count(), 2);
+		assert_eq!(crate::ProposalCleanups::<testpara::Runtime>::get(0), Some((0, ALICE)));
+
+		// New proposal cannot be created while NFTs are still locked by the ended one
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				Some(COLLECTION_ID),
+				2000.into(),
+				None,
+				account_location(ALICE),
+				None,
+			),
+			Error::<Test>::ProposalCleanupPending
+		);
+
+		// Any signed account can continue the cleanup
+		let _ =
+			testpara::XcNFT::collection_x_transfer_cleanup(testpara::RuntimeOrigin::signed(BOB), 0);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::ProposalCleanupUpdated { proposal_id: 0, complete: true },
+		));
+
+		assert_eq!(crate::ProposalLockedItems::<testpara::Runtime>::iter_prefix(0).count(), 0);
+		assert!(!crate::VotableProposals::<testpara::Runtime>::contains_key(BOB, 0));
+
+		assert_noop!(
+			testpara::XcNFT::collection_x_transfer_cleanup(testpara::RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoProposalCleanup
+		);
 	});
 }
 
@@ -604,15 +4115,18 @@ fn try_sending_nft_successful() {
 			0,
 			1000.into(),
 			1,
-			1,
+			Some(1),
+			account_location(ALICE),
+			None,
 		);
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTTransferred {
+			message_hash: last_message_hash(),
 			origin_collection_id: 0,
 			origin_asset_id: 0,
 			destination_para_id: 1000.into(),
 			destination_collection_id: 1,
-			destination_asset_id: 1,
+			destination_asset_id: Some(1),
 		}));
 	});
 }
@@ -629,13 +4143,36 @@ fn try_sending_nft_no_collection() {
 				0,
 				1000.into(),
 				1,
-				1
+				Some(1),
+				account_location(ALICE),
+				None
 			),
 			Error::<Test>::CollectionDoesNotExist
 		);
 	});
 }
 
+#[test]
+fn try_sending_nft_invalid_beneficiary() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		assert_noop!(
+			testpara::XcNFT::nft_x_transfer(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
+				0,
+				2000.into(),
+				0,
+				None,
+				Location::parent(),
+				None
+			),
+			Error::<Test>::InvalidBeneficiary
+		);
+	});
+}
+
 #[test]
 fn try_sending_nft_no_nft() {
 	ParaA::execute_with(|| {
@@ -650,7 +4187,9 @@ fn try_sending_nft_no_nft() {
 				0,
 				1000.into(),
 				1,
-				1
+				Some(1),
+				account_location(ALICE),
+				None
 			),
 			Error::<Test>::NFTDoesNotExist
 		);
@@ -673,7 +4212,9 @@ fn try_sending_nft_not_nft_owner() {
 				0,
 				1000.into(),
 				1,
-				1
+				Some(1),
+				account_location(BOB),
+				None
 			),
 			Error::<Test>::NotNFTOwner
 		);
@@ -882,8 +4423,9 @@ fn try_collection_parse_empty_successful() {
 			testpara::RuntimeOrigin::signed(ALICE),
 			1,
 			None,
-			BoundedVec::new(),
+			Vec::new(),
 			None,
+			account_location(ALICE),
 		);
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
@@ -928,7 +4470,7 @@ fn try_parse_collection_metadata_successful() {
 		let _ = testpara::XcNFT::parse_collection_metadata(
 			testpara::RuntimeOrigin::signed(ALICE),
 			0,
-			BoundedVec::new(),
+			Vec::new(),
 		);
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::NFTs(
@@ -971,7 +4513,7 @@ fn try_parse_nft_metadata_successful() {
 			testpara::RuntimeOrigin::signed(ALICE),
 			0,
 			0,
-			BoundedVec::new(),
+			Vec::new(),
 		);
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::NFTs(
@@ -1011,11 +4553,13 @@ fn try_parse_nft_transfer_no_collection() {
 			testpara::XcNFT::parse_nft_transfer(
 				testpara::RuntimeOrigin::signed(ALICE),
 				0,
-				0,
-				BoundedVec::new(),
+				Some(0),
+				Vec::new(),
+				metadata_hashes(&[]),
 				0,
 				0,
-				1000.into()
+				1000.into(),
+				account_location(ALICE)
 			),
 			Error::<Test>::CollectionDoesNotExist
 		);
@@ -1045,11 +4589,13 @@ fn try_parse_nft_transfer_already_received() {
 			testpara::XcNFT::parse_nft_transfer(
 				testpara::RuntimeOrigin::signed(ALICE),
 				0,
-				0,
-				BoundedVec::new(),
+				Some(0),
+				Vec::new(),
+				metadata_hashes(&[]),
 				0,
 				0,
-				1000.into()
+				1000.into(),
+				account_location(ALICE)
 			),
 			Error::<Test>::NFTAlreadyReceived
 		);
@@ -1057,22 +4603,365 @@ fn try_parse_nft_transfer_already_received() {
 }
 
 #[test]
-fn try_parse_nft_transfer_not_collection_owner() {
+fn parse_nft_transfer_into_derivative_collection() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(2);
 
 		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
 		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
 
+		// Origin chain does not own the collection, NFT is received into derivative collection
+		let _ = sequenced_call(
+			1000,
+			BOB,
+			crate::Call::<testpara::Runtime>::parse_nft_transfer {
+				collection: 0,
+				item: Some(0),
+				data: Vec::new(),
+				metadata_hashes: metadata_hashes(&[]),
+				origin_collection: 3,
+				origin_item: 0,
+				origin_chain: 1000.into(),
+				beneficiary: account_location(BOB),
+			},
+		);
+
+		let derivative = crate::DerivativeCollections::<testpara::Runtime>::get(
+			cumulus_primitives_core::ParaId::from(1000),
+			3,
+		)
+		.unwrap();
+
+		assert_eq!(
+			testpara::NFTs::collection_owner(derivative),
+			Some(testpara::XcNFT::account_id())
+		);
+		assert_eq!(testpara::NFTs::owner(derivative, 0), Some(BOB));
+		assert!(crate::ReceivedAssets::<testpara::Runtime>::contains_key((derivative, 0)));
+	});
+}
+
+#[test]
+fn derivative_collection_allocates_free_id() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
+		// Origin collection id is taken on this chain, derivative collection gets free id
+		let _ = sequenced_call(
+			1000,
+			BOB,
+			crate::Call::<testpara::Runtime>::parse_nft_transfer {
+				collection: 0,
+				item: Some(0),
+				data: Vec::new(),
+				metadata_hashes: metadata_hashes(&[]),
+				origin_collection: 0,
+				origin_item: 0,
+				origin_chain: 1000.into(),
+				beneficiary: account_location(BOB),
+			},
+		);
+
+		let derivative = crate::DerivativeCollections::<testpara::Runtime>::get(
+			cumulus_primitives_core::ParaId::from(1000),
+			0,
+		)
+		.unwrap();
+
+		assert_ne!(derivative, 0);
+		assert_eq!(
+			testpara::NFTs::collection_owner(derivative),
+			Some(testpara::XcNFT::account_id())
+		);
+		assert_eq!(testpara::NFTs::owner(derivative, 0), Some(BOB));
+	});
+}
+
+#[test]
+fn parse_nft_transfer_from_allowed_origin() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
 		assert_noop!(
-			testpara::XcNFT::parse_nft_transfer(
+			testpara::XcNFT::collection_x_allow_incoming(
 				testpara::RuntimeOrigin::signed(BOB),
 				0,
+				1000.into(),
+				None,
+				true
+			),
+			Error::<Test>::NotCollectionOwner
+		);
+
+		let _ = testpara::XcNFT::collection_x_allow_incoming(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			1000.into(),
+			Some(3),
+			true,
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::IncomingAllowlistUpdated {
+				collection_id: 0,
+				origin_para_id: 1000.into(),
+				origin_collection_id: Some(3),
+				allowed: true,
+			},
+		));
+
+		// Collection owner allowed the origin collection, NFT is received into the collection
+		let _ = sequenced_call(
+			1000,
+			BOB,
+			crate::Call::<testpara::Runtime>::parse_nft_transfer {
+				collection: 0,
+				item: Some(1),
+				data: Vec::new(),
+				metadata_hashes: metadata_hashes(&[]),
+				origin_collection: 3,
+				origin_item: 0,
+				origin_chain: 1000.into(),
+				beneficiary: account_location(BOB),
+			},
+		);
+
+		assert_eq!(testpara::NFTs::owner(0, 1), Some(BOB));
+	});
+}
+
+#[test]
+fn parse_nft_transfer_allocates_item_id() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		// Origin item id is taken, next free item id is allocated
+		let _ = testpara::XcNFT::parse_nft_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			None,
+			Vec::new(),
+			metadata_hashes(&[]),
+			0,
+			0,
+			2000.into(),
+			account_location(ALICE),
+		);
+
+		assert_eq!(testpara::NFTs::owner(0, 1), Some(ALICE));
+		assert_eq!(crate::NextReceivedItem::<testpara::Runtime>::get(0), Some(2));
+
+		// Origin item id is free, so it is kept
+		let _ = testpara::XcNFT::parse_nft_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			None,
+			Vec::new(),
+			metadata_hashes(&[]),
+			0,
+			5,
+			2000.into(),
+			account_location(ALICE),
+		);
+
+		assert_eq!(testpara::NFTs::owner(0, 5), Some(ALICE));
+	});
+}
+
+#[test]
+fn parse_nft_transfer_confirmation_updates_sent_asset() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+
+		let _ = testpara::XcNFT::nft_x_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			0,
+			2000.into(),
+			1,
+			None,
+			account_location(ALICE),
+			None,
+		);
+
+		// Destination asset is not known until destination chain confirms it
+		let sent = crate::SentAssets::<testpara::Runtime>::get((0, 0)).unwrap();
+		assert_eq!(sent.destination_asset_id, 0);
+
+		// Only destination chain of the NFT can confirm it
+		assert_noop!(
+			testpara::XcNFT::parse_nft_transfer_confirmation(
+				testpara::RuntimeOrigin::signed(ALICE),
+				0,
 				0,
-				BoundedVec::new(),
+				1,
+				7
+			),
+			Error::<Test>::InvalidSender
+		);
+		assert_noop!(
+			sequenced_call(
+				3000,
+				ALICE,
+				crate::Call::<testpara::Runtime>::parse_nft_transfer_confirmation {
+					collection: 0,
+					item: 0,
+					destination_collection: 1,
+					destination_item: 7,
+				},
+			),
+			Error::<Test>::InvalidSender
+		);
+
+		let _ = sequenced_call(
+			2000,
+			ALICE,
+			crate::Call::<testpara::Runtime>::parse_nft_transfer_confirmation {
+				collection: 0,
+				item: 0,
+				destination_collection: 1,
+				destination_item: 7,
+			},
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::NFTTransferConfirmed {
+				collection_id: 0,
+				asset_id: 0,
+				destination_collection_id: 1,
+				destination_asset_id: 7,
+			},
+		));
+
+		let sent = crate::SentAssets::<testpara::Runtime>::get((0, 0)).unwrap();
+		assert_eq!(sent.destination_collection_id, 1);
+		assert_eq!(sent.destination_asset_id, 7);
+
+		// Confirmation is accepted once
+		assert_noop!(
+			sequenced_call(
+				2000,
+				ALICE,
+				crate::Call::<testpara::Runtime>::parse_nft_transfer_confirmation {
+					collection: 0,
+					item: 0,
+					destination_collection: 1,
+					destination_item: 8,
+				},
+			),
+			Error::<Test>::InvalidSender
+		);
+
+		assert_noop!(
+			sequenced_call(
+				2000,
+				ALICE,
+				crate::Call::<testpara::Runtime>::parse_nft_transfer_confirmation {
+					collection: 0,
+					item: 1,
+					destination_collection: 1,
+					destination_item: 7,
+				},
+			),
+			Error::<Test>::NFTNotSent
+		);
+	});
+}
+
+#[test]
+fn parse_nft_transfer_into_received_origin_collection() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+
+		// Receive origin collection of the NFT
+		let mut nfts: Vec<(u32, Vec<u8>)> = Vec::new();
+		nfts.push((0, Vec::new()));
+
+		let _ = sequenced_call(
+			1000,
+			ALICE,
+			crate::Call::<testpara::Runtime>::parse_collection_same_owner {
+				config: None,
+				collection_metadata: Vec::new(),
+				nfts: nfts.clone(),
+				metadata_hashes: nfts
+					.iter()
+					.map(|(_, data)| collection_metadata_hashes(&[], data))
+					.collect(),
+				origin_para: 1000.into(),
+				origin_collection_id: 5,
+				dest_collection_id: Some(1),
+				beneficiary: account_location(ALICE),
+			},
+		);
+
+		assert_eq!(
+			crate::ReceivedCollectionsByOrigin::<testpara::Runtime>::get(
+				cumulus_primitives_core::ParaId::from(1000),
+				5
+			),
+			Some(1)
+		);
+
+		// NFT from the origin collection is received into received collection
+		let _ = sequenced_call(
+			1000,
+			BOB,
+			crate::Call::<testpara::Runtime>::parse_nft_transfer {
+				collection: 0,
+				item: Some(0),
+				data: Vec::new(),
+				metadata_hashes: metadata_hashes(&[]),
+				origin_collection: 5,
+				origin_item: 1,
+				origin_chain: 1000.into(),
+				beneficiary: account_location(BOB),
+			},
+		);
+
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTReceived {
+			origin_collection_id: 5,
+			origin_asset_id: 1,
+			received_collection_id: 1,
+			received_asset_id: 1,
+			to_address: BOB,
+		}));
+
+		assert_eq!(testpara::NFTs::owner(1, 1), Some(BOB));
+		assert_eq!(testpara::NFTs::owner(0, 0), None);
+	});
+}
+
+#[test]
+fn try_parse_nft_transfer_not_collection_owner() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::NFTs::mint(testpara::RuntimeOrigin::signed(ALICE), 0, 0, ALICE);
+		assert_noop!(
+			testpara::XcNFT::parse_nft_transfer(
+				testpara::RuntimeOrigin::signed(BOB),
+				0,
+				Some(0),
+				Vec::new(),
+				metadata_hashes(&[]),
 				0,
 				0,
-				1000.into()
+				1000.into(),
+				account_location(BOB)
 			),
 			Error::<Test>::NotCollectionOwner
 		);
@@ -1091,11 +4980,13 @@ fn try_parse_nft_transfer_not_existing_nft() {
 			testpara::XcNFT::parse_nft_transfer(
 				testpara::RuntimeOrigin::signed(ALICE),
 				0,
-				0,
-				BoundedVec::new(),
+				Some(0),
+				Vec::new(),
+				metadata_hashes(&[]),
 				0,
 				0,
-				1000.into()
+				1000.into(),
+				account_location(ALICE)
 			),
 			Error::<Test>::NFTExists
 		);
@@ -1111,11 +5002,13 @@ fn try_parse_nft_transfer_successful() {
 		let _ = testpara::XcNFT::parse_nft_transfer(
 			testpara::RuntimeOrigin::signed(ALICE),
 			0,
-			0,
-			BoundedVec::new(),
+			Some(0),
+			Vec::new(),
+			metadata_hashes(&[]),
 			0,
 			0,
 			1000.into(),
+			account_location(ALICE),
 		);
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTReceived {
 			origin_collection_id: 0,
@@ -1127,6 +5020,36 @@ fn try_parse_nft_transfer_successful() {
 	});
 }
 
+#[test]
+fn parse_nft_transfer_to_beneficiary() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let _ = testpara::NFTs::create(testpara::RuntimeOrigin::signed(ALICE), 0, ALICE);
+		let _ = testpara::XcNFT::parse_nft_transfer(
+			testpara::RuntimeOrigin::signed(ALICE),
+			0,
+			Some(0),
+			Vec::new(),
+			metadata_hashes(&[]),
+			0,
+			0,
+			1000.into(),
+			account_location(BOB),
+		);
+
+		// NFT is minted to beneficiary instead of sender
+		assert_eq!(testpara::NFTs::owner(0, 0), Some(BOB));
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTReceived {
+			origin_collection_id: 0,
+			origin_asset_id: 0,
+			received_collection_id: 0,
+			received_asset_id: 0,
+			to_address: BOB,
+		}));
+	});
+}
+
 #[test]
 fn try_parse_nft_transfer_return_to_origin() {
 	ParaA::execute_with(|| {
@@ -1150,11 +5073,13 @@ fn try_parse_nft_transfer_return_to_origin() {
 		let _ = testpara::XcNFT::parse_nft_transfer(
 			testpara::RuntimeOrigin::signed(ALICE),
 			0,
-			0,
-			BoundedVec::new(),
+			Some(0),
+			Vec::new(),
+			metadata_hashes(&[]),
 			0,
 			0,
 			ParachainInfo::parachain_id(),
+			account_location(ALICE),
 		);
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
 			Event::NFTReturnedToOrigin {
@@ -1171,18 +5096,55 @@ fn parse_collection_same_owner_successful() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(2);
 
-		let mut nfts: Vec<(u32, BoundedVec<u8, testpara::UniquesStringLimit>)> = Vec::new();
-		nfts.push((1, BoundedVec::new()));
+		let mut nfts: Vec<(u32, Vec<u8>)> = Vec::new();
+		nfts.push((1, Vec::new()));
 
 		let _ = testpara::XcNFT::parse_collection_same_owner(
 			testpara::RuntimeOrigin::signed(ALICE),
 			None,
-			BoundedVec::new(),
+			Vec::new(),
 			nfts.clone(),
+			nfts.iter().map(|(_, data)| collection_metadata_hashes(&[], data)).collect(),
 			1000.into(),
 			0,
 			None,
+			account_location(ALICE),
+		);
+		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
+			Event::CollectionWithNftsReceived { collection_id: 0, items: nfts.clone() },
+		));
+	});
+}
+
+#[test]
+fn parse_collection_same_owner_to_beneficiary() {
+	ParaA::execute_with(|| {
+		testpara::System::set_block_number(2);
+
+		let mut nfts: Vec<(u32, Vec<u8>)> = Vec::new();
+		nfts.push((1, Vec::new()));
+
+		let _ = sequenced_call(
+			1000,
+			BOB,
+			crate::Call::<testpara::Runtime>::parse_collection_same_owner {
+				config: None,
+				collection_metadata: Vec::new(),
+				nfts: nfts.clone(),
+				metadata_hashes: nfts
+					.iter()
+					.map(|(_, data)| collection_metadata_hashes(&[], data))
+					.collect(),
+				origin_para: 1000.into(),
+				origin_collection_id: 0,
+				dest_collection_id: None,
+				beneficiary: account_location(ALICE),
+			},
 		);
+
+		// Collection and NFTs are owned by beneficiary instead of sender
+		assert_eq!(testpara::NFTs::collection_owner(0), Some(ALICE));
+		assert_eq!(testpara::NFTs::owner(0, 1), Some(ALICE));
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
 			Event::CollectionWithNftsReceived { collection_id: 0, items: nfts.clone() },
 		));
@@ -1194,18 +5156,19 @@ fn parse_collection_diff_nft_owners_successful() {
 	ParaA::execute_with(|| {
 		testpara::System::set_block_number(2);
 
-		let mut nfts: Vec<(u32, AccountId32, BoundedVec<u8, testpara::UniquesStringLimit>)> =
-			Vec::new();
-		nfts.push((1, BOB, BoundedVec::new()));
+		let mut nfts: Vec<(u32, Location, Vec<u8>)> = Vec::new();
+		nfts.push((1, account_location(BOB), Vec::new()));
 
 		let _ = testpara::XcNFT::parse_collection_diff_owners(
 			testpara::RuntimeOrigin::signed(ALICE),
 			None,
-			BoundedVec::new(),
+			Vec::new(),
 			nfts.clone(),
+			nfts.iter().map(|(_, _, data)| collection_metadata_hashes(&[], data)).collect(),
 			1000.into(),
 			0,
 			None,
+			account_location(ALICE),
 		);
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
 			Event::CollectionWithNftsDiffOwnersReceived { collection_id: 0, items: nfts.clone() },
@@ -1227,6 +5190,7 @@ fn try_collection_metadata_success() {
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
 			Event::CollectionMetadataSent {
+				message_hash: last_message_hash(),
 				collection_id: 0,
 				proposed_data: BoundedVec::new(),
 				owner: ALICE,
@@ -1250,6 +5214,7 @@ fn try_collection_owner_send_success() {
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
 			Event::CollectionOwnershipSent {
+				message_hash: last_message_hash(),
 				collection_id: 0,
 				proposed_owner: BOB,
 				destination: 1000.into(),
@@ -1274,6 +5239,7 @@ fn try_collection_burn_success() {
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
 			Event::CollectionBurnSent {
+				message_hash: last_message_hash(),
 				collection_id: 0,
 				burn_data: witness.clone(),
 				owner: ALICE,
@@ -1297,6 +5263,7 @@ fn try_nft_metadata_successful() {
 		);
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTMetadataSent {
+			message_hash: last_message_hash(),
 			collection_id: 0,
 			asset_id: 0,
 			proposed_data: BoundedVec::new(),
@@ -1321,6 +5288,7 @@ fn try_nft_owner_successful() {
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(
 			Event::NFTOwnershipSent {
+				message_hash: last_message_hash(),
 				collection_id: 0,
 				asset_id: 0,
 				proposed_owner: BOB,
@@ -1339,6 +5307,7 @@ fn try_nft_burn_successful() {
 			testpara::XcNFT::nft_x_burn(testpara::RuntimeOrigin::signed(ALICE), 0, 0, 1000.into());
 
 		testpara::System::assert_has_event(testpara::RuntimeEvent::XcNFT(Event::NFTBurnSent {
+			message_hash: last_message_hash(),
 			collection_id: 0,
 			asset_id: 0,
 			owner: ALICE,
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::tests::testpara::PolkadotXcm;
 use frame_support::traits::Everything;
-use xcm_builder::AllowExplicitUnpaidExecutionFrom;
+use xcm_builder::{AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses};
 
-pub type Barrier = AllowExplicitUnpaidExecutionFrom<Everything>;
+// Status reports of sent messages are accepted for queries awaiting them
+pub type Barrier =
+	(AllowExplicitUnpaidExecutionFrom<Everything>, AllowKnownQueryResponses<PolkadotXcm>);
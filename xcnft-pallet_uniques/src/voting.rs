@@ -0,0 +1,96 @@
+//! Voting strategies used to decide cross-chain collection transfer proposals.
+//!
+//! Runtime selects strategy through `Config::VotingStrategy`, the rule it provides is recorded
+//! in each proposal at creation, so changing the strategy does not affect proposals that are
+//! already being voted on.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use scale_info::TypeInfo;
+use sp_runtime::Percent;
+
+/// Tally of a proposal, contains aye, nay and total voting weight that could be cast.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct Tally {
+	pub aye: u32,
+	pub nay: u32,
+	pub total: u32,
+}
+
+/// Rule that decides whether proposal passed, recorded in each proposal.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum VotingRule {
+	/// More aye than nay votes, accounts that did not vote are ignored.
+	#[default]
+	SimpleMajority,
+	/// Aye votes from more than half of all votes that could be cast.
+	MajorityOfOwners,
+	/// Aye votes reach `threshold` of cast votes and cast votes reach `quorum` of all votes that
+	/// could be cast.
+	SupermajorityWithQuorum { threshold: Percent, quorum: Percent },
+	/// All votes that could be cast are aye.
+	Unanimous,
+}
+
+impl VotingRule {
+	/// Returns true if the tally passes this rule.
+	pub fn passed(&self, tally: &Tally) -> bool {
+		let cast = tally.aye.saturating_add(tally.nay);
+
+		match self {
+			VotingRule::SimpleMajority => tally.aye > tally.nay,
+			VotingRule::MajorityOfOwners => tally.aye.saturating_mul(2) > tally.total,
+			VotingRule::SupermajorityWithQuorum { threshold, quorum } =>
+				tally.aye > 0 &&
+					tally.aye >= threshold.mul_ceil(cast) &&
+					cast >= quorum.mul_ceil(tally.total),
+			VotingRule::Unanimous => tally.total > 0 && tally.aye == tally.total,
+		}
+	}
+}
+
+/// Strategy used to decide cross-chain collection transfer proposals.
+pub trait VotingStrategy {
+	/// Rule recorded in proposals created while this strategy is configured.
+	fn rule() -> VotingRule;
+}
+
+/// Proposal passes with more aye than nay votes.
+pub struct SimpleMajority;
+
+impl VotingStrategy for SimpleMajority {
+	fn rule() -> VotingRule {
+		VotingRule::SimpleMajority
+	}
+}
+
+/// Proposal passes with aye votes from more than half of all owners.
+pub struct MajorityOfOwners;
+
+impl VotingStrategy for MajorityOfOwners {
+	fn rule() -> VotingRule {
+		VotingRule::MajorityOfOwners
+	}
+}
+
+/// Proposal passes with `Threshold` of cast votes being aye, provided that at least `Quorum` of
+/// owners voted.
+pub struct SupermajorityWithQuorum<Threshold, Quorum>(PhantomData<(Threshold, Quorum)>);
+
+impl<Threshold: Get<Percent>, Quorum: Get<Percent>> VotingStrategy
+	for SupermajorityWithQuorum<Threshold, Quorum>
+{
+	fn rule() -> VotingRule {
+		VotingRule::SupermajorityWithQuorum { threshold: Threshold::get(), quorum: Quorum::get() }
+	}
+}
+
+/// Proposal passes only if all owners voted aye.
+pub struct Unanimous;
+
+impl VotingStrategy for Unanimous {
+	fn rule() -> VotingRule {
+		VotingRule::Unanimous
+	}
+}